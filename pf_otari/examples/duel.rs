@@ -0,0 +1,134 @@
+//! 전사와 고블린의 1 대 1 전투
+//!
+//! `cargo run -p pf_otari --example duel`
+
+use pf_otari::prelude::*;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+struct Duelist {
+    name: &'static str,
+    character: Character,
+    weapon: Weapon,
+    attack_bonus: i32,
+    damage_bonus: i32,
+}
+
+fn stats(strength: i32, dexterity: i32, constitution: i32) -> Attribute {
+    Attribute {
+        strength,
+        dexterity,
+        constitution,
+        intelligence: 10,
+        wisdom: 12,
+        charisma: 10,
+    }
+}
+
+fn take_turn(
+    rng: &mut StdRng,
+    attacker: &Duelist,
+    defender: &mut Duelist,
+) -> Result<(), RulesError> {
+    let mut turn = CombatTurn::new();
+    let mut strikes_made = 0;
+
+    // 세 번째 Strike는 MAP -10이라 두 번만 공격
+    while strikes_made < 2 && defender.character.current_hp() > 0 {
+        if let Err(error) = turn.spend_action(1) {
+            println!("  {error}");
+            break;
+        }
+
+        let map = multiple_attack_penalty(strikes_made, attacker.weapon.agile);
+        strikes_made += 1;
+
+        let roll = roll_d20(rng);
+        let degree = strike_character(roll, attacker.attack_bonus + map, &defender.character)?;
+        let damage = calc_damage(
+            degree,
+            attacker.weapon.damage.roll(rng),
+            attacker.damage_bonus,
+        );
+        defender.character.take_damage(damage)?;
+
+        println!(
+            "  {} {} → d20 {} {:+}: {:?}, 피해 {} (남은 HP {})",
+            attacker.name,
+            attacker.weapon.name,
+            roll,
+            attacker.attack_bonus + map,
+            degree,
+            damage,
+            defender.character.current_hp()
+        );
+    }
+
+    Ok(())
+}
+
+fn main() -> Result<(), RulesError> {
+    let seed = std::env::args()
+        .nth(1)
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(2024);
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    let mut fighter = Duelist {
+        name: "전사",
+        character: Character::new(
+            10,
+            stats(16, 12, 14),
+            Defense {
+                armor_item: 4,
+                armor_proficiency: Proficiency::Trained,
+            },
+            Proficiency::Trained,
+        ),
+        weapon: Weapon {
+            name: "Longsword".to_string(),
+            damage: Dice::new(1, 8),
+            agile: false,
+        },
+        attack_bonus: 7,
+        damage_bonus: 3,
+    };
+
+    let mut goblin = Duelist {
+        name: "고블린",
+        character: Character::new(
+            6,
+            stats(10, 16, 12),
+            Defense {
+                armor_item: 2,
+                armor_proficiency: Proficiency::Trained,
+            },
+            Proficiency::Trained,
+        ),
+        weapon: Weapon {
+            name: "Dogslicer".to_string(),
+            damage: Dice::new(1, 6),
+            agile: true,
+        },
+        attack_bonus: 6,
+        damage_bonus: 0,
+    };
+
+    for round in 1.. {
+        println!("라운드 {round}");
+
+        take_turn(&mut rng, &fighter, &mut goblin)?;
+        if goblin.character.current_hp() == 0 {
+            println!("{} 승리", fighter.name);
+            break;
+        }
+
+        take_turn(&mut rng, &goblin, &mut fighter)?;
+        if fighter.character.current_hp() == 0 {
+            println!("{} 승리", goblin.name);
+            break;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,213 @@
+use crate::check::{Rarity, SaveType};
+use crate::dice::Dice;
+
+/// 크리처 특성 (Creature trait)
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum CreatureTrait {
+    Aberration,
+    Animal,
+    Beast,
+    Construct,
+    Dragon,
+    Fey,
+    Fiend,
+    Giant,
+    Goblin,
+    Humanoid,
+    Mindless,
+    Ooze,
+    Orc,
+    Undead,
+}
+
+/// 크리처 Strike
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct CreatureStrike {
+    pub name: String,
+    pub attack_bonus: i32,
+    pub damage: Dice,
+    pub damage_bonus: i32,
+    pub agile: bool,
+}
+
+/// 크리처 능력치 블록
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Creature {
+    pub name: String,
+    pub level: i32,
+    pub rarity: Rarity,
+    pub traits: Vec<CreatureTrait>,
+    pub ac: i32,
+    pub hp: i32,
+    pub perception: i32,
+    pub fortitude: i32,
+    pub reflex: i32,
+    pub will: i32,
+    pub strike: CreatureStrike,
+}
+
+impl Creature {
+    pub fn has_trait(&self, creature_trait: CreatureTrait) -> bool {
+        self.traits.contains(&creature_trait)
+    }
+
+    pub fn with_rarity(mut self, rarity: Rarity) -> Self {
+        self.rarity = rarity;
+        self
+    }
+
+    /// 기술 공격에 맞서는 방어 DC: `10 + 내성 보너스`
+    pub fn defense_dc(&self, save: SaveType) -> i32 {
+        10 + self.save(save)
+    }
+
+    pub fn save(&self, save: SaveType) -> i32 {
+        match save {
+            SaveType::Fortitude => self.fortitude,
+            SaveType::Reflex => self.reflex,
+            SaveType::Will => self.will,
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn creature(
+    name: &str,
+    level: i32,
+    traits: &[CreatureTrait],
+    ac: i32,
+    hp: i32,
+    perception: i32,
+    saves: (i32, i32, i32),
+    strike: (&str, i32, Dice, i32, bool),
+) -> Creature {
+    let (fortitude, reflex, will) = saves;
+    let (strike_name, attack_bonus, damage, damage_bonus, agile) = strike;
+    Creature {
+        name: name.to_string(),
+        level,
+        rarity: Rarity::Common,
+        traits: traits.to_vec(),
+        ac,
+        hp,
+        perception,
+        fortitude,
+        reflex,
+        will,
+        strike: CreatureStrike {
+            name: strike_name.to_string(),
+            attack_bonus,
+            damage,
+            damage_bonus,
+            agile,
+        },
+    }
+}
+
+/// 기본 수록 크리처 (Bestiary 1 일부)
+pub fn bestiary() -> Vec<Creature> {
+    use CreatureTrait::*;
+
+    vec![
+        creature(
+            "Goblin Warrior",
+            -1,
+            &[Goblin, Humanoid],
+            16,
+            6,
+            2,
+            (5, 7, 3),
+            ("Dogslicer", 8, Dice::new(1, 6), 0, true),
+        ),
+        creature(
+            "Skeleton Guard",
+            -1,
+            &[Mindless, Undead],
+            16,
+            4,
+            2,
+            (2, 8, 2),
+            ("Scimitar", 6, Dice::new(1, 6), 2, false),
+        ),
+        creature(
+            "Zombie Shambler",
+            -1,
+            &[Mindless, Undead],
+            12,
+            20,
+            0,
+            (6, 0, 2),
+            ("Fist", 7, Dice::new(1, 6), 2, false),
+        ),
+        creature(
+            "Wolf",
+            1,
+            &[Animal],
+            15,
+            24,
+            7,
+            (7, 9, 5),
+            ("Jaws", 9, Dice::new(1, 6), 2, false),
+        ),
+        creature(
+            "Ghoul",
+            1,
+            &[Undead],
+            16,
+            20,
+            7,
+            (4, 9, 7),
+            ("Jaws", 9, Dice::new(1, 6), 1, false),
+        ),
+        creature(
+            "Ogre Warrior",
+            3,
+            &[Giant, Humanoid],
+            17,
+            50,
+            5,
+            (11, 6, 5),
+            ("Greatclub", 12, Dice::new(1, 10), 7, false),
+        ),
+        creature(
+            "Gelatinous Cube",
+            3,
+            &[Mindless, Ooze],
+            10,
+            90,
+            5,
+            (12, 2, 5),
+            ("Pseudopod", 11, Dice::new(2, 6), 3, false),
+        ),
+        creature(
+            "Owlbear",
+            4,
+            &[Animal],
+            21,
+            70,
+            12,
+            (14, 7, 10),
+            ("Beak", 14, Dice::new(1, 12), 7, false),
+        ),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_bestiary_lookup() {
+        let creatures = bestiary();
+
+        let wolf = creatures.iter().find(|c| c.name == "Wolf").unwrap();
+        assert_eq!(wolf.level, 1);
+        assert!(wolf.has_trait(CreatureTrait::Animal));
+        assert!(!wolf.has_trait(CreatureTrait::Undead));
+        assert_eq!(wolf.strike.damage, Dice::new(1, 6));
+        assert_eq!(wolf.save(SaveType::Reflex), 9);
+        assert_eq!(wolf.defense_dc(SaveType::Fortitude), 17);
+        assert_eq!(wolf.rarity, Rarity::Common);
+    }
+}
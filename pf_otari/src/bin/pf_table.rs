@@ -0,0 +1,235 @@
+//! Bevy 창 없이 터미널에서 진행하는 테이블 전투
+//!
+//! `terminal` 기능이 있어야 빌드된다. 파일 경로는 실행한 디렉터리 기준이라, 워크스페이스 루트에서는
+//!
+//! `cargo run -p pf_otari --features terminal --bin pf_table -- pf_otari/examples/party.txt pf_otari/examples/creatures.txt`
+//! `cargo run -p pf_otari --features terminal --bin pf_table -- --resume fight.txt`
+//!
+//! `--en`을 붙이면 영어로 출력한다. 파일 형식은 `pf_otari::table`을 참고.
+
+use std::io::{self, Write, stdin};
+use std::{env, fs};
+
+use pf_otari::prelude::*;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use termion::event::{Event, Key};
+use termion::input::TermRead;
+use termion::raw::IntoRawMode;
+
+/// 새 전투를 저장하는 기본 파일
+const SAVE_FILE: &str = "fight.txt";
+/// 화면에 보여 줄 최근 기록 수
+const LOG_LINES: usize = 8;
+
+/// 키 입력을 기다리는 상태, 번호를 고를 때는 지금까지 누른 번호 (없으면 0)
+#[derive(PartialEq, Eq, Copy, Clone)]
+enum Prompt {
+    Command,
+    StrikeTarget(usize),
+    Reactor(usize),
+}
+
+impl Prompt {
+    fn with_number(self, number: usize) -> Self {
+        match self {
+            Prompt::Command => Prompt::Command,
+            Prompt::StrikeTarget(_) => Prompt::StrikeTarget(number),
+            Prompt::Reactor(_) => Prompt::Reactor(number),
+        }
+    }
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let locale = if args.iter().any(|a| a == "--en") {
+        Locale::English
+    } else {
+        Locale::Korean
+    };
+    let paths: Vec<&str> = args
+        .iter()
+        .filter(|a| !a.starts_with("--"))
+        .map(String::as_str)
+        .collect();
+
+    let mut rng = StdRng::seed_from_u64(rand::random());
+    let (mut fight, save_path) = match (args.iter().any(|a| a == "--resume"), &paths[..]) {
+        (true, &[path]) => (TableFight::load(&fs::read_to_string(path)?)?, path),
+        (false, &[party, creatures]) => {
+            let party = parse_roster(&fs::read_to_string(party)?)?;
+            let creatures = parse_roster(&fs::read_to_string(creatures)?)?;
+            (TableFight::new(party, creatures, &mut rng), SAVE_FILE)
+        }
+        _ => {
+            eprintln!("pf_table <party> <creatures> | pf_table --resume <fight> [--en]");
+            return Ok(());
+        }
+    };
+
+    let mut stdout = io::stdout().into_raw_mode()?;
+    let mut log = vec![turn_line(&fight, locale)];
+    let mut prompt = Prompt::Command;
+    draw(&mut stdout, &fight, locale, &log, prompt)?;
+
+    for event in stdin().events() {
+        let Event::Key(key) = event? else {
+            continue;
+        };
+        // 번호 입력을 마치면 고른 참가자 번호
+        let mut chosen = None;
+        match (prompt, key) {
+            (_, Key::Esc) => prompt = Prompt::Command,
+            (Prompt::Command, Key::Char('q')) => break,
+            (Prompt::Command, Key::Char('s')) => prompt = Prompt::StrikeTarget(0),
+            (Prompt::Command, Key::Char('r')) => prompt = Prompt::Reactor(0),
+            (Prompt::Command, Key::Char(c @ '1'..='3')) => {
+                let cost = c as u8 - b'0';
+                let name = fight.active().stats.name.clone();
+                log.push(match fight.act(cost) {
+                    Ok(()) => locale.format("table.act", &[("name", &name), ("cost", &cost)]),
+                    Err(error) => error.localize(locale),
+                });
+            }
+            (Prompt::Command, Key::Char('e')) => {
+                fight.end_turn();
+                log.push(turn_line(&fight, locale));
+            }
+            (Prompt::Command, Key::Char('w')) => {
+                fs::write(save_path, fight.save())?;
+                log.push(locale.format("table.saved", &[("path", &save_path)]));
+            }
+            (Prompt::StrikeTarget(number) | Prompt::Reactor(number), Key::Char(c @ '0'..='9')) => {
+                let number = number * 10 + (c as usize - '0' as usize);
+                prompt = prompt.with_number(number);
+                // 번호를 더 이을 수 없으면 Enter 없이 고른다
+                if number * 10 > fight.fighters().len() {
+                    chosen = Some(number);
+                }
+            }
+            (Prompt::StrikeTarget(number) | Prompt::Reactor(number), Key::Char('\n')) => {
+                chosen = Some(number);
+            }
+            (Prompt::StrikeTarget(number) | Prompt::Reactor(number), Key::Backspace) => {
+                prompt = prompt.with_number(number / 10);
+            }
+            _ => {}
+        }
+
+        match (prompt, chosen.and_then(|n| n.checked_sub(1))) {
+            (Prompt::StrikeTarget(_), Some(target)) => {
+                let attacker = fight.active_index();
+                log.push(match fight.strike(target, &mut rng) {
+                    Ok(report) => fight.strike_line(locale, attacker, target, &report),
+                    Err(error) => error.localize(locale),
+                });
+                prompt = Prompt::Command;
+            }
+            (Prompt::Reactor(_), Some(reactor)) => {
+                log.push(match fight.react(reactor) {
+                    Ok(()) => {
+                        let name = &fight.fighters()[reactor].stats.name;
+                        locale.format("table.react", &[("name", name)])
+                    }
+                    Err(error) => error.localize(locale),
+                });
+                prompt = Prompt::Command;
+            }
+            _ => {}
+        }
+
+        if let Some(side) = fight.winner() {
+            let side = locale.text(match side {
+                Side::Party => "side.party",
+                Side::Creatures => "side.creatures",
+            });
+            log.push(locale.format("log.defeated", &[("side", &side)]));
+            draw(&mut stdout, &fight, locale, &log, prompt)?;
+            break;
+        }
+        draw(&mut stdout, &fight, locale, &log, prompt)?;
+    }
+
+    write!(stdout, "\r\n")?;
+    Ok(())
+}
+
+fn turn_line(fight: &TableFight, locale: Locale) -> String {
+    locale.format(
+        "table.turn",
+        &[
+            ("round", &fight.round()),
+            ("name", &fight.active().stats.name),
+        ],
+    )
+}
+
+/// 우선권 순서, HP, 현재 참가자의 남은 액션과 리액션, 최근 기록
+fn draw(
+    stdout: &mut impl Write,
+    fight: &TableFight,
+    locale: Locale,
+    log: &[String],
+    prompt: Prompt,
+) -> io::Result<()> {
+    write!(
+        stdout,
+        "{}{}{}\r\n\r\n",
+        termion::clear::All,
+        termion::cursor::Goto(1, 1),
+        locale.format("table.round", &[("round", &fight.round())])
+    )?;
+
+    for (index, fighter) in fight.fighters().iter().enumerate() {
+        let active = index == fight.active_index();
+        let actions: String = fighter
+            .turn
+            .action_slots()
+            .iter()
+            .map(|slot| match slot {
+                ActionSlot::Lost => slot.symbol(),
+                _ if active => slot.symbol(),
+                _ => ActionSlot::Spent.symbol(),
+            })
+            .collect();
+        let reaction = if fighter.turn.has_reaction() {
+            "⟲"
+        } else {
+            " "
+        };
+        // 쓰러진 참가자는 흐리게
+        if !fighter.is_standing() {
+            write!(stdout, "{}", termion::style::Faint)?;
+        }
+        write!(
+            stdout,
+            "{} {}. {:<20} {:>3}  HP {:>3}/{:<3} AC {:<3} {} {}{}\r\n",
+            if active { '▶' } else { ' ' },
+            index + 1,
+            fighter.stats.name,
+            fighter.initiative,
+            fighter.hp,
+            fighter.stats.max_hp,
+            fighter.stats.ac,
+            actions,
+            reaction,
+            termion::style::Reset
+        )?;
+    }
+
+    write!(stdout, "\r\n")?;
+    for line in &log[log.len().saturating_sub(LOG_LINES)..] {
+        write!(stdout, "{line}\r\n")?;
+    }
+    let (key, number) = match prompt {
+        Prompt::Command => ("table.menu", 0),
+        Prompt::StrikeTarget(number) => ("table.choose_target", number),
+        Prompt::Reactor(number) => ("table.choose_reactor", number),
+    };
+    write!(stdout, "\r\n{}", locale.text(key))?;
+    if number > 0 {
+        write!(stdout, " {number}")?;
+    }
+    write!(stdout, "\r\n")?;
+    stdout.flush()
+}
@@ -0,0 +1,469 @@
+use std::ops::RangeInclusive;
+
+use crate::bestiary::{Creature, CreatureStrike, CreatureTrait};
+use crate::check::Rarity;
+use crate::dice::Dice;
+use crate::error::RulesError;
+
+/// 크리처 제작 표의 단계
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum Tier {
+    Extreme,
+    High,
+    Moderate,
+    Low,
+    /// 지각과 내성에만 있음
+    Terrible,
+}
+
+/// 제작 표가 다루는 가장 낮은 레벨
+pub const MIN_LEVEL: i32 = -1;
+/// 제작 표가 다루는 가장 높은 레벨
+pub const MAX_LEVEL: i32 = 24;
+
+/// AC: 극단, 높음, 보통, 낮음
+const ARMOR_CLASS: [[i32; 4]; 26] = [
+    [18, 15, 14, 12],
+    [19, 16, 15, 13],
+    [19, 16, 15, 13],
+    [21, 18, 17, 15],
+    [22, 19, 18, 16],
+    [24, 21, 20, 18],
+    [25, 22, 21, 19],
+    [27, 24, 23, 21],
+    [28, 25, 24, 22],
+    [30, 27, 26, 24],
+    [31, 28, 27, 25],
+    [33, 30, 29, 27],
+    [34, 31, 30, 28],
+    [36, 33, 32, 30],
+    [37, 34, 33, 31],
+    [39, 36, 35, 33],
+    [40, 37, 36, 34],
+    [42, 39, 38, 36],
+    [43, 40, 39, 37],
+    [45, 42, 41, 39],
+    [46, 43, 42, 40],
+    [48, 45, 44, 42],
+    [49, 46, 45, 43],
+    [51, 48, 47, 45],
+    [52, 49, 48, 46],
+    [54, 51, 50, 48],
+];
+
+/// 지각과 내성: 극단, 높음, 보통, 낮음, 형편없음
+const MODIFIER: [[i32; 5]; 26] = [
+    [9, 8, 5, 2, 0],
+    [10, 9, 6, 3, 1],
+    [11, 10, 7, 4, 2],
+    [12, 11, 8, 5, 3],
+    [14, 12, 9, 6, 4],
+    [15, 14, 11, 8, 6],
+    [17, 15, 12, 9, 7],
+    [18, 17, 14, 11, 8],
+    [20, 18, 15, 12, 10],
+    [21, 19, 16, 13, 11],
+    [23, 21, 18, 15, 12],
+    [24, 22, 19, 16, 14],
+    [26, 24, 21, 18, 15],
+    [27, 25, 22, 19, 16],
+    [29, 26, 23, 20, 18],
+    [30, 28, 25, 22, 19],
+    [32, 29, 26, 23, 20],
+    [33, 30, 28, 25, 22],
+    [35, 32, 29, 26, 23],
+    [36, 33, 30, 27, 24],
+    [38, 35, 32, 29, 26],
+    [39, 36, 33, 30, 27],
+    [41, 38, 35, 32, 28],
+    [43, 39, 36, 33, 30],
+    [44, 40, 37, 34, 31],
+    [46, 42, 38, 36, 32],
+];
+
+/// HP 범위 (최소, 최대): 높음, 보통, 낮음
+const HIT_POINTS: [[(i32, i32); 3]; 26] = [
+    [(9, 9), (7, 8), (5, 6)],
+    [(17, 20), (14, 16), (11, 13)],
+    [(24, 26), (19, 21), (14, 16)],
+    [(36, 40), (28, 32), (21, 25)],
+    [(53, 59), (42, 48), (31, 37)],
+    [(72, 78), (57, 63), (42, 48)],
+    [(91, 97), (72, 78), (53, 59)],
+    [(115, 123), (91, 99), (67, 75)],
+    [(140, 148), (111, 119), (82, 90)],
+    [(165, 173), (131, 139), (97, 105)],
+    [(190, 198), (151, 159), (112, 120)],
+    [(215, 223), (171, 179), (127, 135)],
+    [(240, 248), (191, 199), (142, 150)],
+    [(265, 273), (211, 219), (157, 165)],
+    [(290, 298), (231, 239), (172, 180)],
+    [(315, 323), (251, 259), (187, 195)],
+    [(340, 348), (271, 279), (202, 210)],
+    [(365, 373), (291, 299), (217, 225)],
+    [(390, 398), (311, 319), (232, 240)],
+    [(415, 423), (331, 339), (247, 255)],
+    [(440, 448), (351, 359), (262, 270)],
+    [(465, 473), (371, 379), (277, 285)],
+    [(495, 505), (395, 405), (295, 305)],
+    [(532, 544), (424, 436), (317, 329)],
+    [(569, 581), (454, 466), (339, 351)],
+    [(617, 633), (492, 508), (367, 383)],
+];
+
+/// Strike 명중 보너스: 극단, 높음, 보통, 낮음
+const STRIKE_ATTACK: [[i32; 4]; 26] = [
+    [10, 8, 6, 4],
+    [10, 8, 6, 4],
+    [11, 9, 7, 5],
+    [13, 11, 9, 7],
+    [14, 12, 10, 8],
+    [16, 14, 12, 9],
+    [17, 15, 13, 11],
+    [19, 17, 15, 12],
+    [20, 18, 16, 13],
+    [22, 20, 18, 15],
+    [23, 21, 19, 16],
+    [25, 23, 21, 17],
+    [27, 24, 22, 19],
+    [28, 26, 24, 20],
+    [29, 27, 25, 21],
+    [31, 29, 27, 23],
+    [32, 30, 28, 24],
+    [34, 32, 30, 25],
+    [35, 33, 31, 27],
+    [37, 35, 33, 28],
+    [38, 36, 34, 29],
+    [40, 38, 36, 31],
+    [41, 39, 37, 32],
+    [43, 41, 39, 33],
+    [44, 42, 40, 35],
+    [46, 44, 42, 36],
+];
+
+/// Strike 피해 (주사위 수, 면, 고정): 극단, 높음, 보통, 낮음
+const STRIKE_DAMAGE: [[(u8, u8, i32); 4]; 26] = [
+    [(1, 6, 1), (1, 4, 1), (1, 4, 0), (1, 4, 0)],
+    [(1, 6, 3), (1, 6, 2), (1, 4, 2), (1, 4, 1)],
+    [(1, 8, 4), (1, 6, 3), (1, 6, 2), (1, 4, 2)],
+    [(1, 12, 4), (1, 10, 4), (1, 8, 4), (1, 6, 3)],
+    [(1, 12, 8), (1, 10, 6), (1, 8, 6), (1, 6, 5)],
+    [(2, 10, 7), (2, 8, 5), (2, 6, 5), (2, 4, 4)],
+    [(2, 12, 7), (2, 8, 7), (2, 6, 6), (2, 4, 6)],
+    [(2, 12, 10), (2, 8, 9), (2, 6, 8), (2, 4, 7)],
+    [(2, 12, 12), (2, 10, 9), (2, 8, 8), (2, 6, 6)],
+    [(2, 12, 15), (2, 10, 11), (2, 8, 9), (2, 6, 8)],
+    [(2, 12, 17), (2, 10, 13), (2, 8, 11), (2, 6, 9)],
+    [(2, 12, 20), (2, 12, 13), (2, 10, 11), (2, 6, 10)],
+    [(2, 12, 22), (2, 12, 15), (2, 10, 12), (2, 8, 10)],
+    [(3, 12, 19), (3, 10, 14), (3, 8, 12), (3, 6, 10)],
+    [(3, 12, 21), (3, 10, 16), (3, 8, 14), (3, 6, 11)],
+    [(3, 12, 24), (3, 10, 18), (3, 8, 15), (3, 6, 13)],
+    [(3, 12, 26), (3, 12, 17), (3, 10, 14), (3, 6, 14)],
+    [(3, 12, 29), (3, 12, 18), (3, 10, 15), (3, 6, 15)],
+    [(3, 12, 31), (3, 12, 19), (3, 10, 16), (3, 6, 16)],
+    [(3, 12, 34), (3, 12, 20), (3, 10, 17), (3, 6, 17)],
+    [(4, 12, 29), (4, 10, 20), (4, 8, 17), (4, 6, 14)],
+    [(4, 12, 32), (4, 10, 22), (4, 8, 19), (4, 6, 15)],
+    [(4, 12, 34), (4, 10, 24), (4, 8, 20), (4, 6, 17)],
+    [(4, 12, 37), (4, 10, 26), (4, 8, 22), (4, 6, 18)],
+    [(4, 12, 39), (4, 12, 24), (4, 10, 20), (4, 6, 19)],
+    [(4, 12, 42), (4, 12, 26), (4, 10, 22), (4, 6, 21)],
+];
+
+fn row(level: i32) -> Result<usize, RulesError> {
+    if !(MIN_LEVEL..=MAX_LEVEL).contains(&level) {
+        return Err(RulesError::InvalidLevel(level));
+    }
+    Ok((level - MIN_LEVEL) as usize)
+}
+
+/// 표에 없는 단계면 오류
+fn column(stat: &str, tier: Tier, tiers: &[Tier]) -> Result<usize, RulesError> {
+    tiers
+        .iter()
+        .position(|t| *t == tier)
+        .ok_or_else(|| RulesError::NoSuchTier {
+            stat: stat.to_string(),
+            tier,
+        })
+}
+
+const FOUR_TIERS: [Tier; 4] = [Tier::Extreme, Tier::High, Tier::Moderate, Tier::Low];
+
+pub fn armor_class(level: i32, tier: Tier) -> Result<i32, RulesError> {
+    Ok(ARMOR_CLASS[row(level)?][column("AC", tier, &FOUR_TIERS)?])
+}
+
+/// 지각과 세 내성은 같은 표를 쓴다
+pub fn modifier(level: i32, tier: Tier) -> Result<i32, RulesError> {
+    let column = match tier {
+        Tier::Extreme => 0,
+        Tier::High => 1,
+        Tier::Moderate => 2,
+        Tier::Low => 3,
+        Tier::Terrible => 4,
+    };
+    Ok(MODIFIER[row(level)?][column])
+}
+
+pub fn hit_points(level: i32, tier: Tier) -> Result<RangeInclusive<i32>, RulesError> {
+    let tiers = [Tier::High, Tier::Moderate, Tier::Low];
+    let (min, max) = HIT_POINTS[row(level)?][column("HP", tier, &tiers)?];
+    Ok(min..=max)
+}
+
+pub fn strike_attack(level: i32, tier: Tier) -> Result<i32, RulesError> {
+    Ok(STRIKE_ATTACK[row(level)?][column("Strike", tier, &FOUR_TIERS)?])
+}
+
+/// 피해 주사위와 고정 피해
+pub fn strike_damage(level: i32, tier: Tier) -> Result<(Dice, i32), RulesError> {
+    let (count, sides, bonus) = STRIKE_DAMAGE[row(level)?][column("Damage", tier, &FOUR_TIERS)?];
+    Ok((Dice::new(count, sides), bonus))
+}
+
+/// 레벨과 능력치별 단계로 만드는 크리처 설계
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct CreatureBlueprint {
+    pub name: String,
+    pub level: i32,
+    pub traits: Vec<CreatureTrait>,
+    pub ac: Tier,
+    pub hp: Tier,
+    pub perception: Tier,
+    /// 인내, 반사, 의지
+    pub saves: (Tier, Tier, Tier),
+    pub strike_name: String,
+    pub attack: Tier,
+    pub damage: Tier,
+    pub agile: bool,
+}
+
+impl CreatureBlueprint {
+    /// 모든 능력치가 보통인 설계
+    pub fn new(name: &str, level: i32) -> Self {
+        Self {
+            name: name.to_string(),
+            level,
+            traits: Vec::new(),
+            ac: Tier::Moderate,
+            hp: Tier::Moderate,
+            perception: Tier::Moderate,
+            saves: (Tier::Moderate, Tier::Moderate, Tier::Moderate),
+            strike_name: "Strike".to_string(),
+            attack: Tier::Moderate,
+            damage: Tier::Moderate,
+            agile: false,
+        }
+    }
+
+    pub fn with_traits(mut self, traits: &[CreatureTrait]) -> Self {
+        self.traits = traits.to_vec();
+        self
+    }
+
+    pub fn with_ac(mut self, tier: Tier) -> Self {
+        self.ac = tier;
+        self
+    }
+
+    pub fn with_hp(mut self, tier: Tier) -> Self {
+        self.hp = tier;
+        self
+    }
+
+    pub fn with_perception(mut self, tier: Tier) -> Self {
+        self.perception = tier;
+        self
+    }
+
+    pub fn with_saves(mut self, fortitude: Tier, reflex: Tier, will: Tier) -> Self {
+        self.saves = (fortitude, reflex, will);
+        self
+    }
+
+    pub fn with_strike(mut self, name: &str, attack: Tier, damage: Tier, agile: bool) -> Self {
+        self.strike_name = name.to_string();
+        self.attack = attack;
+        self.damage = damage;
+        self.agile = agile;
+        self
+    }
+
+    /// 표에서 값을 찾아 능력치 블록을 만든다. HP는 범위의 가운데
+    pub fn build(&self) -> Result<Creature, RulesError> {
+        let level = self.level;
+        let hp = hit_points(level, self.hp)?;
+        let (damage, damage_bonus) = strike_damage(level, self.damage)?;
+        let (fortitude, reflex, will) = self.saves;
+        Ok(Creature {
+            name: self.name.clone(),
+            level,
+            rarity: Rarity::Common,
+            traits: self.traits.clone(),
+            ac: armor_class(level, self.ac)?,
+            hp: (hp.start() + hp.end()) / 2,
+            perception: modifier(level, self.perception)?,
+            fortitude: modifier(level, fortitude)?,
+            reflex: modifier(level, reflex)?,
+            will: modifier(level, will)?,
+            strike: CreatureStrike {
+                name: self.strike_name.clone(),
+                attack_bonus: strike_attack(level, self.attack)?,
+                damage,
+                damage_bonus,
+                agile: self.agile,
+            },
+        })
+    }
+}
+
+impl Creature {
+    /// 정예 조정: 레벨 +1 (-1, 0레벨은 +2), 수치 +2, 레벨에 따라 HP 증가
+    pub fn elite(&self) -> Self {
+        let hp = match self.level {
+            ..=1 => 10,
+            2..=4 => 15,
+            5..=19 => 20,
+            _ => 30,
+        };
+        let level = if self.level <= 0 { 2 } else { 1 };
+        self.adjusted("Elite", level, 2, hp)
+    }
+
+    /// 약화 조정: 레벨 -1 (1레벨은 -2), 수치 -2, 레벨에 따라 HP 감소.
+    /// 1레벨 미만 크리처에는 쓸 수 없다
+    pub fn weak(&self) -> Result<Self, RulesError> {
+        let hp = match self.level {
+            ..=0 => return Err(RulesError::InvalidLevel(self.level)),
+            1..=2 => 10,
+            3..=5 => 15,
+            6..=20 => 20,
+            _ => 30,
+        };
+        let level = if self.level == 1 { 2 } else { 1 };
+        Ok(self.adjusted("Weak", -level, -2, -hp))
+    }
+
+    fn adjusted(&self, prefix: &str, level: i32, bonus: i32, hp: i32) -> Self {
+        let mut creature = self.clone();
+        creature.name = format!("{prefix} {}", self.name);
+        creature.level += level;
+        creature.ac += bonus;
+        creature.hp = (creature.hp + hp).max(1);
+        creature.perception += bonus;
+        creature.fortitude += bonus;
+        creature.reflex += bonus;
+        creature.will += bonus;
+        creature.strike.attack_bonus += bonus;
+        creature.strike.damage_bonus += bonus;
+        creature
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::bestiary::bestiary;
+
+    #[test]
+    fn test_published_tables() {
+        let ac: Vec<i32> = FOUR_TIERS
+            .iter()
+            .map(|tier| armor_class(5, *tier).unwrap())
+            .collect();
+        assert_eq!(ac, vec![25, 22, 21, 19]);
+        assert_eq!(hit_points(3, Tier::High), Ok(53..=59));
+        assert_eq!(hit_points(20, Tier::Low), Ok(277..=285));
+        assert_eq!(strike_attack(10, Tier::Low), Ok(17));
+        assert_eq!(strike_damage(4, Tier::Extreme), Ok((Dice::new(2, 10), 7)));
+        assert_eq!(strike_damage(12, Tier::Moderate), Ok((Dice::new(3, 8), 12)));
+        assert_eq!(modifier(0, Tier::Terrible), Ok(1));
+        assert_eq!(modifier(24, Tier::Extreme), Ok(46));
+
+        assert_eq!(
+            armor_class(25, Tier::High),
+            Err(RulesError::InvalidLevel(25))
+        );
+        assert_eq!(
+            hit_points(1, Tier::Extreme),
+            Err(RulesError::NoSuchTier {
+                stat: "HP".to_string(),
+                tier: Tier::Extreme
+            })
+        );
+    }
+
+    #[test]
+    fn test_tables_grow_with_level_and_tier() {
+        for level in MIN_LEVEL..=MAX_LEVEL {
+            let ac = |level, tier| armor_class(level, tier).unwrap();
+            let attack = |level, tier| strike_attack(level, tier).unwrap();
+            for pair in FOUR_TIERS.windows(2) {
+                assert!(ac(level, pair[0]) > ac(level, pair[1]));
+                assert!(attack(level, pair[0]) > attack(level, pair[1]));
+            }
+            if level > MIN_LEVEL {
+                for tier in FOUR_TIERS {
+                    assert!(ac(level, tier) >= ac(level - 1, tier));
+                    assert!(attack(level, tier) >= attack(level - 1, tier));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_build_matches_bestiary_wolf() {
+        let creatures = bestiary();
+        let wolf = creatures.iter().find(|c| c.name == "Wolf").unwrap();
+
+        let built = CreatureBlueprint::new("Wolf", 1)
+            .with_traits(&[CreatureTrait::Animal])
+            .with_hp(Tier::High)
+            .with_strike("Jaws", Tier::High, Tier::Moderate, false)
+            .build()
+            .unwrap();
+
+        assert_eq!(built.ac, wolf.ac);
+        assert_eq!(built.strike, wolf.strike);
+        assert!(hit_points(1, Tier::High).unwrap().contains(&wolf.hp));
+        assert_eq!(built.hp, 25);
+        assert_eq!(built.perception, 7);
+        assert_eq!(built.traits, wolf.traits);
+    }
+
+    #[test]
+    fn test_elite_and_weak() {
+        let creatures = bestiary();
+        let wolf = creatures.iter().find(|c| c.name == "Wolf").unwrap();
+
+        let elite = wolf.elite();
+        assert_eq!(elite.name, "Elite Wolf");
+        assert_eq!(elite.level, 2);
+        assert_eq!(elite.ac, 17);
+        assert_eq!(elite.hp, 34);
+        assert_eq!(elite.reflex, 11);
+        assert_eq!(elite.strike.attack_bonus, 11);
+        assert_eq!(elite.strike.damage_bonus, 4);
+
+        let weak = wolf.weak().unwrap();
+        assert_eq!(weak.level, -1);
+        assert_eq!(weak.hp, 14);
+        assert_eq!(weak.strike.attack_bonus, 7);
+
+        let goblin = creatures
+            .iter()
+            .find(|c| c.name == "Goblin Warrior")
+            .unwrap();
+        assert_eq!(goblin.elite().level, 1);
+        assert_eq!(goblin.elite().hp, 16);
+        assert_eq!(goblin.weak(), Err(RulesError::InvalidLevel(-1)));
+
+        let owlbear = creatures.iter().find(|c| c.name == "Owlbear").unwrap();
+        assert_eq!(owlbear.weak().unwrap().level, 3);
+        assert_eq!(owlbear.weak().unwrap().hp, 55);
+        assert_eq!(owlbear.elite().hp, 85);
+    }
+}
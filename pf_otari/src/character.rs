@@ -1,9 +1,20 @@
-fn ability_modifier(score: i32) -> i32 {
+use crate::check::SaveType;
+use crate::class::Class;
+use crate::combat::{ActionKind, CombatTurn, TurnStart, Weapon};
+use crate::condition::Condition;
+use crate::dice::{DegreeOfSuccess, check_degree};
+use crate::error::RulesError;
+use crate::feat::{Feat, FeatEffect, FeatSlot, feat_slots};
+use crate::inventory::{Inventory, Item, ItemState};
+use crate::treasure::Coins;
+
+/// 능력치 수정값: `(점수 - 10) / 2`, 내림
+pub fn ability_modifier(score: i32) -> i32 {
     (score - 10).div_euclid(2)
 }
 
-#[derive(Debug, PartialEq, Eq, Copy, Clone)]
-enum Proficiency {
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Copy, Clone)]
+pub enum Proficiency {
     Untrained,
     Trained,
     Expert,
@@ -11,7 +22,8 @@ enum Proficiency {
     Legendary,
 }
 
-fn proficiency_bonus(proficiency: Proficiency, level: i32) -> i32 {
+/// 숙련도 보너스: Untrained 0, 그 외 레벨 + 2/4/6/8
+pub fn proficiency_bonus(proficiency: Proficiency, level: i32) -> i32 {
     match proficiency {
         Proficiency::Untrained => 0,
         Proficiency::Trained => level + 2,
@@ -21,80 +33,710 @@ fn proficiency_bonus(proficiency: Proficiency, level: i32) -> i32 {
     }
 }
 
-fn ac(dex_score: i32, armor_bonus: i32, proficiency_bonus: i32) -> i32 {
+pub fn ac(dex_score: i32, armor_bonus: i32, proficiency_bonus: i32) -> i32 {
     let dex_mod = ability_modifier(dex_score);
     10 + dex_mod + armor_bonus + proficiency_bonus
 }
 
-fn max_hp(class_hp: i32, con_score: i32, level: i32) -> i32 {
+pub fn max_hp(class_hp: i32, con_score: i32, level: i32) -> i32 {
     let con_mod = ability_modifier(con_score);
     (class_hp + con_mod) * level
 }
 
-#[derive(Debug, PartialEq, Eq)]
-struct Attribute {
-    strength: i32,
-    dexterity: i32,
-    constitution: i32,
-    intelligence: i32,
-    wisdom: i32,
-    charisma: i32,
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Attribute {
+    pub strength: i32,
+    pub dexterity: i32,
+    pub constitution: i32,
+    pub intelligence: i32,
+    pub wisdom: i32,
+    pub charisma: i32,
+}
+
+impl Attribute {
+    pub fn score(&self, ability: Ability) -> i32 {
+        match ability {
+            Ability::Strength => self.strength,
+            Ability::Dexterity => self.dexterity,
+            Ability::Constitution => self.constitution,
+            Ability::Intelligence => self.intelligence,
+            Ability::Wisdom => self.wisdom,
+            Ability::Charisma => self.charisma,
+        }
+    }
+}
+
+/// 능력치 종류
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum Ability {
+    Strength,
+    Dexterity,
+    Constitution,
+    Intelligence,
+    Wisdom,
+    Charisma,
+}
+
+/// 기술
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum Skill {
+    Acrobatics,
+    Arcana,
+    Athletics,
+    Crafting,
+    Deception,
+    Diplomacy,
+    Intimidation,
+    Medicine,
+    Nature,
+    Occultism,
+    Performance,
+    Religion,
+    Society,
+    Stealth,
+    Survival,
+    Thievery,
+}
+
+impl Skill {
+    /// 기술의 핵심 능력치 점수
+    pub fn key_score(&self, stats: &Attribute) -> i32 {
+        match self {
+            Skill::Athletics => stats.strength,
+            Skill::Acrobatics | Skill::Stealth | Skill::Thievery => stats.dexterity,
+            Skill::Arcana | Skill::Crafting | Skill::Occultism | Skill::Society => {
+                stats.intelligence
+            }
+            Skill::Medicine | Skill::Nature | Skill::Religion | Skill::Survival => stats.wisdom,
+            Skill::Deception | Skill::Diplomacy | Skill::Intimidation | Skill::Performance => {
+                stats.charisma
+            }
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Eq)]
-struct Character {
+/// 캐릭터
+///
+/// ```
+/// use pf_otari::prelude::*;
+///
+/// let fighter = Character::new(
+///     10,
+///     Attribute {
+///         strength: 16,
+///         dexterity: 12,
+///         constitution: 14,
+///         intelligence: 10,
+///         wisdom: 12,
+///         charisma: 10,
+///     },
+///     Defense {
+///         armor_item: 4,
+///         armor_proficiency: Proficiency::Trained,
+///     },
+///     Proficiency::Trained,
+/// );
+///
+/// assert_eq!(fighter.ac(), 18);
+/// assert_eq!(fighter.max_hp(), 12);
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Character {
     level: i32,
+    class: Option<Class>,
     class_hp: i32,
     hp: i32,
+    temp_hp: i32,
+    /// 임시 HP를 준 효과 이름, 그 효과가 끝나면 임시 HP도 사라진다
+    temp_hp_source: Option<String>,
+    drained: u8,
+    dying: u8,
+    wounded: u8,
+    /// 턴을 넘어 남는 Stunned 값
+    stunned: u8,
     stats: Attribute,
     armor: Defense,
     perception: Proficiency,
+    fortitude: Proficiency,
+    reflex: Proficiency,
+    will: Proficiency,
+    skills: Vec<(Skill, Proficiency)>,
+    weapon_proficiency: Proficiency,
+    speed: i32,
+    inventory: Inventory,
+    coins: Coins,
+    feats: Vec<(FeatSlot, Feat)>,
 }
 
-#[derive(Debug, PartialEq, Eq)]
-struct Defense {
-    armor_item: i32,
-    armor_proficiency: Proficiency,
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Defense {
+    /// 갑옷을 착용하지 않았을 때의 아이템 AC 보너스
+    pub armor_item: i32,
+    pub armor_proficiency: Proficiency,
 }
 
 impl Character {
-    fn new(class_hp: i32, stats: Attribute, armor: Defense, perception: Proficiency) -> Self {
+    /// Dying이 이 값에 이르면 죽는다
+    pub const DEAD_DYING: u8 = 4;
+
+    pub fn new(class_hp: i32, stats: Attribute, armor: Defense, perception: Proficiency) -> Self {
         let level = 1;
         let hp = max_hp(class_hp, stats.constitution, level);
         Self {
             level,
+            class: None,
             class_hp,
             hp,
+            temp_hp: 0,
+            temp_hp_source: None,
+            drained: 0,
+            dying: 0,
+            wounded: 0,
+            stunned: 0,
             stats,
             armor,
             perception,
+            fortitude: Proficiency::Trained,
+            reflex: Proficiency::Trained,
+            will: Proficiency::Trained,
+            skills: Vec::new(),
+            weapon_proficiency: Proficiency::Trained,
+            speed: 25,
+            inventory: Inventory::new(),
+            coins: Coins::ZERO,
+            feats: Vec::new(),
+        }
+    }
+
+    /// 클래스 HP와 1레벨 숙련도(지각, 내성, 무기)를 클래스에 맞춘다
+    pub fn with_class(mut self, class: Class) -> Self {
+        self.class = Some(class);
+        self.class_hp = class.hit_points();
+        self.perception = class.perception();
+        for save in [SaveType::Fortitude, SaveType::Reflex, SaveType::Will] {
+            self = self.with_save(save, class.save(save));
         }
+        self.weapon_proficiency = class.weapon_proficiency(self.level);
+        self.hp = self.max_hp();
+        self
+    }
+
+    /// 내성 숙련도, 기본 Trained
+    pub fn with_save(mut self, save: SaveType, proficiency: Proficiency) -> Self {
+        match save {
+            SaveType::Fortitude => self.fortitude = proficiency,
+            SaveType::Reflex => self.reflex = proficiency,
+            SaveType::Will => self.will = proficiency,
+        }
+        self
+    }
+
+    /// 무기 숙련도, 기본 Trained
+    pub fn with_weapon_proficiency(mut self, proficiency: Proficiency) -> Self {
+        self.weapon_proficiency = proficiency;
+        self
+    }
+
+    /// 기본 이동 속도 (피트), 기본 25
+    pub fn with_speed(mut self, feet: i32) -> Self {
+        self.speed = feet;
+        self
+    }
+
+    /// 기술 숙련도 지정, 지정하지 않은 기술은 Untrained
+    pub fn with_skill(mut self, skill: Skill, proficiency: Proficiency) -> Self {
+        self.skills.retain(|(s, _)| *s != skill);
+        self.skills.push((skill, proficiency));
+        self
     }
 
-    fn ac(&self) -> i32 {
+    /// 레벨을 바꾸고 HP를 새 최대치로 맞춘다
+    pub fn with_level(mut self, level: i32) -> Result<Self, RulesError> {
+        if !(1..=20).contains(&level) {
+            return Err(RulesError::InvalidLevel(level));
+        }
+        self.level = level;
+        if let Some(class) = self.class {
+            self.weapon_proficiency = class.weapon_proficiency(level);
+        }
+        self.hp = self.max_hp();
+        Ok(self)
+    }
+
+    pub fn level(&self) -> i32 {
+        self.level
+    }
+
+    pub fn class(&self) -> Option<Class> {
+        self.class
+    }
+
+    pub fn stats(&self) -> &Attribute {
+        &self.stats
+    }
+
+    /// 착용한 갑옷이 있으면 그 보너스와 민첩 상한을 쓴다, Clumsy는 상태 페널티
+    pub fn ac(&self) -> i32 {
         let prof_bonus = proficiency_bonus(self.armor.armor_proficiency, self.level);
-        ac(self.stats.dexterity, self.armor.armor_item, prof_bonus)
+        let clumsy = self.condition_value(Condition::Clumsy(0)) as i32;
+
+        match self.inventory.worn_armor() {
+            Some(armor) => {
+                let dex_mod = ability_modifier(self.stats.dexterity);
+                let dex_mod = armor.dex_cap.map_or(dex_mod, |cap| i32::min(dex_mod, cap));
+                10 + dex_mod + armor.ac_bonus + prof_bonus - clumsy
+            }
+            None => ac(self.stats.dexterity, self.armor.armor_item, prof_bonus) - clumsy,
+        }
+    }
+
+    /// Drained는 최대 HP를 `레벨 × 값`만큼 줄인다 (최소 1)
+    /// 재주(Toughness 등)로 늘어난 최대 HP 포함
+    pub fn max_hp(&self) -> i32 {
+        let drained = self.level * self.drained as i32;
+        let feats: i32 = self
+            .feats
+            .iter()
+            .map(|(_, feat)| match feat.effect {
+                FeatEffect::HitPoints(per_level) => per_level * self.level,
+                _ => 0,
+            })
+            .sum();
+        i32::max(
+            max_hp(self.class_hp, self.stats.constitution, self.level) + feats - drained,
+            1,
+        )
     }
 
-    fn max_hp(&self) -> i32 {
-        max_hp(self.class_hp, self.stats.constitution, self.level)
+    /// 임시 HP가 먼저 피해를 받는다, HP가 0이 되면 Dying 1
+    pub fn take_damage(&mut self, damage: i32) -> Result<(), RulesError> {
+        self.apply_damage(damage, 1)
     }
 
-    fn take_damage(&mut self, damage: i32) {
-        self.hp = i32::max(self.hp - damage, 0);
+    /// 치명타 피해, HP가 0이 되면 Dying 2
+    pub fn take_critical_damage(&mut self, damage: i32) -> Result<(), RulesError> {
+        self.apply_damage(damage, 2)
+    }
+
+    fn apply_damage(&mut self, damage: i32, dying: u8) -> Result<(), RulesError> {
+        if damage < 0 {
+            return Err(RulesError::NegativeAmount(damage));
+        }
+        let absorbed = i32::min(self.temp_hp, damage);
+        self.temp_hp -= absorbed;
+        if self.temp_hp == 0 {
+            self.temp_hp_source = None;
+        }
+        self.lose_hp(damage - absorbed, dying);
+        Ok(())
     }
 
-    fn heal(&mut self, heal: i32) {
+    /// HP를 잃고 0이 되면 Dying을 얻는다, 이미 0이면 Dying이 오른다
+    fn lose_hp(&mut self, amount: i32, dying: u8) {
+        if amount <= 0 || self.is_dead() {
+            return;
+        }
+        if self.hp > 0 || self.dying == 0 {
+            self.hp = i32::max(self.hp - amount, 0);
+            if self.hp == 0 {
+                self.dying = dying + self.wounded;
+            }
+        } else {
+            self.dying += dying;
+        }
+        self.dying = self.dying.min(Self::DEAD_DYING);
+    }
+
+    pub fn temp_hp(&self) -> i32 {
+        self.temp_hp
+    }
+
+    /// 임시 HP는 겹치지 않고 더 큰 쪽을 남긴다, `source`는 임시 HP를 준 효과
+    pub fn gain_temp_hp(&mut self, amount: i32, source: &str) -> Result<(), RulesError> {
+        if amount < 0 {
+            return Err(RulesError::NegativeAmount(amount));
+        }
+        if amount > self.temp_hp {
+            self.temp_hp = amount;
+            self.temp_hp_source = Some(source.to_string());
+        }
+        Ok(())
+    }
+
+    /// 효과가 끝나면 그 효과가 준 임시 HP를 잃는다
+    pub fn end_effect(&mut self, source: &str) {
+        if self.temp_hp_source.as_deref() == Some(source) {
+            self.temp_hp = 0;
+            self.temp_hp_source = None;
+        }
+    }
+
+    /// 치유 (healing): HP를 회복하고, 0에서 일어나면 Dying을 잃고 Wounded가 1 오른다
+    ///
+    /// 임시 HP나 Drained 회복으로 늘어난 최대 HP는 치유가 아니다.
+    pub fn heal(&mut self, heal: i32) -> Result<(), RulesError> {
+        if heal < 0 {
+            return Err(RulesError::NegativeAmount(heal));
+        }
+        if self.is_dead() {
+            return Err(RulesError::TargetDead);
+        }
         self.hp = i32::min(self.hp + heal, self.max_hp());
+        if self.hp > 0 && self.dying > 0 {
+            self.recover();
+        }
+        Ok(())
+    }
+
+    /// 치유가 아닌 HP 회복 (예: 최대 HP가 늘어남), 최대 HP를 넘지 않고 Dying, Wounded는 그대로
+    pub fn gain_hp(&mut self, amount: i32) -> Result<(), RulesError> {
+        if amount < 0 {
+            return Err(RulesError::NegativeAmount(amount));
+        }
+        if self.is_dead() {
+            return Err(RulesError::TargetDead);
+        }
+        self.hp = i32::min(self.hp + amount, self.max_hp());
+        Ok(())
+    }
+
+    /// Drained 값을 바꾼다, 값이 오른 만큼 `레벨 × 증가분` HP를 잃는다
+    ///
+    /// 값이 내려가도 늘어난 최대 HP만큼 회복하지는 않는다.
+    pub fn set_drained(&mut self, value: u8) {
+        let gained = value.saturating_sub(self.drained) as i32;
+        self.drained = value;
+        self.lose_hp(self.level * gained, 1);
+        self.hp = i32::min(self.hp, self.max_hp());
+    }
+
+    /// Stunned, 이미 걸려 있으면 높은 값을 따른다
+    pub fn stun(&mut self, value: u8) {
+        self.stunned = self.stunned.max(value);
+    }
+
+    pub fn stunned(&self) -> u8 {
+        self.stunned
+    }
+
+    /// 상태 이상을 반영해 턴을 시작하고, 쓴 만큼 Stunned를 줄인다
+    ///
+    /// `effects`는 지속 효과에서 온 상태 (Slowed, Quickened 등)
+    pub fn start_turn(
+        &mut self,
+        actions: u8,
+        effects: &[Condition],
+        quickened: &[ActionKind],
+    ) -> TurnStart {
+        let mut conditions = self.conditions();
+        conditions.extend_from_slice(effects);
+        let start = CombatTurn::start(actions, &conditions, quickened);
+        self.stunned = start.stunned;
+        start
+    }
+
+    pub fn dying(&self) -> u8 {
+        self.dying
+    }
+
+    pub fn wounded(&self) -> u8 {
+        self.wounded
+    }
+
+    pub fn is_dead(&self) -> bool {
+        self.dying >= Self::DEAD_DYING
+    }
+
+    /// 회복 판정 DC: `10 + Dying`
+    pub fn recovery_dc(&self) -> i32 {
+        10 + self.dying as i32
+    }
+
+    /// 턴 시작의 회복 판정 (단순 판정), 성공하면 Dying이 내려가고 실패하면 오른다
+    ///
+    /// Dying이 0이 되면 Wounded가 1 오르고, HP 0으로 의식 없이 안정된다.
+    pub fn recovery_check(&mut self, roll: u8) -> Result<DegreeOfSuccess, RulesError> {
+        if !(1..=20).contains(&roll) {
+            return Err(RulesError::InvalidRoll(roll));
+        }
+        let degree = check_degree(roll as i32, self.recovery_dc(), roll as i32);
+        if self.dying == 0 || self.is_dead() {
+            return Ok(degree);
+        }
+        match degree {
+            DegreeOfSuccess::CriticalSuccess => self.dying = self.dying.saturating_sub(2),
+            DegreeOfSuccess::Success => self.dying -= 1,
+            DegreeOfSuccess::Failure => self.dying += 1,
+            DegreeOfSuccess::CriticalFailure => self.dying += 2,
+        }
+        if self.dying == 0 {
+            self.wounded += 1;
+        } else {
+            self.dying = self.dying.min(Self::DEAD_DYING);
+        }
+        Ok(degree)
+    }
+
+    /// Treat Wounds로 회복하면 Wounded가 사라진다
+    pub fn clear_wounded(&mut self) {
+        self.wounded = 0;
+    }
+
+    /// Dying을 잃으면 Wounded가 1 오른다
+    fn recover(&mut self) {
+        self.dying = 0;
+        self.wounded += 1;
     }
 
-    fn current_hp(&self) -> i32 {
+    pub fn is_conscious(&self) -> bool {
+        self.hp > 0
+    }
+
+    pub fn current_hp(&self) -> i32 {
         self.hp
     }
 
-    fn perception_bonus(&self) -> i32 {
+    pub fn perception_bonus(&self) -> i32 {
         ability_modifier(self.stats.wisdom) + proficiency_bonus(self.perception, self.level)
     }
+
+    /// Hide, Sneak 등 Stealth 판정의 DC
+    pub fn perception_dc(&self) -> i32 {
+        10 + self.perception_bonus()
+    }
+
+    /// 내성 보너스: Fortitude CON (Drained 페널티), Reflex DEX (Clumsy 페널티), Will WIS
+    pub fn save_bonus(&self, save: SaveType) -> i32 {
+        let (score, proficiency) = match save {
+            SaveType::Fortitude => (self.stats.constitution, self.fortitude),
+            SaveType::Reflex => (self.stats.dexterity, self.reflex),
+            SaveType::Will => (self.stats.wisdom, self.will),
+        };
+        let penalty = match save {
+            SaveType::Fortitude => self.drained as i32,
+            SaveType::Reflex => self.condition_value(Condition::Clumsy(0)) as i32,
+            SaveType::Will => 0,
+        };
+        ability_modifier(score) + proficiency_bonus(proficiency, self.level) - penalty
+    }
+
+    /// 기술 공격에 맞서는 방어 DC: `10 + 내성 보너스`
+    pub fn defense_dc(&self, save: SaveType) -> i32 {
+        10 + self.save_bonus(save)
+    }
+
+    pub fn inventory(&self) -> &Inventory {
+        &self.inventory
+    }
+
+    /// 이 값을 넘으면 Encumbered: `5 + STR 수정값`
+    pub fn encumbered_bulk(&self) -> i32 {
+        5 + ability_modifier(self.stats.strength)
+    }
+
+    /// 들고 다닐 수 있는 최대 Bulk: `10 + STR 수정값`
+    pub fn max_bulk(&self) -> i32 {
+        10 + ability_modifier(self.stats.strength)
+    }
+
+    pub fn is_encumbered(&self) -> bool {
+        self.inventory.total_bulk().whole() as i32 > self.encumbered_bulk()
+    }
+
+    /// 소지품과 HP에서 오는 상태, Encumbered면 Clumsy 1
+    pub fn conditions(&self) -> Vec<Condition> {
+        let mut conditions = Vec::new();
+        if self.is_encumbered() {
+            conditions.push(Condition::Clumsy(1));
+        }
+        if self.drained > 0 {
+            conditions.push(Condition::Drained(self.drained));
+        }
+        if self.dying > 0 {
+            conditions.push(Condition::Dying(self.dying));
+        }
+        if self.wounded > 0 {
+            conditions.push(Condition::Wounded(self.wounded));
+        }
+        if self.stunned > 0 {
+            conditions.push(Condition::Stunned(self.stunned));
+        }
+        if !self.is_conscious() {
+            conditions.push(Condition::Unconscious);
+        }
+        conditions
+    }
+
+    /// `kind`와 같은 종류 상태의 값, 없으면 0
+    pub fn condition_value(&self, kind: Condition) -> u8 {
+        self.conditions()
+            .iter()
+            .filter(|c| c.same_kind(&kind))
+            .filter_map(Condition::value)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Encumbered면 10피트 느려진다 (최소 5피트)
+    pub fn speed(&self) -> i32 {
+        if self.is_encumbered() {
+            i32::max(self.speed - 10, 5)
+        } else {
+            self.speed
+        }
+    }
+
+    /// 최대 Bulk를 넘으면 들 수 없다
+    pub fn carry(&mut self, item: Item, state: ItemState) -> Result<usize, RulesError> {
+        let bulk = (self.inventory.total_bulk() + item.bulk).whole() as i32;
+        if bulk > self.max_bulk() {
+            return Err(RulesError::OverMaxBulk {
+                bulk,
+                max: self.max_bulk(),
+            });
+        }
+        self.inventory.add(item, state)
+    }
+
+    pub fn set_item_state(&mut self, index: usize, state: ItemState) -> Result<(), RulesError> {
+        self.inventory.set_state(index, state)
+    }
+
+    pub fn drop_item(&mut self, index: usize) -> Result<Item, RulesError> {
+        self.inventory.remove(index)
+    }
+
+    pub fn coins(&self) -> Coins {
+        self.coins
+    }
+
+    pub fn gain_coins(&mut self, coins: Coins) {
+        self.coins = self.coins + coins;
+    }
+
+    pub fn spend_coins(&mut self, price: Coins) -> Result<(), RulesError> {
+        self.coins = self
+            .coins
+            .checked_sub(price)
+            .ok_or(RulesError::NotEnoughCoins {
+                price,
+                coins: self.coins,
+            })?;
+        Ok(())
+    }
+
+    /// 가격을 내고 든다, 들 수 없으면 돈을 내지 않는다
+    pub fn buy(&mut self, item: Item, state: ItemState) -> Result<usize, RulesError> {
+        let price = item.price;
+        if self.coins < price {
+            return Err(RulesError::NotEnoughCoins {
+                price,
+                coins: self.coins,
+            });
+        }
+        let index = self.carry(item, state)?;
+        self.spend_coins(price)?;
+        Ok(index)
+    }
+
+    /// 가격의 절반에 판다
+    pub fn sell(&mut self, index: usize) -> Result<Coins, RulesError> {
+        let earned = self.inventory.remove(index)?.price.half();
+        self.gain_coins(earned);
+        Ok(earned)
+    }
+
+    /// 가진 돈과 소지품 가격의 합
+    pub fn wealth(&self) -> Coins {
+        self.coins
+            + self
+                .inventory
+                .items()
+                .map(|(item, _)| item.price)
+                .sum::<Coins>()
+    }
+
+    /// 손에 든 첫 번째 무기
+    pub fn weapon(&self) -> Option<&Weapon> {
+        self.inventory.held_weapons().next()
+    }
+
+    /// 근접 Strike 명중 보너스: STR 수정값 + 무기 숙련도 보너스
+    pub fn attack_bonus(&self) -> i32 {
+        ability_modifier(self.stats.strength)
+            + proficiency_bonus(self.weapon_proficiency, self.level)
+    }
+
+    /// 근접 Strike 피해 보너스: STR 수정값
+    pub fn damage_bonus(&self) -> i32 {
+        ability_modifier(self.stats.strength)
+    }
+
+    pub fn perception_proficiency(&self) -> Proficiency {
+        self.perception
+    }
+
+    pub fn save_proficiency(&self, save: SaveType) -> Proficiency {
+        match save {
+            SaveType::Fortitude => self.fortitude,
+            SaveType::Reflex => self.reflex,
+            SaveType::Will => self.will,
+        }
+    }
+
+    pub fn weapon_proficiency(&self) -> Proficiency {
+        self.weapon_proficiency
+    }
+
+    /// 고른 재주와 그 슬롯
+    pub fn feats(&self) -> &[(FeatSlot, Feat)] {
+        &self.feats
+    }
+
+    pub fn has_feat(&self, name: &str) -> bool {
+        self.feats.iter().any(|(_, feat)| feat.name == name)
+    }
+
+    /// 현재 레벨까지 아직 채우지 않은 재주 슬롯
+    pub fn open_feat_slots(&self) -> Vec<FeatSlot> {
+        let mut open = feat_slots(self.class, self.level);
+        for (slot, _) in &self.feats {
+            if let Some(index) = open.iter().position(|s| s == slot) {
+                open.remove(index);
+            }
+        }
+        open
+    }
+
+    /// 선행 조건과 슬롯을 확인하고 재주를 고른다
+    pub fn take_feat(&mut self, feat: &Feat, slot: FeatSlot) -> Result<(), RulesError> {
+        if !self.open_feat_slots().contains(&slot) {
+            return Err(RulesError::NoFeatSlot {
+                kind: slot.kind,
+                level: slot.level,
+            });
+        }
+        feat.check(self, slot)?;
+        // 늘어난 최대 HP만큼 현재 HP도 늘어난다
+        let max_hp = self.max_hp();
+        self.feats.push((slot, feat.clone()));
+        if !self.is_dead() {
+            self.gain_hp(self.max_hp() - max_hp)?;
+        }
+        Ok(())
+    }
+
+    pub fn skill_proficiency(&self, skill: Skill) -> Proficiency {
+        self.skills
+            .iter()
+            .find(|(s, _)| *s == skill)
+            .map_or(Proficiency::Untrained, |(_, p)| *p)
+    }
+
+    pub fn skill_bonus(&self, skill: Skill) -> i32 {
+        ability_modifier(skill.key_score(&self.stats))
+            + proficiency_bonus(self.skill_proficiency(skill), self.level)
+    }
 }
 
 #[cfg(test)]
@@ -102,6 +744,8 @@ mod tests {
 
     use super::Proficiency::*;
     use super::*;
+    use crate::dice::Dice;
+    use crate::inventory::{Armor, Bulk};
 
     #[test]
     fn test_ability_modifier() {
@@ -169,19 +813,315 @@ mod tests {
     fn test_character_damage_hp() {
         let mut fighter = fighter();
 
-        fighter.take_damage(5);
+        fighter.take_damage(5).unwrap();
         assert_eq!(fighter.current_hp(), 7);
 
-        fighter.take_damage(10); // 초과 피해
+        fighter.take_damage(10).unwrap(); // 초과 피해
         assert_eq!(fighter.current_hp(), 0); // 0 아래로 내려가지 않음
+        assert!(!fighter.is_conscious());
 
-        fighter.heal(3);
+        fighter.heal(3).unwrap();
         assert_eq!(fighter.current_hp(), 3);
     }
 
+    #[test]
+    fn test_negative_damage_and_heal() {
+        let mut fighter = fighter();
+
+        assert_eq!(fighter.take_damage(-5), Err(RulesError::NegativeAmount(-5)));
+        assert_eq!(fighter.heal(-1), Err(RulesError::NegativeAmount(-1)));
+        assert_eq!(fighter.current_hp(), 12); // 변화 없음
+    }
+
+    #[test]
+    fn test_temp_hp_expires_with_effect() {
+        let mut fighter = fighter();
+        fighter.gain_temp_hp(5, "False Life").unwrap();
+        // 겹치지 않고 더 큰 쪽, 작은 쪽은 출처도 바뀌지 않는다
+        fighter.gain_temp_hp(3, "Rage").unwrap();
+        assert_eq!(fighter.temp_hp(), 5);
+
+        fighter.end_effect("Rage");
+        assert_eq!(fighter.temp_hp(), 5);
+        fighter.take_damage(2).unwrap();
+        assert_eq!((fighter.temp_hp(), fighter.current_hp()), (3, 12));
+        fighter.end_effect("False Life");
+        assert_eq!(fighter.temp_hp(), 0);
+
+        // 임시 HP는 치유가 아니다
+        fighter.take_damage(12).unwrap();
+        fighter.gain_temp_hp(4, "Rage").unwrap();
+        assert_eq!(fighter.dying(), 1);
+        assert!(!fighter.is_conscious());
+    }
+
+    #[test]
+    fn test_dying_and_wounded() {
+        let mut fighter = fighter();
+        fighter.take_damage(20).unwrap();
+        assert_eq!(fighter.dying(), 1);
+        assert_eq!(
+            fighter.conditions(),
+            vec![Condition::Dying(1), Condition::Unconscious]
+        );
+
+        // 쓰러진 채 피해를 받으면 Dying이 오른다
+        fighter.take_critical_damage(1).unwrap();
+        assert_eq!(fighter.dying(), 3);
+        assert_eq!(fighter.recovery_dc(), 13);
+
+        // 치유로 일어나면 Wounded 1
+        fighter.heal(5).unwrap();
+        assert_eq!((fighter.dying(), fighter.wounded()), (0, 1));
+        assert_eq!(fighter.current_hp(), 5);
+
+        // 다시 쓰러지면 Dying 1 + Wounded 1, 치명타면 Dying 2 + 1
+        fighter.take_critical_damage(5).unwrap();
+        assert_eq!(fighter.dying(), 3);
+        fighter.take_damage(1).unwrap();
+        assert!(fighter.is_dead());
+        assert_eq!(fighter.heal(10), Err(RulesError::TargetDead));
+        assert_eq!(fighter.current_hp(), 0);
+    }
+
+    #[test]
+    fn test_gain_hp_is_not_healing() {
+        let mut fighter = fighter();
+        fighter.take_damage(20).unwrap();
+        let mut healed = fighter.clone();
+
+        // 치유가 아니면 HP만 오르고 Dying, Wounded는 그대로
+        fighter.gain_hp(5).unwrap();
+        assert_eq!(fighter.current_hp(), 5);
+        assert_eq!((fighter.dying(), fighter.wounded()), (1, 0));
+
+        healed.heal(5).unwrap();
+        assert_eq!(healed.current_hp(), 5);
+        assert_eq!((healed.dying(), healed.wounded()), (0, 1));
+
+        // 최대 HP를 넘지 않음
+        fighter.gain_hp(100).unwrap();
+        assert_eq!(fighter.current_hp(), 12);
+        assert_eq!(fighter.gain_hp(-1), Err(RulesError::NegativeAmount(-1)));
+    }
+
+    #[test]
+    fn test_recovery_check() {
+        let mut fighter = fighter();
+        fighter.take_critical_damage(20).unwrap();
+        assert_eq!(fighter.dying(), 2);
+
+        // DC 12: 12 성공, 20 치명적 성공, 2 이하 치명적 실패
+        assert_eq!(fighter.recovery_check(12), Ok(DegreeOfSuccess::Success));
+        assert_eq!(fighter.dying(), 1);
+        assert_eq!(
+            fighter.recovery_check(1),
+            Ok(DegreeOfSuccess::CriticalFailure)
+        );
+        assert_eq!(fighter.dying(), 3);
+        assert_eq!(
+            fighter.recovery_check(20),
+            Ok(DegreeOfSuccess::CriticalSuccess)
+        );
+        assert_eq!(fighter.dying(), 1);
+        fighter.recovery_check(15).unwrap();
+
+        // 안정되면 Wounded 1, HP 0으로 의식 없음
+        assert_eq!((fighter.dying(), fighter.wounded()), (0, 1));
+        assert!(!fighter.is_conscious());
+        assert_eq!(fighter.recovery_check(0), Err(RulesError::InvalidRoll(0)));
+    }
+
+    #[test]
+    fn test_drained() {
+        let mut fighter = fighter().with_level(3).unwrap();
+        fighter.take_damage(10).unwrap();
+
+        // 최대 HP와 현재 HP가 레벨 × 2 줄고, Fortitude -2
+        fighter.set_drained(2);
+        assert_eq!(fighter.max_hp(), 30);
+        assert_eq!(fighter.current_hp(), 20);
+        assert_eq!(fighter.save_bonus(SaveType::Fortitude), 5);
+        assert!(fighter.conditions().contains(&Condition::Drained(2)));
+
+        fighter.heal(20).unwrap();
+        assert_eq!(fighter.current_hp(), 30);
+
+        // 회복해도 최대 HP만 돌아온다
+        fighter.set_drained(1);
+        assert_eq!(fighter.max_hp(), 33);
+        assert_eq!(fighter.current_hp(), 30);
+
+        // HP가 0이 되면 쓰러진다
+        fighter.take_damage(28).unwrap();
+        fighter.set_drained(3);
+        assert_eq!(fighter.current_hp(), 0);
+        assert_eq!(fighter.dying(), 1);
+    }
+
+    #[test]
+    fn test_stunned_lasts_across_turns() {
+        let mut fighter = fighter();
+        fighter.stun(2);
+        fighter.stun(1);
+        assert_eq!(fighter.stunned(), 2);
+
+        // Slowed 1과 함께면 Stunned 2로 잃은 액션이 Slowed를 대신한다
+        let start = fighter.start_turn(3, &[Condition::Slowed(1)], &[]);
+        assert_eq!(start.turn.remaining_actions(), 1);
+        assert_eq!(fighter.stunned(), 0);
+        assert!(fighter.conditions().is_empty());
+
+        fighter.stun(5);
+        assert_eq!(fighter.start_turn(3, &[], &[]).turn.remaining_actions(), 0);
+        assert_eq!(fighter.conditions(), vec![Condition::Stunned(2)]);
+        assert_eq!(fighter.start_turn(3, &[], &[]).turn.remaining_actions(), 1);
+        assert_eq!(fighter.stunned(), 0);
+    }
+
+    #[test]
+    fn test_with_level() {
+        let fighter = fighter().with_level(3).unwrap();
+        assert_eq!(fighter.level(), 3);
+        assert_eq!(fighter.max_hp(), 36); // (10 + 2) * 3
+        assert_eq!(fighter.current_hp(), 36);
+
+        assert_eq!(
+            self::fighter().with_level(0),
+            Err(RulesError::InvalidLevel(0))
+        );
+        assert_eq!(
+            self::fighter().with_level(21),
+            Err(RulesError::InvalidLevel(21))
+        );
+    }
+
     #[test]
     fn test_perception() {
         let fighter = fighter();
         assert_eq!(fighter.perception_bonus(), 4);
     }
+
+    #[test]
+    fn test_encumbrance() {
+        let mut fighter = fighter();
+        assert_eq!(fighter.encumbered_bulk(), 8); // 5 + STR 3
+        assert_eq!(fighter.max_bulk(), 13);
+
+        fighter
+            .carry(Item::gear("Backpack", Bulk::new(8), 0), ItemState::Worn)
+            .unwrap();
+        assert!(!fighter.is_encumbered());
+        assert_eq!(fighter.speed(), 25);
+
+        fighter
+            .carry(Item::gear("Rope", Bulk::new(1), 0), ItemState::Stowed)
+            .unwrap();
+        assert!(fighter.is_encumbered());
+        assert_eq!(fighter.conditions(), vec![Condition::Clumsy(1)]);
+        assert_eq!(fighter.ac(), 17); // Clumsy 1
+        assert_eq!(fighter.speed(), 15);
+
+        assert_eq!(
+            fighter.carry(Item::gear("Anvil", Bulk::new(5), 0), ItemState::Stowed),
+            Err(RulesError::OverMaxBulk { bulk: 14, max: 13 })
+        );
+
+        fighter.drop_item(1).unwrap();
+        assert!(!fighter.is_encumbered());
+        assert_eq!(fighter.ac(), 18);
+    }
+
+    #[test]
+    fn test_buy_and_sell() {
+        let mut fighter = fighter();
+        fighter.gain_coins(Coins::gp(15));
+        let rope = Item::gear("Rope", Bulk::LIGHT, 0).with_price(Coins::sp(5));
+        let anvil = Item::gear("Anvil", Bulk::new(14), 0).with_price(Coins::gp(5));
+
+        let index = fighter.buy(rope, ItemState::Stowed).unwrap();
+        assert_eq!(fighter.coins(), Coins::cp(1450));
+        assert_eq!(fighter.wealth(), Coins::gp(15));
+
+        // 들 수 없으면 돈을 내지 않는다
+        assert_eq!(
+            fighter.buy(anvil, ItemState::Stowed),
+            Err(RulesError::OverMaxBulk { bulk: 14, max: 13 })
+        );
+        let plate = Item::gear("Full Plate", Bulk::new(4), 0).with_price(Coins::gp(30));
+        assert_eq!(
+            fighter.buy(plate, ItemState::Worn),
+            Err(RulesError::NotEnoughCoins {
+                price: Coins::gp(30),
+                coins: Coins::cp(1450)
+            })
+        );
+        assert_eq!(fighter.coins(), Coins::cp(1450));
+
+        assert_eq!(fighter.sell(index), Ok(Coins::cp(25)));
+        assert_eq!(fighter.coins(), Coins::cp(1475));
+        assert_eq!(fighter.wealth(), fighter.coins());
+    }
+
+    #[test]
+    fn test_inventory_armor_and_weapon() {
+        let mut fighter = fighter().with_weapon_proficiency(Expert);
+        assert_eq!(fighter.weapon(), None);
+
+        let plate = fighter
+            .carry(
+                Item::armor(
+                    Armor {
+                        name: "Full Plate".to_string(),
+                        ac_bonus: 6,
+                        dex_cap: Some(0),
+                    },
+                    Bulk::new(4),
+                ),
+                ItemState::Worn,
+            )
+            .unwrap();
+        // 10 + DEX 0 (상한) + 6 + Trained 3
+        assert_eq!(fighter.ac(), 19);
+
+        fighter.set_item_state(plate, ItemState::Stowed).unwrap();
+        assert_eq!(fighter.ac(), 18); // Defense.armor_item 사용
+
+        let longsword = Weapon {
+            name: "Longsword".to_string(),
+            damage: Dice::new(1, 8),
+            agile: false,
+        };
+        fighter
+            .carry(
+                Item::weapon(longsword.clone(), Bulk::new(1), 1),
+                ItemState::Held,
+            )
+            .unwrap();
+        assert_eq!(fighter.weapon(), Some(&longsword));
+        assert_eq!(fighter.attack_bonus(), 8); // STR 3 + Expert 5
+        assert_eq!(fighter.damage_bonus(), 3);
+    }
+
+    #[test]
+    fn test_saves() {
+        let fighter = fighter().with_save(SaveType::Fortitude, Expert);
+
+        assert_eq!(fighter.save_bonus(SaveType::Fortitude), 7); // CON 2 + Expert 5
+        assert_eq!(fighter.save_bonus(SaveType::Reflex), 4); // DEX 1 + Trained 3
+        assert_eq!(fighter.defense_dc(SaveType::Will), 14); // 10 + WIS 1 + Trained 3
+    }
+
+    #[test]
+    fn test_skill_bonus() {
+        let fighter = fighter()
+            .with_skill(Skill::Athletics, Trained)
+            .with_skill(Skill::Medicine, Trained)
+            .with_skill(Skill::Medicine, Expert); // 덮어씀
+
+        assert_eq!(fighter.skill_bonus(Skill::Athletics), 6); // STR +3, Trained 3
+        assert_eq!(fighter.skill_proficiency(Skill::Medicine), Expert);
+        assert_eq!(fighter.skill_bonus(Skill::Medicine), 6); // WIS +1, Expert 5
+        assert_eq!(fighter.skill_bonus(Skill::Stealth), 1); // DEX +1, Untrained
+    }
 }
@@ -0,0 +1,421 @@
+use rand::Rng;
+
+use crate::character::Proficiency;
+use crate::dice::{DegreeOfSuccess, check_degree, roll_d20};
+use crate::error::RulesError;
+
+/// 내성 굴림 종류
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum SaveType {
+    Fortitude,
+    Reflex,
+    Will,
+}
+
+/// 레벨별 DC (-1~25레벨)
+const LEVEL_DCS: [i32; 27] = [
+    13, 14, 15, 16, 18, 19, 20, 22, 23, 24, 26, 27, 28, 30, 31, 32, 34, 35, 36, 38, 39, 40, 42, 44,
+    46, 48, 50,
+];
+
+/// 레벨 기반 DC, 범위를 벗어나면 `None`
+pub fn level_dc(level: i32) -> Option<i32> {
+    usize::try_from(level + 1)
+        .ok()
+        .and_then(|i| LEVEL_DCS.get(i))
+        .copied()
+}
+
+/// 숙련도 기준 단순 DC
+pub fn simple_dc(proficiency: Proficiency) -> i32 {
+    match proficiency {
+        Proficiency::Untrained => 10,
+        Proficiency::Trained => 15,
+        Proficiency::Expert => 20,
+        Proficiency::Master => 30,
+        Proficiency::Legendary => 40,
+    }
+}
+
+/// DC 난이도 조정
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum DcAdjustment {
+    IncrediblyEasy,
+    VeryEasy,
+    Easy,
+    Hard,
+    VeryHard,
+    IncrediblyHard,
+}
+
+impl DcAdjustment {
+    pub fn value(&self) -> i32 {
+        match self {
+            DcAdjustment::IncrediblyEasy => -10,
+            DcAdjustment::VeryEasy => -5,
+            DcAdjustment::Easy => -2,
+            DcAdjustment::Hard => 2,
+            DcAdjustment::VeryHard => 5,
+            DcAdjustment::IncrediblyHard => 10,
+        }
+    }
+}
+
+/// 희귀도, 드문 대상일수록 DC가 오른다
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Default)]
+pub enum Rarity {
+    #[default]
+    Common,
+    Uncommon,
+    Rare,
+    Unique,
+}
+
+impl Rarity {
+    pub fn dc_adjustment(&self) -> Option<DcAdjustment> {
+        match self {
+            Rarity::Common => None,
+            Rarity::Uncommon => Some(DcAdjustment::Hard),
+            Rarity::Rare => Some(DcAdjustment::VeryHard),
+            Rarity::Unique => Some(DcAdjustment::IncrediblyHard),
+        }
+    }
+
+    /// 레벨 DC에 희귀도 조정을 더한다
+    pub fn adjust(&self, dc: i32) -> i32 {
+        dc + self.dc_adjustment().map_or(0, |a| a.value())
+    }
+}
+
+/// 판정 종류
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum CheckKind {
+    Attack,
+    Save(SaveType),
+    Skill,
+    Perception,
+}
+
+/// 굴림 수정 효과
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum RollModifier {
+    /// 두 번 굴려 높은 값 사용
+    Fortune,
+    /// 두 번 굴려 낮은 값 사용
+    Misfortune,
+}
+
+/// 한 판정에 실제로 적용되는 굴림 방식
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+enum RollMode {
+    Normal,
+    Fortune,
+    Misfortune,
+}
+
+/// 히어로 포인트, 최대 3점
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct HeroPoints {
+    points: u8,
+}
+
+impl HeroPoints {
+    pub const MAX: u8 = 3;
+
+    pub fn new(points: u8) -> Self {
+        Self {
+            points: u8::min(points, Self::MAX),
+        }
+    }
+
+    pub fn remaining(&self) -> u8 {
+        self.points
+    }
+
+    pub fn award(&mut self, points: u8) {
+        self.points = u8::min(self.points.saturating_add(points), Self::MAX);
+    }
+
+    fn spend(&mut self) -> bool {
+        if self.points == 0 {
+            false
+        } else {
+            self.points -= 1;
+            true
+        }
+    }
+}
+
+/// d20 + `modifier` vs `dc` 판정
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Check {
+    modifier: i32,
+    dc: i32,
+    fortune: bool,
+    misfortune: bool,
+}
+
+/// 판정 결과, 굴린 주사위를 모두 기록한다
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct CheckOutcome {
+    /// 굴린 순서대로의 모든 d20
+    pub rolls: Vec<u8>,
+    /// 최종 결과에 사용한 d20
+    pub natural: u8,
+    pub total: i32,
+    pub degree: DegreeOfSuccess,
+    /// 행운 효과(Fortune, 히어로 포인트) 적용 여부
+    fortune_applied: bool,
+}
+
+impl Check {
+    pub fn new(modifier: i32, dc: i32) -> Self {
+        Self {
+            modifier,
+            dc,
+            fortune: false,
+            misfortune: false,
+        }
+    }
+
+    /// 같은 종류의 효과는 겹치지 않는다
+    pub fn with(mut self, modifier: RollModifier) -> Self {
+        match modifier {
+            RollModifier::Fortune => self.fortune = true,
+            RollModifier::Misfortune => self.misfortune = true,
+        }
+        self
+    }
+
+    fn mode(&self) -> RollMode {
+        match (self.fortune, self.misfortune) {
+            (true, false) => RollMode::Fortune,
+            (false, true) => RollMode::Misfortune,
+            // 행운과 불운은 서로 상쇄된다
+            _ => RollMode::Normal,
+        }
+    }
+
+    pub fn roll(&self, rng: &mut impl Rng) -> CheckOutcome {
+        self.roll_with(&mut || roll_d20(rng))
+    }
+
+    /// 히어로 포인트 1점으로 다시 굴린다. 두 번째 결과를 반드시 사용한다.
+    ///
+    /// 다시 굴리기도 행운 효과이므로 이미 행운 효과가 적용된 판정에는 사용할 수 없다.
+    pub fn reroll(
+        &self,
+        previous: &CheckOutcome,
+        hero_points: &mut HeroPoints,
+        rng: &mut impl Rng,
+    ) -> Result<CheckOutcome, RulesError> {
+        self.reroll_with(previous, hero_points, &mut || roll_d20(rng))
+    }
+
+    fn roll_with(&self, next: &mut impl FnMut() -> u8) -> CheckOutcome {
+        let mut rolls = Vec::new();
+        let natural = self.roll_natural(next, &mut rolls);
+        let total = natural as i32 + self.modifier;
+
+        CheckOutcome {
+            rolls,
+            natural,
+            total,
+            degree: check_degree(total, self.dc, natural as i32),
+            fortune_applied: self.mode() == RollMode::Fortune,
+        }
+    }
+
+    fn reroll_with(
+        &self,
+        previous: &CheckOutcome,
+        hero_points: &mut HeroPoints,
+        next: &mut impl FnMut() -> u8,
+    ) -> Result<CheckOutcome, RulesError> {
+        if previous.fortune_applied {
+            return Err(RulesError::FortuneAlreadyApplied);
+        }
+        if !hero_points.spend() {
+            return Err(RulesError::NoHeroPoints);
+        }
+
+        let mut outcome = self.roll_with(next);
+        let mut rolls = previous.rolls.clone();
+        rolls.append(&mut outcome.rolls);
+        outcome.rolls = rolls;
+        outcome.fortune_applied = true;
+        Ok(outcome)
+    }
+
+    fn roll_natural(&self, next: &mut impl FnMut() -> u8, rolls: &mut Vec<u8>) -> u8 {
+        let first = next();
+        rolls.push(first);
+
+        match self.mode() {
+            RollMode::Normal => first,
+            RollMode::Fortune | RollMode::Misfortune => {
+                let second = next();
+                rolls.push(second);
+                if self.mode() == RollMode::Fortune {
+                    u8::max(first, second)
+                } else {
+                    u8::min(first, second)
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::DegreeOfSuccess::*;
+    use super::*;
+
+    fn scripted(rolls: &[u8]) -> impl FnMut() -> u8 + '_ {
+        let mut iter = rolls.iter().copied();
+        move || iter.next().unwrap()
+    }
+
+    #[test]
+    fn test_level_dc() {
+        assert_eq!(level_dc(0), Some(14));
+        assert_eq!(level_dc(1), Some(15));
+        assert_eq!(level_dc(3), Some(18));
+        assert_eq!(level_dc(10), Some(27));
+        assert_eq!(level_dc(20), Some(40));
+        assert_eq!(level_dc(25), Some(50));
+        assert_eq!(level_dc(-1), Some(13));
+        assert_eq!(level_dc(-2), None);
+        assert_eq!(level_dc(26), None);
+    }
+
+    #[test]
+    fn test_dc_adjustments() {
+        assert_eq!(simple_dc(Proficiency::Untrained), 10);
+        assert_eq!(simple_dc(Proficiency::Master), 30);
+
+        assert_eq!(DcAdjustment::VeryEasy.value(), -5);
+        assert_eq!(Rarity::Common.adjust(18), 18);
+        assert_eq!(Rarity::Uncommon.adjust(18), 20);
+        assert_eq!(Rarity::Rare.adjust(18), 23);
+        assert_eq!(Rarity::Unique.adjust(18), 28);
+    }
+
+    #[test]
+    fn test_normal_roll() {
+        let outcome = Check::new(5, 15).roll_with(&mut scripted(&[10]));
+
+        assert_eq!(outcome.rolls, vec![10]);
+        assert_eq!(outcome.natural, 10);
+        assert_eq!(outcome.total, 15);
+        assert_eq!(outcome.degree, Success);
+    }
+
+    #[test]
+    fn test_fortune_takes_higher() {
+        let check = Check::new(5, 15).with(RollModifier::Fortune);
+        let outcome = check.roll_with(&mut scripted(&[3, 12]));
+
+        assert_eq!(outcome.rolls, vec![3, 12]);
+        assert_eq!(outcome.natural, 12);
+        assert_eq!(outcome.degree, Success);
+    }
+
+    #[test]
+    fn test_misfortune_takes_lower() {
+        let check = Check::new(5, 15).with(RollModifier::Misfortune);
+        let outcome = check.roll_with(&mut scripted(&[20, 1]));
+
+        assert_eq!(outcome.rolls, vec![20, 1]);
+        assert_eq!(outcome.natural, 1);
+        // 6 vs 15 → Failure, 자연 1로 CriticalFailure
+        assert_eq!(outcome.degree, CriticalFailure);
+    }
+
+    #[test]
+    fn test_fortune_and_misfortune_cancel() {
+        let check = Check::new(5, 15)
+            .with(RollModifier::Fortune)
+            .with(RollModifier::Misfortune);
+        let outcome = check.roll_with(&mut scripted(&[4]));
+
+        assert_eq!(outcome.rolls, vec![4]);
+        assert_eq!(outcome.degree, Failure);
+    }
+
+    #[test]
+    fn test_hero_point_reroll() {
+        let check = Check::new(5, 15);
+        let mut hero_points = HeroPoints::new(1);
+
+        let first = check.roll_with(&mut scripted(&[15]));
+        assert_eq!(first.degree, Success);
+
+        // 두 번째 결과가 더 나빠도 그대로 사용
+        let second = check
+            .reroll_with(&first, &mut hero_points, &mut scripted(&[2]))
+            .unwrap();
+        assert_eq!(second.rolls, vec![15, 2]);
+        assert_eq!(second.natural, 2);
+        assert_eq!(second.degree, Failure);
+        assert_eq!(hero_points.remaining(), 0);
+
+        // 다시 굴린 결과는 다시 굴릴 수 없음
+        let mut more = HeroPoints::new(3);
+        assert_eq!(
+            check.reroll_with(&second, &mut more, &mut scripted(&[20])),
+            Err(RulesError::FortuneAlreadyApplied)
+        );
+        assert_eq!(more.remaining(), 3);
+    }
+
+    #[test]
+    fn test_reroll_requires_hero_point() {
+        let check = Check::new(0, 10);
+        let mut hero_points = HeroPoints::new(0);
+        let first = check.roll_with(&mut scripted(&[5]));
+
+        assert_eq!(
+            check.reroll_with(&first, &mut hero_points, &mut scripted(&[20])),
+            Err(RulesError::NoHeroPoints)
+        );
+    }
+
+    #[test]
+    fn test_reroll_not_allowed_after_fortune() {
+        let check = Check::new(0, 10).with(RollModifier::Fortune);
+        let mut hero_points = HeroPoints::new(1);
+        let first = check.roll_with(&mut scripted(&[5, 6]));
+
+        assert_eq!(
+            check.reroll_with(&first, &mut hero_points, &mut scripted(&[20, 20])),
+            Err(RulesError::FortuneAlreadyApplied)
+        );
+    }
+
+    #[test]
+    fn test_reroll_keeps_misfortune() {
+        // 불운 판정을 다시 굴리면 다시 두 번 굴려 낮은 값
+        let check = Check::new(0, 10).with(RollModifier::Misfortune);
+        let mut hero_points = HeroPoints::new(1);
+        let first = check.roll_with(&mut scripted(&[5, 6]));
+        let second = check
+            .reroll_with(&first, &mut hero_points, &mut scripted(&[18, 12]))
+            .unwrap();
+
+        assert_eq!(second.rolls, vec![5, 6, 18, 12]);
+        assert_eq!(second.natural, 12);
+        assert_eq!(second.degree, Success);
+    }
+
+    #[test]
+    fn test_hero_points_capped() {
+        let mut hero_points = HeroPoints::new(5);
+        assert_eq!(hero_points.remaining(), 3);
+
+        assert!(hero_points.spend());
+        hero_points.award(4);
+        assert_eq!(hero_points.remaining(), 3);
+    }
+}
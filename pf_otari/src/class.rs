@@ -0,0 +1,340 @@
+use crate::character::{Character, Proficiency, ability_modifier};
+use crate::check::SaveType;
+use crate::combat::{CombatTurn, Weapon};
+use crate::dice::Dice;
+use crate::duration::{ActiveEffect, BonusType, EffectDuration, Modifier, Statistic};
+use crate::error::RulesError;
+use crate::grid::GridPos;
+
+/// 근접 무기 클래스
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum Class {
+    Fighter,
+    Rogue,
+    Barbarian,
+    Champion,
+}
+
+impl Class {
+    /// 레벨당 클래스 HP
+    pub fn hit_points(&self) -> i32 {
+        match self {
+            Class::Fighter | Class::Champion => 10,
+            Class::Rogue => 8,
+            Class::Barbarian => 12,
+        }
+    }
+
+    /// 1레벨 지각 숙련도
+    pub fn perception(&self) -> Proficiency {
+        match self {
+            Class::Champion => Proficiency::Trained,
+            _ => Proficiency::Expert,
+        }
+    }
+
+    /// 1레벨 내성 숙련도
+    pub fn save(&self, save: SaveType) -> Proficiency {
+        let expert = match self {
+            Class::Fighter => [SaveType::Fortitude, SaveType::Reflex],
+            Class::Rogue => [SaveType::Reflex, SaveType::Will],
+            Class::Barbarian | Class::Champion => [SaveType::Fortitude, SaveType::Will],
+        };
+        if expert.contains(&save) {
+            Proficiency::Expert
+        } else {
+            Proficiency::Trained
+        }
+    }
+
+    /// 무기 숙련도, Fighter는 1레벨부터 Expert
+    pub fn weapon_proficiency(&self, level: i32) -> Proficiency {
+        let (expert, master, legendary) = match self {
+            Class::Fighter => (1, 5, Some(13)),
+            _ => (5, 13, None),
+        };
+        match level {
+            l if legendary.is_some_and(|legendary| l >= legendary) => Proficiency::Legendary,
+            l if l >= master => Proficiency::Master,
+            l if l >= expert => Proficiency::Expert,
+            _ => Proficiency::Trained,
+        }
+    }
+}
+
+fn require(character: &Character, class: Class) -> Result<(), RulesError> {
+    if character.class() == Some(class) {
+        Ok(())
+    } else {
+        Err(RulesError::WrongClass(class))
+    }
+}
+
+/// Sneak Attack 정밀 피해 주사위: 1d6, 5레벨 2d6, 11레벨 3d6, 17레벨 4d6
+pub fn sneak_attack_dice(level: i32) -> Dice {
+    let count = 1 + [5, 11, 17].iter().filter(|l| level >= **l).count();
+    Dice::new(count as u8, 6)
+}
+
+/// Off-Guard 대상을 Strike하는 Rogue의 추가 정밀 피해
+///
+/// Finesse 특성은 다루지 않으므로 agile 무기일 때만 적용한다.
+/// 정밀 피해는 무기 피해에 더해 치명타에서 함께 두 배가 된다.
+pub fn sneak_attack(attacker: &Character, weapon: &Weapon, target_off_guard: bool) -> Option<Dice> {
+    (attacker.class() == Some(Class::Rogue) && weapon.agile && target_off_guard)
+        .then(|| sneak_attack_dice(attacker.level()))
+}
+
+/// Rage 효과 이름, Rage가 끝나면 임시 HP도 사라진다
+pub const RAGE: &str = "Rage";
+/// Rage 근접 피해 보너스
+pub const RAGE_DAMAGE: i32 = 2;
+/// Rage AC 페널티
+pub const RAGE_AC_PENALTY: i32 = -1;
+
+/// Rage (1 액션): 임시 HP `레벨 + CON 수정값`을 얻고, 1분 동안 근접 피해 +2, AC -1
+///
+/// 반환된 효과를 분노한 참가자의 효과 목록에 더한다.
+pub fn rage<Id>(holder: Id, barbarian: &mut Character) -> Result<ActiveEffect<Id>, RulesError> {
+    require(barbarian, Class::Barbarian)?;
+    let temp_hp = barbarian.level() + ability_modifier(barbarian.stats().constitution);
+    barbarian.gain_temp_hp(temp_hp, RAGE)?;
+
+    Ok(ActiveEffect::new(RAGE, EffectDuration::minute(holder))
+        .with_modifier(Modifier::new(
+            Statistic::Damage,
+            BonusType::Untyped,
+            RAGE_DAMAGE,
+        ))
+        .with_modifier(Modifier::new(
+            Statistic::ArmorClass,
+            BonusType::Untyped,
+            RAGE_AC_PENALTY,
+        )))
+}
+
+/// 챔피언 리액션이 닿는 거리
+pub const CHAMPION_AURA_FEET: i32 = 15;
+
+/// Retributive Strike 결과
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct RetributiveStrike {
+    /// 아군이 얻은 모든 피해 저항 `2 + 레벨`
+    pub resistance: i32,
+    /// 저항을 뺀 뒤 아군이 받는 피해
+    pub damage_taken: i32,
+    /// 적이 근접 간격 안이면 챔피언이 Strike한다
+    pub can_strike: bool,
+}
+
+/// 챔피언 리액션 (Retributive Strike)
+///
+/// 15피트 안의 적이 15피트 안의 아군에게 피해를 주면, 아군은 그 피해에
+/// `2 + 레벨` 저항을 얻고 적이 간격 안이면 챔피언이 Strike한다.
+pub fn retributive_strike(
+    champion: &Character,
+    turn: &mut CombatTurn,
+    (champion_at, enemy_at, ally_at): (GridPos, GridPos, GridPos),
+    damage: i32,
+) -> Result<RetributiveStrike, RulesError> {
+    require(champion, Class::Champion)?;
+    if champion_at.distance_feet(enemy_at) > CHAMPION_AURA_FEET
+        || champion_at.distance_feet(ally_at) > CHAMPION_AURA_FEET
+    {
+        return Err(RulesError::OutOfReach);
+    }
+    turn.spend_reaction()?;
+
+    let resistance = 2 + champion.level();
+    Ok(RetributiveStrike {
+        resistance,
+        damage_taken: i32::max(damage - resistance, 0),
+        can_strike: champion_at.is_adjacent(enemy_at),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::character::{Attribute, Defense};
+    use crate::combat::calc_damage;
+    use crate::dice::DegreeOfSuccess;
+    use crate::duration::EffectRegistry;
+
+    fn character(class: Class, level: i32) -> Character {
+        Character::new(
+            8,
+            Attribute {
+                strength: 18,
+                dexterity: 14,
+                constitution: 16,
+                intelligence: 10,
+                wisdom: 12,
+                charisma: 10,
+            },
+            Defense {
+                armor_item: 3,
+                armor_proficiency: Proficiency::Trained,
+            },
+            Proficiency::Trained,
+        )
+        .with_level(level)
+        .unwrap()
+        .with_class(class)
+    }
+
+    fn shortsword() -> Weapon {
+        Weapon {
+            name: "Shortsword".to_string(),
+            damage: Dice::new(1, 6),
+            agile: true,
+        }
+    }
+
+    #[test]
+    fn test_fighter_weapon_expertise() {
+        // 1레벨 STR 18: Fighter +4 + Expert 5 = +9, 다른 클래스는 Trained +7
+        assert_eq!(character(Class::Fighter, 1).attack_bonus(), 9);
+        assert_eq!(character(Class::Rogue, 1).attack_bonus(), 7);
+
+        // 5레벨 Fighter Master, 13레벨 Legendary
+        let fighter = character(Class::Fighter, 1).with_level(5).unwrap();
+        assert_eq!(fighter.attack_bonus(), 4 + 6 + 5);
+        assert_eq!(
+            Class::Fighter.weapon_proficiency(13),
+            Proficiency::Legendary
+        );
+        assert_eq!(Class::Champion.weapon_proficiency(5), Proficiency::Expert);
+        assert_eq!(Class::Champion.weapon_proficiency(20), Proficiency::Master);
+    }
+
+    #[test]
+    fn test_class_hit_points_and_saves() {
+        // (클래스 HP + CON 3) × 레벨
+        assert_eq!(character(Class::Barbarian, 1).max_hp(), 15);
+        assert_eq!(character(Class::Rogue, 2).max_hp(), 22);
+
+        let rogue = character(Class::Rogue, 1);
+        assert_eq!(rogue.class(), Some(Class::Rogue));
+        // Reflex: DEX +2, Expert 5
+        assert_eq!(rogue.save_bonus(SaveType::Reflex), 7);
+        assert_eq!(rogue.save_bonus(SaveType::Fortitude), 6);
+        // 지각: WIS +1, Expert 5
+        assert_eq!(rogue.perception_bonus(), 6);
+        assert_eq!(character(Class::Champion, 1).perception_bonus(), 4);
+    }
+
+    #[test]
+    fn test_sneak_attack() {
+        assert_eq!(sneak_attack_dice(1), Dice::new(1, 6));
+        assert_eq!(sneak_attack_dice(5), Dice::new(2, 6));
+        assert_eq!(sneak_attack_dice(11), Dice::new(3, 6));
+        assert_eq!(sneak_attack_dice(17), Dice::new(4, 6));
+
+        let rogue = character(Class::Rogue, 1);
+        let sneak = sneak_attack(&rogue, &shortsword(), true).unwrap();
+        assert_eq!(sneak, Dice::new(1, 6));
+
+        // Shortsword 4 + Sneak Attack 3 + STR 4, 치명타면 정밀 피해도 두 배
+        assert_eq!(calc_damage(DegreeOfSuccess::Success, 4 + 3, 4), 11);
+        assert_eq!(calc_damage(DegreeOfSuccess::CriticalSuccess, 4 + 3, 4), 22);
+
+        assert_eq!(sneak_attack(&rogue, &shortsword(), false), None);
+        let mut longsword = shortsword();
+        longsword.agile = false;
+        assert_eq!(sneak_attack(&rogue, &longsword, true), None);
+        assert_eq!(
+            sneak_attack(&character(Class::Fighter, 1), &shortsword(), true),
+            None
+        );
+    }
+
+    #[test]
+    fn test_rage() {
+        let mut barbarian = character(Class::Barbarian, 1);
+        let ac = barbarian.ac();
+        let mut effects = EffectRegistry::new();
+        effects.add(rage(0, &mut barbarian).unwrap());
+
+        // 임시 HP 1 + CON 3, 피해 +2, AC -1
+        assert_eq!(barbarian.temp_hp(), 4);
+        assert_eq!(effects.modifier(Statistic::Damage), 2);
+        assert_eq!(ac + effects.modifier(Statistic::ArmorClass), ac - 1);
+
+        // 임시 HP가 먼저 깎인다
+        barbarian.take_damage(3).unwrap();
+        assert_eq!(barbarian.temp_hp(), 1);
+        assert_eq!(barbarian.current_hp(), 15);
+
+        // 1분 뒤 끝나고 남은 임시 HP도 사라진다
+        for _ in 0..10 {
+            for effect in effects.start_turn(0) {
+                barbarian.end_effect(&effect.name);
+            }
+        }
+        assert_eq!(effects.modifier(Statistic::Damage), 0);
+        assert_eq!(barbarian.temp_hp(), 0);
+
+        let mut fighter = character(Class::Fighter, 1);
+        assert_eq!(
+            rage(0, &mut fighter),
+            Err(RulesError::WrongClass(Class::Barbarian))
+        );
+        assert_eq!(fighter.temp_hp(), 0);
+    }
+
+    #[test]
+    fn test_retributive_strike() {
+        let champion = character(Class::Champion, 1);
+        let mut turn = CombatTurn::new();
+        let champion_at = GridPos::new(0, 0);
+        let ally_at = GridPos::new(2, 0);
+        let goblin_at = GridPos::new(1, 1);
+
+        // 고블린이 아군에게 8 피해: 저항 3
+        let reaction =
+            retributive_strike(&champion, &mut turn, (champion_at, goblin_at, ally_at), 8).unwrap();
+        assert_eq!(
+            reaction,
+            RetributiveStrike {
+                resistance: 3,
+                damage_taken: 5,
+                can_strike: true,
+            }
+        );
+        assert!(!turn.has_reaction());
+
+        // 리액션은 라운드에 한 번
+        assert_eq!(
+            retributive_strike(&champion, &mut turn, (champion_at, goblin_at, ally_at), 8),
+            Err(RulesError::ReactionUsed)
+        );
+
+        // 15피트 밖의 적
+        let mut turn = CombatTurn::new();
+        let far = GridPos::new(4, 0);
+        assert_eq!(
+            retributive_strike(&champion, &mut turn, (champion_at, far, ally_at), 8),
+            Err(RulesError::OutOfReach)
+        );
+        assert!(turn.has_reaction());
+
+        // 저항이 피해보다 크면 0, 멀리 있는 적에게는 Strike 불가
+        let near = GridPos::new(3, 0);
+        let reaction =
+            retributive_strike(&champion, &mut turn, (champion_at, near, ally_at), 2).unwrap();
+        assert_eq!(reaction.damage_taken, 0);
+        assert!(!reaction.can_strike);
+
+        assert_eq!(
+            retributive_strike(
+                &character(Class::Rogue, 1),
+                &mut CombatTurn::new(),
+                (champion_at, goblin_at, ally_at),
+                8
+            ),
+            Err(RulesError::WrongClass(Class::Champion))
+        );
+    }
+}
@@ -1,38 +1,269 @@
-use crate::dice::{check_degree, DegreeOfSuccess};
+use crate::character::Character;
+use crate::condition::Condition;
+use crate::dice::{DegreeOfSuccess, Dice, check_degree};
+use crate::error::RulesError;
+
+/// 한 턴의 기본 액션
+pub const ACTIONS_PER_TURN: u8 = 3;
+
+/// Quickened 추가 액션의 허용 목록에 쓰는 액션 종류
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ActionKind {
+    Strike,
+    Stride,
+    Step,
+    Interact,
+    RaiseShield,
+    Command,
+    Skill,
+}
+
+/// 액션 표시의 한 칸
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ActionSlot {
+    Available,
+    /// 허용 목록에만 쓸 수 있는 Quickened 추가 액션
+    Quickened,
+    Spent,
+    /// Stunned, Slowed로 잃은 액션
+    Lost,
+}
+
+impl ActionSlot {
+    pub fn symbol(&self) -> char {
+        match self {
+            ActionSlot::Available => '◆',
+            ActionSlot::Quickened => '◈',
+            ActionSlot::Spent => '◇',
+            ActionSlot::Lost => '✕',
+        }
+    }
+}
 
-struct CombatTurn {
+/// 한 턴의 액션과 리액션
+///
+/// ```
+/// use pf_otari::prelude::*;
+///
+/// let mut turn = CombatTurn::new();
+/// turn.spend_action(2).unwrap();
+///
+/// assert_eq!(turn.remaining_actions(), 1);
+/// assert_eq!(
+///     turn.spend_action(2),
+///     Err(RulesError::InsufficientActions { cost: 2, remaining: 1 })
+/// );
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct CombatTurn {
     actions: u8,
+    /// 턴을 시작할 때 받은 액션 (미니언은 2), Quickened 추가 액션과 잃은 액션을 포함한다
+    granted: u8,
+    /// Stunned, Slowed로 잃은 액션
+    lost: u8,
+    /// 아직 쓰지 않은 Quickened 추가 액션이 허용하는 액션
+    quickened: Option<Vec<ActionKind>>,
     reaction: bool,
+    /// 이번 턴에 Flourish 액션을 했는지
+    flourished: bool,
+}
+
+/// 상태 이상을 반영한 턴 시작
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct TurnStart {
+    pub turn: CombatTurn,
+    /// Stunned, Slowed로 잃은 액션
+    pub lost: u8,
+    /// 남은 Stunned 값, 다음 턴으로 넘어간다
+    pub stunned: u8,
 }
 
 impl CombatTurn {
-    fn new() -> Self {
+    pub fn new() -> Self {
+        Self::with_actions(ACTIONS_PER_TURN)
+    }
+
+    /// 액션 수가 다른 참가자 (미니언 등)
+    pub fn with_actions(actions: u8) -> Self {
         Self {
-            actions: 3,
+            actions,
+            granted: actions,
+            lost: 0,
+            quickened: None,
             reaction: true,
+            flourished: false,
         }
     }
 
-    fn remaining_actions(&self) -> u8 {
+    /// 상태 이상을 반영해 턴을 시작한다
+    ///
+    /// Quickened면 `quickened` 액션에만 쓸 수 있는 액션 하나를 더 얻는다. Stunned는 잃은 액션만큼
+    /// 값이 줄고, 함께 걸린 Slowed는 Stunned로 잃은 액션을 포함해 센다.
+    /// 잃는 액션은 일반 액션에서 먼저 뺀다.
+    ///
+    /// ```
+    /// use pf_otari::prelude::*;
+    ///
+    /// let start = CombatTurn::start(
+    ///     ACTIONS_PER_TURN,
+    ///     &[Condition::Stunned(1), Condition::Slowed(2)],
+    ///     &[],
+    /// );
+    /// assert_eq!(start.turn.remaining_actions(), 1);
+    /// assert_eq!(start.stunned, 0);
+    /// ```
+    pub fn start(actions: u8, conditions: &[Condition], quickened: &[ActionKind]) -> TurnStart {
+        let value = |kind: Condition| {
+            conditions
+                .iter()
+                .filter(|c| c.same_kind(&kind))
+                .filter_map(Condition::value)
+                .max()
+                .unwrap_or(0)
+        };
+        let mut turn = Self::with_actions(actions);
+        if conditions.contains(&Condition::Quickened) && !quickened.is_empty() {
+            turn.quickened = Some(quickened.to_vec());
+        }
+
+        let total = turn.total_actions();
+        turn.granted = total;
+        let stunned = value(Condition::Stunned(0));
+        let stun_lost = stunned.min(total);
+        let lost = stun_lost.max(value(Condition::Slowed(0))).min(total);
+
+        let from_actions = lost.min(turn.actions);
+        turn.actions -= from_actions;
+        if lost > from_actions {
+            turn.quickened = None;
+        }
+        turn.lost = lost;
+        TurnStart {
+            turn,
+            lost,
+            stunned: stunned - stun_lost,
+        }
+    }
+
+    /// 일반 액션, Quickened 추가 액션은 세지 않는다
+    pub fn remaining_actions(&self) -> u8 {
         self.actions
     }
 
-    fn spend_action(&mut self, cost: u8) -> Result<(), ()> {
+    /// 턴을 시작할 때 받은 액션, 액션 표시의 칸 수 (Quickened 추가 액션과 잃은 액션 포함)
+    pub fn granted_actions(&self) -> u8 {
+        self.granted
+    }
+
+    /// 남은 일반 액션, 남은 Quickened 추가 액션, 쓴 액션, 잃은 액션 순서의 칸
+    ///
+    /// ```
+    /// use pf_otari::prelude::*;
+    ///
+    /// let mut turn = CombatTurn::start(
+    ///     ACTIONS_PER_TURN,
+    ///     &[Condition::Quickened, Condition::Slowed(1)],
+    ///     &[ActionKind::Strike],
+    /// )
+    /// .turn;
+    /// turn.spend_action(1).unwrap();
+    /// let bar: String = turn.action_slots().iter().map(ActionSlot::symbol).collect();
+    /// assert_eq!(bar, "◆◈◇✕");
+    /// ```
+    pub fn action_slots(&self) -> Vec<ActionSlot> {
+        let spent = self.granted - self.lost - self.total_actions();
+        let mut slots = vec![ActionSlot::Available; usize::from(self.actions)];
+        if self.quickened.is_some() {
+            slots.push(ActionSlot::Quickened);
+        }
+        slots.extend(std::iter::repeat_n(ActionSlot::Spent, usize::from(spent)));
+        slots.extend(std::iter::repeat_n(
+            ActionSlot::Lost,
+            usize::from(self.lost),
+        ));
+        slots
+    }
+
+    /// Quickened 추가 액션을 포함한 남은 액션
+    pub fn total_actions(&self) -> u8 {
+        self.actions + u8::from(self.quickened.is_some())
+    }
+
+    /// 아직 쓰지 않은 Quickened 추가 액션이 허용하는 액션
+    pub fn quickened_actions(&self) -> Option<&[ActionKind]> {
+        self.quickened.as_deref()
+    }
+
+    /// `kind`에 쓸 수 있는 남은 액션
+    pub fn actions_for(&self, kind: ActionKind) -> u8 {
+        self.actions + u8::from(self.allows_quickened(kind))
+    }
+
+    fn allows_quickened(&self, kind: ActionKind) -> bool {
+        self.quickened.as_ref().is_some_and(|q| q.contains(&kind))
+    }
+
+    pub fn spend_action(&mut self, cost: u8) -> Result<(), RulesError> {
         if cost > self.actions {
-            Err(())
+            Err(RulesError::InsufficientActions {
+                cost,
+                remaining: self.actions,
+            })
         } else {
             self.actions -= cost;
             Ok(())
         }
     }
 
-    fn has_reaction(&self) -> bool {
+    /// Quickened 추가 액션을 먼저 쓰고, 모자란 만큼 일반 액션을 쓴다
+    pub fn spend_action_for(&mut self, kind: ActionKind, cost: u8) -> Result<(), RulesError> {
+        if cost == 0 || !self.allows_quickened(kind) {
+            return self.spend_action(cost);
+        }
+        if cost - 1 > self.actions {
+            return Err(RulesError::InsufficientActions {
+                cost,
+                remaining: self.actions_for(kind),
+            });
+        }
+        self.quickened = None;
+        self.actions -= cost - 1;
+        Ok(())
+    }
+
+    /// Quickened 추가 액션을 쓴다, 허용 목록에 없는 액션이면 실패
+    pub fn spend_quickened(&mut self, kind: ActionKind) -> Result<(), RulesError> {
+        match &self.quickened {
+            None => Err(RulesError::NoQuickenedAction),
+            Some(allowed) if !allowed.contains(&kind) => Err(RulesError::QuickenedNotAllowed(kind)),
+            Some(_) => {
+                self.quickened = None;
+                Ok(())
+            }
+        }
+    }
+
+    pub fn has_reaction(&self) -> bool {
         self.reaction
     }
 
-    fn spend_reaction(&mut self) -> Result<(), ()> {
+    pub fn flourished(&self) -> bool {
+        self.flourished
+    }
+
+    /// Flourish 액션은 턴마다 하나, 액션이 모자라면 Flourish도 쓰지 않는다
+    pub fn spend_flourish(&mut self, cost: u8) -> Result<(), RulesError> {
+        if self.flourished {
+            return Err(RulesError::FlourishUsed);
+        }
+        self.spend_action(cost)?;
+        self.flourished = true;
+        Ok(())
+    }
+
+    pub fn spend_reaction(&mut self) -> Result<(), RulesError> {
         if !self.reaction {
-            Err(())
+            Err(RulesError::ReactionUsed)
         } else {
             self.reaction = false;
             Ok(())
@@ -40,18 +271,60 @@ impl CombatTurn {
     }
 }
 
+impl Default for CombatTurn {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// 공격명중굴림 판정
 ///
 /// # Arguments
 /// `roll` - 굴림
 /// `attack_bonus` - 공격 보너스
 /// `target_ac` - 대상 방어 수치 (armor class)
-fn strike(roll: u8, attack_bonus: i32, target_ac: i32) -> DegreeOfSuccess {
+///
+/// ```
+/// use pf_otari::prelude::*;
+///
+/// assert_eq!(strike(12, 7, 18), Ok(DegreeOfSuccess::Success));
+/// assert_eq!(strike(20, 7, 18), Ok(DegreeOfSuccess::CriticalSuccess));
+/// assert_eq!(strike(0, 7, 18), Err(RulesError::InvalidRoll(0)));
+/// ```
+pub fn strike(roll: u8, attack_bonus: i32, target_ac: i32) -> Result<DegreeOfSuccess, RulesError> {
+    if !(1..=20).contains(&roll) {
+        return Err(RulesError::InvalidRoll(roll));
+    }
     let roll = roll as i32;
-    check_degree(roll + attack_bonus, target_ac, roll)
+    Ok(check_degree(roll + attack_bonus, target_ac, roll))
 }
 
-fn calc_damage(degree: DegreeOfSuccess, dice_roll: i32, bonus: i32) -> i32 {
+/// 캐릭터 대상 Strike, 의식이 없는 대상은 공격할 수 없다
+pub fn strike_character(
+    roll: u8,
+    attack_bonus: i32,
+    target: &Character,
+) -> Result<DegreeOfSuccess, RulesError> {
+    if !target.is_conscious() {
+        return Err(RulesError::TargetUnconscious);
+    }
+    strike(roll, attack_bonus, target.ac())
+}
+
+/// 무기 피해 계산
+///
+/// # Arguments
+/// `degree` - 명중 판정 결과
+/// `dice_roll` - 피해 주사위 굴림 합계
+/// `bonus` - 피해 보너스 (힘 수정치, 아이템 보너스 등)
+///
+/// ```
+/// use pf_otari::prelude::*;
+///
+/// assert_eq!(calc_damage(DegreeOfSuccess::CriticalSuccess, 5, 3), 16);
+/// assert_eq!(calc_damage(DegreeOfSuccess::Failure, 5, 3), 0);
+/// ```
+pub fn calc_damage(degree: DegreeOfSuccess, dice_roll: i32, bonus: i32) -> i32 {
     let p = match degree {
         DegreeOfSuccess::CriticalSuccess => 2,
         DegreeOfSuccess::Success => 1,
@@ -65,6 +338,29 @@ fn calc_damage(degree: DegreeOfSuccess, dice_roll: i32, bonus: i32) -> i32 {
     }
 }
 
+/// 무기
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Weapon {
+    pub name: String,
+    pub damage: Dice,
+    pub agile: bool,
+}
+
+/// 다중 공격 페널티 (Multiple Attack Penalty)
+///
+/// # Arguments
+/// `strikes_made` - 이번 턴에 이미 한 Strike 횟수
+/// `agile` - Agile 무기 여부
+pub fn multiple_attack_penalty(strikes_made: u8, agile: bool) -> i32 {
+    match (strikes_made, agile) {
+        (0, _) => 0,
+        (1, false) => -5,
+        (1, true) => -4,
+        (_, false) => -10,
+        (_, true) => -8,
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -76,6 +372,14 @@ mod tests {
         assert_eq!(turn.remaining_actions(), 3);
     }
 
+    #[test]
+    fn test_granted_actions() {
+        let mut turn = CombatTurn::with_actions(2);
+        turn.spend_action(1).unwrap();
+        assert_eq!((turn.remaining_actions(), turn.granted_actions()), (1, 2));
+        assert_eq!(CombatTurn::new().granted_actions(), ACTIONS_PER_TURN);
+    }
+
     #[test]
     fn test_spend_action() {
         let mut turn = CombatTurn::new();
@@ -95,7 +399,88 @@ mod tests {
         assert_eq!(turn.remaining_actions(), 1);
 
         let action_result = turn.spend_action(2);
-        assert!(action_result.is_err());
+        assert_eq!(
+            action_result,
+            Err(RulesError::InsufficientActions {
+                cost: 2,
+                remaining: 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_slowed_and_stunned() {
+        use Condition::*;
+
+        let start = CombatTurn::start(3, &[Slowed(1)], &[]);
+        assert_eq!((start.turn.remaining_actions(), start.lost), (2, 1));
+
+        // Stunned 4는 이번 턴 3 액션을 모두 빼앗고 1이 남는다
+        let start = CombatTurn::start(3, &[Stunned(4)], &[]);
+        assert_eq!((start.turn.remaining_actions(), start.stunned), (0, 1));
+        let start = CombatTurn::start(3, &[Stunned(start.stunned)], &[]);
+        assert_eq!((start.turn.remaining_actions(), start.stunned), (2, 0));
+
+        // Stunned로 잃은 액션은 Slowed에 포함된다
+        let start = CombatTurn::start(3, &[Stunned(2), Slowed(1)], &[]);
+        assert_eq!((start.turn.remaining_actions(), start.lost), (1, 2));
+    }
+
+    #[test]
+    fn test_quickened() {
+        let haste = [ActionKind::Strike, ActionKind::Stride];
+        let mut turn = CombatTurn::start(3, &[Condition::Quickened], &haste).turn;
+        assert_eq!(turn.total_actions(), 4);
+        assert_eq!(turn.actions_for(ActionKind::RaiseShield), 3);
+
+        assert_eq!(
+            turn.spend_quickened(ActionKind::RaiseShield),
+            Err(RulesError::QuickenedNotAllowed(ActionKind::RaiseShield))
+        );
+        turn.spend_action_for(ActionKind::Stride, 2).unwrap();
+        assert_eq!(turn.remaining_actions(), 2);
+        assert_eq!(turn.quickened_actions(), None);
+        assert_eq!(
+            turn.spend_quickened(ActionKind::Strike),
+            Err(RulesError::NoQuickenedAction)
+        );
+
+        // 허용 목록이 없으면 추가 액션도 없다
+        let turn = CombatTurn::start(3, &[Condition::Quickened], &[]).turn;
+        assert_eq!(turn.total_actions(), 3);
+
+        // Slowed는 일반 액션을 먼저 빼앗는다
+        let start = CombatTurn::start(3, &[Condition::Quickened, Condition::Slowed(3)], &haste);
+        assert_eq!(start.turn.total_actions(), 1);
+        assert_eq!(start.turn.actions_for(ActionKind::Strike), 1);
+    }
+
+    #[test]
+    fn test_action_slots() {
+        use ActionSlot::*;
+
+        let haste = [ActionKind::Strike, ActionKind::Stride];
+        let mut turn = CombatTurn::start(3, &[Condition::Quickened], &haste).turn;
+        assert_eq!(turn.granted_actions(), 4);
+        assert_eq!(
+            turn.action_slots(),
+            vec![Available, Available, Available, Quickened]
+        );
+        turn.spend_action_for(ActionKind::Strike, 1).unwrap();
+        assert_eq!(
+            turn.action_slots(),
+            vec![Available, Available, Available, Spent]
+        );
+
+        // Stunned 2: 잃은 액션은 따로 표시한다
+        let mut turn = CombatTurn::start(3, &[Condition::Stunned(2)], &[]).turn;
+        assert_eq!(turn.action_slots(), vec![Available, Lost, Lost]);
+        turn.spend_action(1).unwrap();
+        assert_eq!(turn.action_slots(), vec![Spent, Lost, Lost]);
+
+        // 일반 액션보다 많이 잃으면 Quickened 추가 액션도 잃는다
+        let turn = CombatTurn::start(3, &[Condition::Quickened, Condition::Slowed(4)], &haste).turn;
+        assert_eq!(turn.action_slots(), vec![Lost, Lost, Lost, Lost]);
     }
 
     #[test]
@@ -113,7 +498,7 @@ mod tests {
         assert!(reaction_result.is_ok());
 
         let reaction_result = turn.spend_reaction();
-        assert!(reaction_result.is_err());
+        assert_eq!(reaction_result, Err(RulesError::ReactionUsed));
     }
 
     use super::DegreeOfSuccess::*;
@@ -121,43 +506,80 @@ mod tests {
     #[test]
     fn test_strike() {
         // roll = 20
-        assert_eq!(strike(20, 0, 20), CriticalSuccess);
+        assert_eq!(strike(20, 0, 20), Ok(CriticalSuccess));
 
         // roll + attack_bonus >= target_ac -> Success
-        assert_eq!(strike(10, 2, 11), Success);
+        assert_eq!(strike(10, 2, 11), Ok(Success));
 
         // roll + attack_bonus < target_ac -> Failure
-        assert_eq!(strike(10, 2, 13), Failure);
+        assert_eq!(strike(10, 2, 13), Ok(Failure));
 
         // roll + attack_bonus >= target_ac + 10 -> CriticalSuccess
-        assert_eq!(strike(10, 10, 10), CriticalSuccess);
+        assert_eq!(strike(10, 10, 10), Ok(CriticalSuccess));
 
         // roll = 1 -> 강등
-        assert_eq!(strike(1, 15, 10), Failure);
+        assert_eq!(strike(1, 15, 10), Ok(Failure));
 
         // 정확히 AC와 동일 (경계)
-        assert_eq!(strike(10, 0, 10), Success);
+        assert_eq!(strike(10, 0, 10), Ok(Success));
 
         // AC보다 1 부족 (경계)
-        assert_eq!(strike(10, 0, 11), Failure);
+        assert_eq!(strike(10, 0, 11), Ok(Failure));
 
         // 차이 -10 (경계)
-        assert_eq!(strike(10, 0, 20), CriticalFailure);
+        assert_eq!(strike(10, 0, 20), Ok(CriticalFailure));
 
         // 차이 -10 초과
-        assert_eq!(strike(10, 0, 21), CriticalFailure);
+        assert_eq!(strike(10, 0, 21), Ok(CriticalFailure));
 
         // 차이 +9, CriticalSuccess 아님 (경계)
-        assert_eq!(strike(10, 9, 10), Success);
+        assert_eq!(strike(10, 9, 10), Ok(Success));
 
         // roll=20, 기본 CriticalFailure → ascend → Failure
-        assert_eq!(strike(20, 0, 30), Failure);
+        assert_eq!(strike(20, 0, 30), Ok(Failure));
 
         // roll=1, 기본 CriticalSuccess → descend → Success
-        assert_eq!(strike(1, 20, 10), Success);
+        assert_eq!(strike(1, 20, 10), Ok(Success));
 
         // roll=1, 기본 Failure → descend → CriticalFailure
-        assert_eq!(strike(1, 0, 10), CriticalFailure);
+        assert_eq!(strike(1, 0, 10), Ok(CriticalFailure));
+    }
+
+    #[test]
+    fn test_strike_invalid_roll() {
+        assert_eq!(strike(0, 5, 15), Err(RulesError::InvalidRoll(0)));
+        assert_eq!(strike(21, 5, 15), Err(RulesError::InvalidRoll(21)));
+    }
+
+    #[test]
+    fn test_strike_character() {
+        use crate::character::{Attribute, Defense, Proficiency};
+
+        let mut target = Character::new(
+            8,
+            Attribute {
+                strength: 10,
+                dexterity: 14,
+                constitution: 10,
+                intelligence: 10,
+                wisdom: 10,
+                charisma: 10,
+            },
+            Defense {
+                armor_item: 1,
+                armor_proficiency: Proficiency::Trained,
+            },
+            Proficiency::Trained,
+        );
+
+        // AC 16
+        assert_eq!(strike_character(10, 6, &target), Ok(Success));
+
+        target.take_damage(100).unwrap();
+        assert_eq!(
+            strike_character(10, 6, &target),
+            Err(RulesError::TargetUnconscious)
+        );
     }
 
     #[test]
@@ -188,4 +610,16 @@ mod tests {
         // Failure는 음수여도 0
         assert_eq!(calc_damage(Failure, 1, -5), 0);
     }
+
+    #[test]
+    fn test_multiple_attack_penalty() {
+        assert_eq!(multiple_attack_penalty(0, false), 0);
+        assert_eq!(multiple_attack_penalty(1, false), -5);
+        assert_eq!(multiple_attack_penalty(2, false), -10);
+        assert_eq!(multiple_attack_penalty(5, false), -10); // 3번째 이상은 동일
+
+        assert_eq!(multiple_attack_penalty(0, true), 0);
+        assert_eq!(multiple_attack_penalty(1, true), -4);
+        assert_eq!(multiple_attack_penalty(2, true), -8);
+    }
 }
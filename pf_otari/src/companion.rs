@@ -0,0 +1,137 @@
+use crate::combat::{ActionKind, CombatTurn};
+use crate::error::RulesError;
+
+/// 명령을 받은 미니언의 액션
+pub const MINION_ACTIONS: u8 = 2;
+/// Command an Animal, Command a Minion 등 명령에 드는 주인의 액션
+pub const COMMAND_ACTIONS: u8 = 1;
+
+/// 미니언 종류
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum MinionKind {
+    AnimalCompanion,
+    /// 명령을 받지 않아도 1 액션을 쓴다
+    MatureCompanion,
+    Familiar,
+    Summoned,
+}
+
+impl MinionKind {
+    /// 명령을 받지 않은 턴의 액션
+    pub fn idle_actions(&self) -> u8 {
+        match self {
+            MinionKind::MatureCompanion => 1,
+            MinionKind::AnimalCompanion | MinionKind::Familiar | MinionKind::Summoned => 0,
+        }
+    }
+}
+
+/// 미니언 특성을 가진 동료와 소환수
+///
+/// 우선권을 굴리지 않고 주인의 턴에 행동한다. 주인이 1 액션으로 명령하면 그 턴에 2 액션을 얻는다.
+///
+/// ```
+/// use pf_otari::prelude::*;
+///
+/// let mut wolf = Minion::new("Druid", MinionKind::AnimalCompanion);
+/// let mut druid = CombatTurn::new();
+///
+/// assert_eq!(wolf.start_master_turn().remaining_actions(), 0);
+/// let wolf_turn = wolf.command(&"Druid", &mut druid).unwrap();
+///
+/// assert_eq!(wolf_turn.remaining_actions(), 2);
+/// assert_eq!(druid.remaining_actions(), 2);
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Minion<Id> {
+    pub master: Id,
+    pub kind: MinionKind,
+    commanded: bool,
+}
+
+impl<Id: PartialEq> Minion<Id> {
+    pub fn new(master: Id, kind: MinionKind) -> Self {
+        Self {
+            master,
+            kind,
+            commanded: false,
+        }
+    }
+
+    /// 주인의 턴이 시작될 때의 미니언 턴
+    pub fn start_master_turn(&mut self) -> CombatTurn {
+        self.commanded = false;
+        CombatTurn::with_actions(self.kind.idle_actions())
+    }
+
+    pub fn is_commanded(&self) -> bool {
+        self.commanded
+    }
+
+    /// 주인의 액션 하나로 명령한다, 한 턴에 한 번만 받을 수 있다
+    pub fn command(
+        &mut self,
+        commander: &Id,
+        master_turn: &mut CombatTurn,
+    ) -> Result<CombatTurn, RulesError> {
+        if *commander != self.master {
+            return Err(RulesError::NotYourMinion);
+        }
+        if self.commanded {
+            return Err(RulesError::AlreadyCommanded);
+        }
+        master_turn.spend_action_for(ActionKind::Command, COMMAND_ACTIONS)?;
+        self.commanded = true;
+        Ok(CombatTurn::with_actions(MINION_ACTIONS))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_command() {
+        let mut familiar = Minion::new(1, MinionKind::Familiar);
+        let mut master = CombatTurn::new();
+
+        assert_eq!(familiar.start_master_turn().remaining_actions(), 0);
+        assert_eq!(
+            familiar.command(&2, &mut CombatTurn::new()),
+            Err(RulesError::NotYourMinion)
+        );
+
+        let turn = familiar.command(&1, &mut master).unwrap();
+        assert_eq!(turn.remaining_actions(), MINION_ACTIONS);
+        assert_eq!(master.remaining_actions(), 2);
+        assert!(familiar.is_commanded());
+        assert_eq!(
+            familiar.command(&1, &mut master),
+            Err(RulesError::AlreadyCommanded)
+        );
+        assert_eq!(master.remaining_actions(), 2);
+
+        // 다음 턴에는 다시 명령할 수 있다
+        familiar.start_master_turn();
+        assert!(!familiar.is_commanded());
+
+        let mut spent = CombatTurn::with_actions(0);
+        assert_eq!(
+            familiar.command(&1, &mut spent),
+            Err(RulesError::InsufficientActions {
+                cost: 1,
+                remaining: 0
+            })
+        );
+        assert!(!familiar.is_commanded());
+    }
+
+    #[test]
+    fn test_mature_companion_acts_alone() {
+        let mut wolf = Minion::new("Ranger", MinionKind::MatureCompanion);
+        assert_eq!(wolf.start_master_turn().remaining_actions(), 1);
+        let turn = wolf.command(&"Ranger", &mut CombatTurn::new()).unwrap();
+        assert_eq!(turn.remaining_actions(), 2);
+    }
+}
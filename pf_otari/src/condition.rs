@@ -0,0 +1,73 @@
+/// Off-Guard의 AC 상황 페널티
+pub const OFF_GUARD_AC_PENALTY: i32 = 2;
+
+/// 상태 이상 (Condition)
+///
+/// 값이 있는 상태는 값을 함께 가진다 (예: Frightened 2)
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum Condition {
+    Blinded,
+    Clumsy(u8),
+    Dazzled,
+    Deafened,
+    Drained(u8),
+    Dying(u8),
+    Enfeebled(u8),
+    Fatigued,
+    Frightened(u8),
+    Grabbed,
+    Immobilized,
+    OffGuard,
+    Prone,
+    Quickened,
+    Restrained,
+    Sickened(u8),
+    Slowed(u8),
+    Stunned(u8),
+    Stupefied(u8),
+    Unconscious,
+    Wounded(u8),
+}
+
+impl Condition {
+    /// 값을 무시하고 같은 종류의 상태인지
+    pub fn same_kind(&self, other: &Condition) -> bool {
+        std::mem::discriminant(self) == std::mem::discriminant(other)
+    }
+
+    /// 상태의 값, 값이 없는 상태는 `None`
+    pub fn value(&self) -> Option<u8> {
+        match self {
+            Condition::Clumsy(v)
+            | Condition::Drained(v)
+            | Condition::Dying(v)
+            | Condition::Enfeebled(v)
+            | Condition::Frightened(v)
+            | Condition::Sickened(v)
+            | Condition::Slowed(v)
+            | Condition::Stunned(v)
+            | Condition::Stupefied(v)
+            | Condition::Wounded(v) => Some(*v),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::Condition::*;
+
+    #[test]
+    fn test_same_kind() {
+        assert!(Frightened(1).same_kind(&Frightened(3)));
+        assert!(!Frightened(1).same_kind(&Sickened(1)));
+        assert!(Prone.same_kind(&Prone));
+    }
+
+    #[test]
+    fn test_value() {
+        assert_eq!(Frightened(2).value(), Some(2));
+        assert_eq!(Prone.value(), None);
+    }
+}
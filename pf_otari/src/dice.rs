@@ -1,4 +1,11 @@
-#[derive(Debug, PartialEq, Eq)]
+use std::fmt;
+use std::str::FromStr;
+
+use rand::{Rng, RngExt};
+
+use crate::error::RulesError;
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
 pub enum DegreeOfSuccess {
     CriticalSuccess,
     Success,
@@ -7,7 +14,8 @@ pub enum DegreeOfSuccess {
 }
 
 impl DegreeOfSuccess {
-    fn ascend(&self) -> Self {
+    /// 한 단계 상승
+    pub fn ascend(&self) -> Self {
         match self {
             DegreeOfSuccess::CriticalSuccess => Self::CriticalSuccess,
             DegreeOfSuccess::Success => Self::CriticalSuccess,
@@ -16,7 +24,8 @@ impl DegreeOfSuccess {
         }
     }
 
-    fn descend(&self) -> Self {
+    /// 한 단계 하강
+    pub fn descend(&self) -> Self {
         match self {
             DegreeOfSuccess::CriticalSuccess => Self::Success,
             DegreeOfSuccess::Success => Self::Failure,
@@ -32,12 +41,20 @@ impl DegreeOfSuccess {
 /// * `total` - d20 굴림 + 보너스 합계
 /// * `dc` - 난이도 (difficulty class)
 /// * `natural_roll` - d20 굴림값, 보너스 미포함
+///
+/// ```
+/// use pf_otari::prelude::*;
+///
+/// assert_eq!(check_degree(25, 15, 10), DegreeOfSuccess::CriticalSuccess);
+/// // 자연 20은 한 단계 상승
+/// assert_eq!(check_degree(20, 25, 20), DegreeOfSuccess::Success);
+/// ```
 pub fn check_degree(total: i32, dc: i32, natural_roll: i32) -> DegreeOfSuccess {
     let diff = total - dc;
 
     let success = if diff >= 10 {
         DegreeOfSuccess::CriticalSuccess
-    } else if diff >= 0 && diff < 10 {
+    } else if (0..10).contains(&diff) {
         DegreeOfSuccess::Success
     } else if diff > -10 && diff < 0 {
         DegreeOfSuccess::Failure
@@ -54,6 +71,56 @@ pub fn check_degree(total: i32, dc: i32, natural_roll: i32) -> DegreeOfSuccess {
     }
 }
 
+/// 피해 주사위 표기 (예: 1d8, 2d6)
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct Dice {
+    pub count: u8,
+    pub sides: u8,
+}
+
+impl Dice {
+    /// # Panics
+    /// 주사위 개수나 면 수가 0이면 패닉, 입력에서 읽을 때는 `parse`를 쓴다
+    pub fn new(count: u8, sides: u8) -> Self {
+        assert!(count > 0 && sides > 0, "invalid dice: {count}d{sides}");
+        Self { count, sides }
+    }
+
+    /// 주사위를 모두 굴린 합계
+    pub fn roll(&self, rng: &mut impl Rng) -> i32 {
+        (0..self.count)
+            .map(|_| rng.random_range(1..=self.sides as i32))
+            .sum()
+    }
+}
+
+impl fmt::Display for Dice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}d{}", self.count, self.sides)
+    }
+}
+
+/// `1d8` 같은 표기를 읽는다
+impl FromStr for Dice {
+    type Err = RulesError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || RulesError::InvalidDice(s.to_string());
+        let (count, sides) = s.split_once('d').ok_or_else(invalid)?;
+        let count: u8 = count.parse().map_err(|_| invalid())?;
+        let sides: u8 = sides.parse().map_err(|_| invalid())?;
+        if count == 0 || sides == 0 {
+            return Err(invalid());
+        }
+        Ok(Self::new(count, sides))
+    }
+}
+
+/// d20 한 번 굴림
+pub fn roll_d20(rng: &mut impl Rng) -> u8 {
+    rng.random_range(1..=20)
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -80,4 +147,43 @@ mod tests {
         assert_eq!(check_degree(25, 15, 1), Success);
         assert_eq!(check_degree(5, 15, 1), CriticalFailure);
     }
+
+    #[test]
+    fn test_dice_roll_range() {
+        use rand::SeedableRng;
+        use rand::rngs::StdRng;
+
+        let mut rng = StdRng::seed_from_u64(7);
+        let dice = Dice::new(2, 6);
+
+        for _ in 0..1000 {
+            let total = dice.roll(&mut rng);
+            assert!((2..=12).contains(&total)); // 2d6 범위
+            assert!((1..=20).contains(&roll_d20(&mut rng)));
+        }
+    }
+
+    #[test]
+    fn test_dice_notation() {
+        assert_eq!("2d6".parse(), Ok(Dice::new(2, 6)));
+        assert_eq!(Dice::new(1, 8).to_string(), "1d8");
+        for invalid in ["d6", "2d", "0d6", "1d8+4", "sword"] {
+            assert_eq!(
+                invalid.parse::<Dice>(),
+                Err(RulesError::InvalidDice(invalid.to_string()))
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid dice: 0d6")]
+    fn test_dice_without_count() {
+        Dice::new(0, 6);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid dice: 1d0")]
+    fn test_dice_without_sides() {
+        Dice::new(1, 0);
+    }
 }
@@ -0,0 +1,446 @@
+use rand::Rng;
+
+use crate::character::{Character, Proficiency, Skill};
+use crate::check::{Check, CheckOutcome, level_dc};
+use crate::dice::{DegreeOfSuccess, Dice};
+use crate::error::RulesError;
+
+/// 과제 레벨별 하루 수입 (cp): 실패, Trained, Expert, Master, Legendary
+const INCOME_PER_DAY: [[u32; 5]; 21] = [
+    [1, 5, 5, 5, 5],
+    [2, 20, 20, 20, 20],
+    [4, 30, 30, 30, 30],
+    [8, 50, 50, 50, 50],
+    [10, 70, 80, 80, 80],
+    [20, 90, 100, 100, 100],
+    [30, 150, 200, 200, 200],
+    [40, 200, 250, 250, 250],
+    [50, 250, 300, 300, 300],
+    [60, 300, 400, 400, 400],
+    [70, 400, 500, 600, 600],
+    [80, 500, 600, 800, 800],
+    [90, 600, 800, 1000, 1000],
+    [100, 700, 1000, 1500, 1500],
+    [150, 800, 1500, 2000, 2000],
+    [200, 1000, 2000, 2800, 2800],
+    [250, 1300, 2500, 3600, 4000],
+    [300, 1500, 3000, 4500, 5500],
+    [400, 2000, 4500, 7000, 9000],
+    [600, 3000, 6000, 10000, 13000],
+    [800, 4000, 7500, 15000, 20000],
+];
+
+/// 20레벨 과제 Critical Success 수입 (cp): Trained, Expert, Master, Legendary
+const INCOME_LEVEL_20_CRITICAL: [u32; 4] = [5000, 9000, 17500, 30000];
+
+/// 숙련도별 하루 수입 (cp), 과제 레벨은 0~20
+fn income_per_day(task_level: usize, proficiency: Proficiency) -> u32 {
+    let column = match proficiency {
+        Proficiency::Untrained => 0,
+        Proficiency::Trained => 1,
+        Proficiency::Expert => 2,
+        Proficiency::Master => 3,
+        Proficiency::Legendary => 4,
+    };
+    INCOME_PER_DAY[task_level][column]
+}
+
+/// Earn Income 결과
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct EarnIncome {
+    pub outcome: CheckOutcome,
+    pub per_day_cp: u32,
+    pub total_cp: u32,
+}
+
+/// 기술로 돈을 번다 (Earn Income)
+///
+/// 과제 레벨 DC로 한 번 판정하고, 결과에 따른 하루 수입을 `days`일 동안 받는다.
+/// Critical Success는 한 레벨 높은 과제의 수입, Failure는 실패 수입, Critical Failure는 0이다.
+pub fn earn_income(
+    character: &Character,
+    skill: Skill,
+    task_level: i32,
+    days: u32,
+    rng: &mut impl Rng,
+) -> Result<EarnIncome, RulesError> {
+    let proficiency = character.skill_proficiency(skill);
+    if proficiency < Proficiency::Trained {
+        return Err(RulesError::ProficiencyTooLow {
+            skill,
+            required: Proficiency::Trained,
+        });
+    }
+    if !(0..=20).contains(&task_level) {
+        return Err(RulesError::InvalidLevel(task_level));
+    }
+
+    let dc = level_dc(task_level).expect("0~20레벨 DC는 표에 있다");
+    let outcome = Check::new(character.skill_bonus(skill), dc).roll(rng);
+    let per_day_cp = income_for(outcome.degree, task_level as usize, proficiency);
+
+    Ok(EarnIncome {
+        outcome,
+        per_day_cp,
+        total_cp: per_day_cp * days,
+    })
+}
+
+fn income_for(degree: DegreeOfSuccess, task_level: usize, proficiency: Proficiency) -> u32 {
+    match degree {
+        DegreeOfSuccess::CriticalSuccess if task_level == 20 => {
+            INCOME_LEVEL_20_CRITICAL[critical_income_column(proficiency)]
+        }
+        DegreeOfSuccess::CriticalSuccess => income_per_day(task_level + 1, proficiency),
+        DegreeOfSuccess::Success => income_per_day(task_level, proficiency),
+        DegreeOfSuccess::Failure => income_per_day(task_level, Proficiency::Untrained),
+        DegreeOfSuccess::CriticalFailure => 0,
+    }
+}
+
+/// 20레벨 Critical Success 표의 열, Trained부터 시작
+fn critical_income_column(proficiency: Proficiency) -> usize {
+    match proficiency {
+        Proficiency::Untrained | Proficiency::Trained => 0,
+        Proficiency::Expert => 1,
+        Proficiency::Master => 2,
+        Proficiency::Legendary => 3,
+    }
+}
+
+/// 효과에 대한 일시적 면역, 휴식 시계(분) 기준
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Default)]
+pub struct Immunity {
+    until: Option<u32>,
+}
+
+impl Immunity {
+    pub fn minutes_left(&self, now: u32) -> u32 {
+        self.until.map_or(0, |until| until.saturating_sub(now))
+    }
+
+    fn grant(&mut self, now: u32, minutes: u32) {
+        self.until = Some(now + minutes);
+    }
+}
+
+/// Treat Wounds 결과
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct TreatWoundsResult {
+    pub outcome: CheckOutcome,
+    pub healed: i32,
+    pub damage: i32,
+}
+
+/// 상처 치료 (Treat Wounds), 숙련도에 따라 DC와 추가 회복량을 고른다
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct TreatWounds {
+    rank: Proficiency,
+}
+
+impl TreatWounds {
+    /// 치료에 걸리는 시간 (분)
+    pub const MINUTES: u32 = 10;
+    /// 치료받은 대상은 1시간 동안 다시 치료받을 수 없다
+    pub const IMMUNITY_MINUTES: u32 = 60;
+
+    pub fn new(rank: Proficiency) -> Result<Self, RulesError> {
+        if rank < Proficiency::Trained {
+            return Err(RulesError::ProficiencyTooLow {
+                skill: Skill::Medicine,
+                required: Proficiency::Trained,
+            });
+        }
+        Ok(Self { rank })
+    }
+
+    pub fn dc(&self) -> i32 {
+        match self.rank {
+            Proficiency::Untrained | Proficiency::Trained => 15,
+            Proficiency::Expert => 20,
+            Proficiency::Master => 30,
+            Proficiency::Legendary => 40,
+        }
+    }
+
+    /// 성공 시 추가 회복량
+    pub fn bonus_healing(&self) -> i32 {
+        match self.rank {
+            Proficiency::Untrained | Proficiency::Trained => 0,
+            Proficiency::Expert => 10,
+            Proficiency::Master => 30,
+            Proficiency::Legendary => 50,
+        }
+    }
+
+    /// `now`(분)에 치료를 시도한다. 결과와 관계없이 대상은 1시간 동안 면역이 된다.
+    pub fn attempt(
+        &self,
+        medic: &Character,
+        patient: &mut Character,
+        immunity: &mut Immunity,
+        now: u32,
+        rng: &mut impl Rng,
+    ) -> Result<TreatWoundsResult, RulesError> {
+        if medic.skill_proficiency(Skill::Medicine) < self.rank {
+            return Err(RulesError::ProficiencyTooLow {
+                skill: Skill::Medicine,
+                required: self.rank,
+            });
+        }
+        let minutes_left = immunity.minutes_left(now);
+        if minutes_left > 0 {
+            return Err(RulesError::Immune { minutes_left });
+        }
+
+        let outcome = Check::new(medic.skill_bonus(Skill::Medicine), self.dc()).roll(rng);
+        let (healed, damage) = self.resolve(outcome.degree, patient, rng)?;
+        immunity.grant(now, Self::IMMUNITY_MINUTES);
+
+        Ok(TreatWoundsResult {
+            outcome,
+            healed,
+            damage,
+        })
+    }
+
+    /// 등급별 효과: 4d8 / 2d8 회복 (+추가 회복), 실패 없음, 치명적 실패 1d8 피해
+    fn resolve(
+        &self,
+        degree: DegreeOfSuccess,
+        patient: &mut Character,
+        rng: &mut impl Rng,
+    ) -> Result<(i32, i32), RulesError> {
+        let before = patient.current_hp();
+        match degree {
+            DegreeOfSuccess::CriticalSuccess => {
+                patient.heal(Dice::new(4, 8).roll(rng) + self.bonus_healing())?
+            }
+            DegreeOfSuccess::Success => {
+                patient.heal(Dice::new(2, 8).roll(rng) + self.bonus_healing())?
+            }
+            DegreeOfSuccess::Failure => {}
+            DegreeOfSuccess::CriticalFailure => patient.take_damage(Dice::new(1, 8).roll(rng))?,
+        }
+
+        // 치료에 성공하면 Wounded가 사라진다
+        if matches!(
+            degree,
+            DegreeOfSuccess::CriticalSuccess | DegreeOfSuccess::Success
+        ) {
+            patient.clear_wounded();
+        }
+
+        let change = patient.current_hp() - before;
+        Ok((change.max(0), (-change).max(0)))
+    }
+}
+
+/// 집중 포인트 (Focus Points), 최대 3점
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct FocusPool {
+    points: u8,
+    max: u8,
+}
+
+impl FocusPool {
+    pub const MAX: u8 = 3;
+    /// Refocus에 걸리는 시간 (분)
+    pub const REFOCUS_MINUTES: u32 = 10;
+
+    /// 가득 찬 상태로 시작
+    pub fn new(max: u8) -> Self {
+        let max = u8::min(max, Self::MAX);
+        Self { points: max, max }
+    }
+
+    pub fn remaining(&self) -> u8 {
+        self.points
+    }
+
+    pub fn spend(&mut self) -> Result<(), RulesError> {
+        if self.points == 0 {
+            return Err(RulesError::NoFocusPoints);
+        }
+        self.points -= 1;
+        Ok(())
+    }
+
+    /// 10분 동안 집중해 1점을 회복한다
+    pub fn refocus(&mut self) -> Result<(), RulesError> {
+        if self.points >= self.max {
+            return Err(RulesError::FocusPoolFull);
+        }
+        self.points += 1;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    use super::DegreeOfSuccess::*;
+    use super::*;
+    use crate::character::{Attribute, Defense};
+
+    fn cleric() -> Character {
+        Character::new(
+            8,
+            Attribute {
+                strength: 10,
+                dexterity: 10,
+                constitution: 12,
+                intelligence: 10,
+                wisdom: 18,
+                charisma: 14,
+            },
+            Defense {
+                armor_item: 2,
+                armor_proficiency: Proficiency::Trained,
+            },
+            Proficiency::Trained,
+        )
+        .with_level(5)
+        .unwrap()
+        .with_skill(Skill::Medicine, Proficiency::Expert)
+        .with_skill(Skill::Religion, Proficiency::Trained)
+    }
+
+    #[test]
+    fn test_income_for_degree() {
+        assert_eq!(income_for(Success, 3, Proficiency::Trained), 50);
+        assert_eq!(income_for(CriticalSuccess, 3, Proficiency::Trained), 70);
+        assert_eq!(income_for(Failure, 3, Proficiency::Expert), 8);
+        assert_eq!(income_for(CriticalFailure, 3, Proficiency::Expert), 0);
+        assert_eq!(income_for(Success, 16, Proficiency::Legendary), 4000);
+        assert_eq!(income_for(CriticalSuccess, 20, Proficiency::Master), 17500);
+    }
+
+    #[test]
+    fn test_earn_income() {
+        let cleric = cleric();
+        let mut rng = StdRng::seed_from_u64(11);
+
+        for _ in 0..50 {
+            let income = earn_income(&cleric, Skill::Religion, 4, 7, &mut rng).unwrap();
+            // Religion: WIS +4, Trained 7, DC 19
+            assert_eq!(income.outcome.total, income.outcome.natural as i32 + 11);
+            assert_eq!(
+                income.per_day_cp,
+                income_for(income.outcome.degree, 4, Proficiency::Trained)
+            );
+            assert_eq!(income.total_cp, income.per_day_cp * 7);
+        }
+
+        assert_eq!(
+            earn_income(&cleric, Skill::Crafting, 4, 7, &mut rng),
+            Err(RulesError::ProficiencyTooLow {
+                skill: Skill::Crafting,
+                required: Proficiency::Trained
+            })
+        );
+        assert_eq!(
+            earn_income(&cleric, Skill::Religion, 21, 1, &mut rng),
+            Err(RulesError::InvalidLevel(21))
+        );
+    }
+
+    #[test]
+    fn test_treat_wounds_dc() {
+        let expert = TreatWounds::new(Proficiency::Expert).unwrap();
+        assert_eq!(expert.dc(), 20);
+        assert_eq!(expert.bonus_healing(), 10);
+
+        assert_eq!(
+            TreatWounds::new(Proficiency::Untrained),
+            Err(RulesError::ProficiencyTooLow {
+                skill: Skill::Medicine,
+                required: Proficiency::Trained
+            })
+        );
+    }
+
+    #[test]
+    fn test_treat_wounds_resolve() {
+        let treat = TreatWounds::new(Proficiency::Trained).unwrap();
+        let mut rng = StdRng::seed_from_u64(3);
+        let mut patient = cleric();
+        let max_hp = patient.max_hp(); // (8 + 1) * 5 = 45
+
+        patient.take_damage(40).unwrap();
+        let (healed, damage) = treat.resolve(Success, &mut patient, &mut rng).unwrap();
+        assert!((2..=16).contains(&healed));
+        assert_eq!(damage, 0);
+        assert_eq!(patient.current_hp(), 5 + healed);
+
+        patient.take_damage(max_hp).unwrap();
+        patient.heal(20).unwrap();
+        let (healed, damage) = treat
+            .resolve(CriticalFailure, &mut patient, &mut rng)
+            .unwrap();
+        assert_eq!(healed, 0);
+        assert!((1..=8).contains(&damage));
+        assert_eq!(patient.current_hp(), 20 - damage);
+
+        assert_eq!(treat.resolve(Failure, &mut patient, &mut rng), Ok((0, 0)));
+
+        // 쓰러졌다 일어난 Wounded는 치료에 성공해야 사라진다
+        assert_eq!(patient.wounded(), 1);
+        treat.resolve(Success, &mut patient, &mut rng).unwrap();
+        assert_eq!(patient.wounded(), 0);
+    }
+
+    #[test]
+    fn test_treat_wounds_immunity() {
+        let medic = cleric();
+        let mut patient = cleric();
+        let mut immunity = Immunity::default();
+        let mut rng = StdRng::seed_from_u64(7);
+        let treat = TreatWounds::new(Proficiency::Expert).unwrap();
+
+        patient.take_damage(30).unwrap();
+        let result = treat
+            .attempt(&medic, &mut patient, &mut immunity, 0, &mut rng)
+            .unwrap();
+        // Medicine: WIS +4, Expert 9, DC 20
+        assert_eq!(result.outcome.total, result.outcome.natural as i32 + 13);
+        assert_eq!(patient.current_hp(), 15 + result.healed - result.damage);
+
+        assert_eq!(
+            treat.attempt(&medic, &mut patient, &mut immunity, 10, &mut rng),
+            Err(RulesError::Immune { minutes_left: 50 })
+        );
+        assert!(
+            treat
+                .attempt(&medic, &mut patient, &mut immunity, 60, &mut rng)
+                .is_ok()
+        );
+
+        // Master 치료는 Expert 의료인이 할 수 없다
+        let master = TreatWounds::new(Proficiency::Master).unwrap();
+        assert_eq!(
+            master.attempt(&medic, &mut patient, &mut immunity, 120, &mut rng),
+            Err(RulesError::ProficiencyTooLow {
+                skill: Skill::Medicine,
+                required: Proficiency::Master
+            })
+        );
+    }
+
+    #[test]
+    fn test_refocus() {
+        let mut pool = FocusPool::new(2);
+        assert_eq!(pool.refocus(), Err(RulesError::FocusPoolFull));
+
+        pool.spend().unwrap();
+        pool.spend().unwrap();
+        assert_eq!(pool.spend(), Err(RulesError::NoFocusPoints));
+
+        pool.refocus().unwrap();
+        assert_eq!(pool.remaining(), 1);
+        assert_eq!(FocusPool::new(5).remaining(), FocusPool::MAX);
+    }
+}
@@ -0,0 +1,335 @@
+use crate::condition::Condition;
+
+/// 효과가 끝나는 시점, `Id`는 턴 순서의 참가자를 가리킨다
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum EffectDuration<Id> {
+    /// 해당 참가자의 다음 턴 시작에 끝남 ("다음 턴 시작까지")
+    UntilStartOfTurn(Id),
+    /// 해당 참가자의 턴 끝에 끝남, 그 참가자의 턴 중이면 이번 턴 끝
+    UntilEndOfTurn(Id),
+    /// 해당 참가자의 턴 시작마다 1씩 줄어 0이 되면 끝남 (1분 = 10라운드)
+    Rounds { anchor: Id, rounds: u32 },
+    /// 직접 제거할 때까지
+    Unlimited,
+}
+
+impl<Id> EffectDuration<Id> {
+    /// 1분 지속
+    pub fn minute(anchor: Id) -> Self {
+        EffectDuration::Rounds { anchor, rounds: 10 }
+    }
+}
+
+/// 보너스·페널티 종류, 같은 종류끼리는 겹치지 않는다
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum BonusType {
+    Circumstance,
+    Item,
+    Status,
+    /// 종류 없는 보정, 모두 더한다 (Rage 피해, 갑옷 페널티 등)
+    Untyped,
+}
+
+/// 보너스가 붙는 수치
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum Statistic {
+    AttackRolls,
+    Damage,
+    ArmorClass,
+    Saves,
+    SkillChecks,
+    Perception,
+    Initiative,
+}
+
+/// 수치 보정, 음수면 페널티
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct Modifier {
+    pub statistic: Statistic,
+    pub bonus_type: BonusType,
+    pub value: i32,
+}
+
+impl Modifier {
+    pub fn new(statistic: Statistic, bonus_type: BonusType, value: i32) -> Self {
+        Self {
+            statistic,
+            bonus_type,
+            value,
+        }
+    }
+}
+
+/// 참가자에게 걸린 지속 효과
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ActiveEffect<Id> {
+    pub name: String,
+    pub duration: EffectDuration<Id>,
+    pub modifiers: Vec<Modifier>,
+    pub conditions: Vec<Condition>,
+}
+
+impl<Id> ActiveEffect<Id> {
+    pub fn new(name: &str, duration: EffectDuration<Id>) -> Self {
+        Self {
+            name: name.to_string(),
+            duration,
+            modifiers: Vec::new(),
+            conditions: Vec::new(),
+        }
+    }
+
+    pub fn with_modifier(mut self, modifier: Modifier) -> Self {
+        self.modifiers.push(modifier);
+        self
+    }
+
+    pub fn with_condition(mut self, condition: Condition) -> Self {
+        self.conditions.push(condition);
+        self
+    }
+
+    /// Courageous Anthem (Inspire Courage): 1라운드 동안 명중과 피해 +1 상태 보너스
+    ///
+    /// 공포 효과에 대한 내성 보너스는 다루지 않는다.
+    pub fn courageous_anthem(performer: Id) -> Self {
+        Self::new(
+            "Courageous Anthem",
+            EffectDuration::Rounds {
+                anchor: performer,
+                rounds: 1,
+            },
+        )
+        .with_modifier(Modifier::new(Statistic::AttackRolls, BonusType::Status, 1))
+        .with_modifier(Modifier::new(Statistic::Damage, BonusType::Status, 1))
+    }
+
+    /// Raise a Shield: 다음 턴 시작까지 AC +2 상황 보너스
+    pub fn raised_shield(holder: Id) -> Self {
+        Self::new("Raise a Shield", EffectDuration::UntilStartOfTurn(holder)).with_modifier(
+            Modifier::new(Statistic::ArmorClass, BonusType::Circumstance, 2),
+        )
+    }
+}
+
+/// 한 참가자에게 걸린 효과 목록
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct EffectRegistry<Id> {
+    effects: Vec<ActiveEffect<Id>>,
+}
+
+impl<Id> Default for EffectRegistry<Id> {
+    fn default() -> Self {
+        Self {
+            effects: Vec::new(),
+        }
+    }
+}
+
+impl<Id: Copy + Eq> EffectRegistry<Id> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn effects(&self) -> &[ActiveEffect<Id>] {
+        &self.effects
+    }
+
+    /// 같은 이름의 효과는 새 효과로 바꾼다 (지속 시간 갱신)
+    pub fn add(&mut self, effect: ActiveEffect<Id>) {
+        self.effects.retain(|e| e.name != effect.name);
+        self.effects.push(effect);
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.effects.iter().any(|e| e.name == name)
+    }
+
+    pub fn remove(&mut self, name: &str) -> Option<ActiveEffect<Id>> {
+        let index = self.effects.iter().position(|e| e.name == name)?;
+        Some(self.effects.remove(index))
+    }
+
+    /// `who`의 턴이 시작될 때, 끝난 효과를 돌려준다
+    pub fn start_turn(&mut self, who: Id) -> Vec<ActiveEffect<Id>> {
+        for effect in &mut self.effects {
+            if let EffectDuration::Rounds { anchor, rounds } = &mut effect.duration
+                && *anchor == who
+            {
+                *rounds = rounds.saturating_sub(1);
+            }
+        }
+
+        self.expire(|duration| match duration {
+            EffectDuration::UntilStartOfTurn(anchor) => *anchor == who,
+            EffectDuration::Rounds { anchor, rounds } => *anchor == who && *rounds == 0,
+            _ => false,
+        })
+    }
+
+    /// `who`의 턴이 끝날 때, 끝난 효과를 돌려준다
+    pub fn end_turn(&mut self, who: Id) -> Vec<ActiveEffect<Id>> {
+        self.expire(
+            |duration| matches!(duration, EffectDuration::UntilEndOfTurn(anchor) if *anchor == who),
+        )
+    }
+
+    fn expire(&mut self, ends: impl Fn(&EffectDuration<Id>) -> bool) -> Vec<ActiveEffect<Id>> {
+        let (expired, active) = self
+            .effects
+            .drain(..)
+            .partition(|effect| ends(&effect.duration));
+        self.effects = active;
+        expired
+    }
+
+    /// 종류별로 가장 큰 보너스와 가장 큰 페널티만 더한다, 종류 없는 보정은 모두 더한다
+    pub fn modifier(&self, statistic: Statistic) -> i32 {
+        let values = |bonus_type: BonusType| {
+            self.effects
+                .iter()
+                .flat_map(|e| &e.modifiers)
+                .filter(move |m| m.statistic == statistic && m.bonus_type == bonus_type)
+                .map(|m| m.value)
+        };
+
+        let typed: i32 = [BonusType::Circumstance, BonusType::Item, BonusType::Status]
+            .into_iter()
+            .map(|bonus_type| {
+                let bonus = values(bonus_type).filter(|v| *v > 0).max().unwrap_or(0);
+                let penalty = values(bonus_type).filter(|v| *v < 0).min().unwrap_or(0);
+                bonus + penalty
+            })
+            .sum();
+        typed + values(BonusType::Untyped).sum::<i32>()
+    }
+
+    pub fn conditions(&self) -> Vec<Condition> {
+        self.effects
+            .iter()
+            .flat_map(|e| e.conditions.iter().copied())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    const BARD: usize = 0;
+    const FIGHTER: usize = 1;
+    const GOBLIN: usize = 2;
+
+    fn names(effects: &[ActiveEffect<usize>]) -> Vec<&str> {
+        effects.iter().map(|e| e.name.as_str()).collect()
+    }
+
+    #[test]
+    fn test_courageous_anthem_lasts_one_round() {
+        // 바드 턴에 파이터에게 건다
+        let mut fighter = EffectRegistry::new();
+        fighter.add(ActiveEffect::courageous_anthem(BARD));
+        assert_eq!(fighter.modifier(Statistic::AttackRolls), 1);
+        assert_eq!(fighter.modifier(Statistic::Damage), 1);
+        assert_eq!(fighter.modifier(Statistic::ArmorClass), 0);
+
+        assert!(fighter.end_turn(BARD).is_empty());
+        assert!(fighter.start_turn(FIGHTER).is_empty());
+        assert!(fighter.end_turn(FIGHTER).is_empty());
+        assert!(fighter.start_turn(GOBLIN).is_empty());
+
+        // 바드의 다음 턴 시작에 끝남
+        let expired = fighter.start_turn(BARD);
+        assert_eq!(names(&expired), vec!["Courageous Anthem"]);
+        assert_eq!(fighter.modifier(Statistic::AttackRolls), 0);
+    }
+
+    #[test]
+    fn test_turn_boundaries() {
+        let mut goblin = EffectRegistry::new();
+        goblin.add(
+            ActiveEffect::new("Off-Guard", EffectDuration::UntilStartOfTurn(FIGHTER))
+                .with_condition(Condition::OffGuard),
+        );
+        goblin.add(
+            ActiveEffect::new("Dazzled", EffectDuration::UntilEndOfTurn(GOBLIN))
+                .with_condition(Condition::Dazzled),
+        );
+        assert_eq!(
+            goblin.conditions(),
+            vec![Condition::OffGuard, Condition::Dazzled]
+        );
+
+        // 고블린 턴이 시작해도 끝나지 않고, 끝날 때 Dazzled 종료
+        assert!(goblin.start_turn(GOBLIN).is_empty());
+        assert_eq!(names(&goblin.end_turn(GOBLIN)), vec!["Dazzled"]);
+
+        assert!(goblin.end_turn(FIGHTER).is_empty());
+        assert_eq!(names(&goblin.start_turn(FIGHTER)), vec!["Off-Guard"]);
+        assert!(goblin.effects().is_empty());
+
+        // 방패는 든 쪽의 다음 턴 시작까지
+        goblin.add(ActiveEffect::raised_shield(GOBLIN));
+        assert!(goblin.contains("Raise a Shield"));
+        assert_eq!(goblin.modifier(Statistic::ArmorClass), 2);
+        assert!(goblin.end_turn(GOBLIN).is_empty());
+        assert_eq!(names(&goblin.start_turn(GOBLIN)), vec!["Raise a Shield"]);
+    }
+
+    #[test]
+    fn test_minute_duration() {
+        let mut fighter = EffectRegistry::new();
+        fighter.add(ActiveEffect::new("Bless", EffectDuration::minute(BARD)));
+        fighter.add(ActiveEffect::new("Rage", EffectDuration::Unlimited));
+
+        for _ in 0..9 {
+            assert!(fighter.start_turn(BARD).is_empty());
+        }
+        assert_eq!(names(&fighter.start_turn(BARD)), vec!["Bless"]);
+        assert_eq!(names(fighter.effects()), vec!["Rage"]);
+        assert_eq!(fighter.remove("Rage").unwrap().name, "Rage");
+        assert_eq!(fighter.remove("Rage"), None);
+        assert!(!fighter.contains("Rage"));
+    }
+
+    #[test]
+    fn test_bonuses_do_not_stack() {
+        let mut fighter = EffectRegistry::new();
+        fighter.add(ActiveEffect::courageous_anthem(BARD));
+        fighter.add(
+            ActiveEffect::new("Heroism", EffectDuration::minute(BARD))
+                .with_modifier(Modifier::new(Statistic::AttackRolls, BonusType::Status, 1)),
+        );
+        fighter.add(
+            ActiveEffect::new("Flanking", EffectDuration::UntilEndOfTurn(FIGHTER)).with_modifier(
+                Modifier::new(Statistic::AttackRolls, BonusType::Circumstance, 2),
+            ),
+        );
+        fighter.add(
+            ActiveEffect::new("Frightened", EffectDuration::Unlimited)
+                .with_modifier(Modifier::new(Statistic::AttackRolls, BonusType::Status, -2)),
+        );
+
+        // 상태 +1 (중첩 안 됨) + 상황 +2 + 상태 -2
+        assert_eq!(fighter.modifier(Statistic::AttackRolls), 1);
+
+        // 종류 없는 보정은 모두 더한다
+        for name in ["Rage", "Weapon Specialization"] {
+            fighter.add(
+                ActiveEffect::new(name, EffectDuration::Unlimited).with_modifier(Modifier::new(
+                    Statistic::Damage,
+                    BonusType::Untyped,
+                    2,
+                )),
+            );
+        }
+        assert_eq!(fighter.modifier(Statistic::Damage), 5);
+        fighter.remove("Rage");
+        fighter.remove("Weapon Specialization");
+
+        // 같은 이름은 갱신
+        fighter.add(ActiveEffect::courageous_anthem(BARD));
+        assert_eq!(fighter.effects().len(), 4);
+    }
+}
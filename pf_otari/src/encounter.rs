@@ -0,0 +1,295 @@
+use std::cmp::Reverse;
+
+use crate::bestiary::Creature;
+use crate::error::RulesError;
+
+/// 위협도 (Threat level)
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Copy, Clone)]
+pub enum ThreatLevel {
+    Trivial,
+    Low,
+    Moderate,
+    Severe,
+    Extreme,
+}
+
+impl ThreatLevel {
+    const ALL: [ThreatLevel; 5] = [
+        ThreatLevel::Trivial,
+        ThreatLevel::Low,
+        ThreatLevel::Moderate,
+        ThreatLevel::Severe,
+        ThreatLevel::Extreme,
+    ];
+
+    /// 4인 파티 기준 XP 예산
+    fn base_budget(&self) -> i32 {
+        match self {
+            ThreatLevel::Trivial => 40,
+            ThreatLevel::Low => 60,
+            ThreatLevel::Moderate => 80,
+            ThreatLevel::Severe => 120,
+            ThreatLevel::Extreme => 160,
+        }
+    }
+
+    /// 파티 인원 1명당 예산 조정치
+    fn character_adjustment(&self) -> i32 {
+        match self {
+            ThreatLevel::Trivial => 10,
+            ThreatLevel::Low => 15,
+            ThreatLevel::Moderate => 20,
+            ThreatLevel::Severe => 30,
+            ThreatLevel::Extreme => 40,
+        }
+    }
+}
+
+/// 파티 인원수에 맞춘 XP 예산
+pub fn xp_budget(threat: ThreatLevel, party_size: u32) -> i32 {
+    threat.base_budget() + (party_size as i32 - 4) * threat.character_adjustment()
+}
+
+/// 파티 레벨 대비 크리처 레벨 차이에 따른 XP
+///
+/// 4레벨 넘게 낮으면 위협이 되지 않아 0, 4레벨 넘게 높으면 `None`
+pub fn creature_xp(creature_level: i32, party_level: i32) -> Option<i32> {
+    match creature_level - party_level {
+        ..=-5 => Some(0),
+        -4 => Some(10),
+        -3 => Some(15),
+        -2 => Some(20),
+        -1 => Some(30),
+        0 => Some(40),
+        1 => Some(60),
+        2 => Some(80),
+        3 => Some(120),
+        4 => Some(160),
+        _ => None,
+    }
+}
+
+/// 조우 평가 결과
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct EncounterReport {
+    pub total_xp: i32,
+    /// 총 XP가 들어가는 가장 낮은 위협도, Extreme 예산도 넘으면 `None`
+    pub threat: Option<ThreatLevel>,
+}
+
+/// 파티 인원과 레벨에 맞춰 조우를 구성한다
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct EncounterBuilder {
+    party_size: u32,
+    party_level: i32,
+}
+
+impl EncounterBuilder {
+    /// 파티 인원은 1명 이상, 파티 레벨은 1~20
+    pub fn new(party_size: u32, party_level: i32) -> Result<Self, RulesError> {
+        if party_size == 0 {
+            return Err(RulesError::InvalidPartySize(party_size));
+        }
+        if !(1..=20).contains(&party_level) {
+            return Err(RulesError::InvalidLevel(party_level));
+        }
+        Ok(Self {
+            party_size,
+            party_level,
+        })
+    }
+
+    pub fn budget(&self, threat: ThreatLevel) -> i32 {
+        xp_budget(threat, self.party_size)
+    }
+
+    /// 크리처 목록의 XP 합계와 위협도
+    pub fn evaluate(&self, creatures: &[&Creature]) -> Result<EncounterReport, RulesError> {
+        let mut total_xp = 0;
+        for creature in creatures {
+            total_xp += creature_xp(creature.level, self.party_level)
+                .ok_or_else(|| RulesError::CreatureTooPowerful(creature.name.clone()))?;
+        }
+
+        let threat = ThreatLevel::ALL
+            .into_iter()
+            .find(|threat| total_xp <= self.budget(*threat));
+
+        Ok(EncounterReport { total_xp, threat })
+    }
+
+    /// 크리처 목록이 목표 위협도 예산 안에 드는지 검사
+    pub fn validate(
+        &self,
+        threat: ThreatLevel,
+        creatures: &[&Creature],
+    ) -> Result<EncounterReport, RulesError> {
+        let report = self.evaluate(creatures)?;
+        let budget = self.budget(threat);
+
+        if report.total_xp > budget {
+            Err(RulesError::OverBudget {
+                xp: report.total_xp,
+                budget,
+            })
+        } else {
+            Ok(report)
+        }
+    }
+
+    /// 목표 위협도 예산을 채우는 크리처 목록 제안
+    ///
+    /// 남은 예산에 들어가는 가장 XP가 큰 크리처부터 고른다.
+    pub fn suggest<'a>(&self, threat: ThreatLevel, bestiary: &'a [Creature]) -> Vec<&'a Creature> {
+        let mut candidates: Vec<(&Creature, i32)> = bestiary
+            .iter()
+            .filter_map(|c| {
+                creature_xp(c.level, self.party_level)
+                    .filter(|xp| *xp > 0)
+                    .map(|xp| (c, xp))
+            })
+            .collect();
+        candidates.sort_by_key(|(_, xp)| Reverse(*xp));
+
+        let mut remaining = self.budget(threat);
+        let mut chosen = Vec::new();
+
+        while let Some((creature, xp)) = candidates.iter().find(|(_, xp)| *xp <= remaining) {
+            chosen.push(*creature);
+            remaining -= xp;
+        }
+
+        chosen
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::ThreatLevel::*;
+    use super::*;
+    use crate::bestiary::bestiary;
+
+    fn find<'a>(creatures: &'a [Creature], name: &str) -> &'a Creature {
+        creatures.iter().find(|c| c.name == name).unwrap()
+    }
+
+    #[test]
+    fn test_xp_budget() {
+        // 4인 파티 기본 예산
+        assert_eq!(xp_budget(Trivial, 4), 40);
+        assert_eq!(xp_budget(Low, 4), 60);
+        assert_eq!(xp_budget(Moderate, 4), 80);
+        assert_eq!(xp_budget(Severe, 4), 120);
+        assert_eq!(xp_budget(Extreme, 4), 160);
+
+        // 인원 조정
+        assert_eq!(xp_budget(Moderate, 5), 100); // 80 + 20
+        assert_eq!(xp_budget(Severe, 3), 90); // 120 - 30
+        assert_eq!(xp_budget(Low, 6), 90); // 60 + 15 * 2
+    }
+
+    #[test]
+    fn test_creature_xp() {
+        assert_eq!(creature_xp(1, 1), Some(40));
+        assert_eq!(creature_xp(-1, 3), Some(10)); // -4
+        assert_eq!(creature_xp(-2, 3), Some(0)); // -5, 위협 아님
+        assert_eq!(creature_xp(7, 3), Some(160)); // +4
+        assert_eq!(creature_xp(8, 3), None); // +5
+        assert_eq!(creature_xp(3, 1), Some(80)); // +2
+    }
+
+    #[test]
+    fn test_evaluate_threat() {
+        let creatures = bestiary();
+        let builder = EncounterBuilder::new(4, 1).unwrap();
+
+        // 늑대 2마리 = 80 XP → Moderate
+        let wolf = find(&creatures, "Wolf");
+        let report = builder.evaluate(&[wolf, wolf]).unwrap();
+        assert_eq!(report.total_xp, 80);
+        assert_eq!(report.threat, Some(Moderate));
+
+        // 오거(+2) + 늑대 2마리 = 160 XP → Extreme
+        let ogre = find(&creatures, "Ogre Warrior");
+        let report = builder.evaluate(&[ogre, wolf, wolf]).unwrap();
+        assert_eq!(report.threat, Some(Extreme));
+
+        // Extreme 초과
+        let report = builder.evaluate(&[ogre, ogre, wolf]).unwrap();
+        assert_eq!(report.total_xp, 200);
+        assert_eq!(report.threat, None);
+
+        // 6레벨 오우베어(+5)는 사용할 수 없음
+        let owlbear = Creature {
+            level: 6,
+            ..find(&creatures, "Owlbear").clone()
+        };
+        assert_eq!(
+            builder.evaluate(&[&owlbear]),
+            Err(RulesError::CreatureTooPowerful(owlbear.name.clone()))
+        );
+    }
+
+    #[test]
+    fn test_party_level_range() {
+        assert_eq!(
+            EncounterBuilder::new(4, 0),
+            Err(RulesError::InvalidLevel(0))
+        );
+        assert_eq!(
+            EncounterBuilder::new(4, -1),
+            Err(RulesError::InvalidLevel(-1))
+        );
+        assert_eq!(
+            EncounterBuilder::new(4, 21),
+            Err(RulesError::InvalidLevel(21))
+        );
+        assert!(EncounterBuilder::new(4, 20).is_ok());
+    }
+
+    #[test]
+    fn test_party_size() {
+        assert_eq!(
+            EncounterBuilder::new(0, 1),
+            Err(RulesError::InvalidPartySize(0))
+        );
+        assert!(EncounterBuilder::new(1, 1).is_ok());
+    }
+
+    #[test]
+    fn test_validate_budget() {
+        let creatures = bestiary();
+        let builder = EncounterBuilder::new(3, 1).unwrap();
+        let wolf = find(&creatures, "Wolf");
+
+        // 3인 Low 예산 45 XP
+        assert!(builder.validate(Low, &[wolf]).is_ok());
+        assert_eq!(
+            builder.validate(Low, &[wolf, wolf]),
+            Err(RulesError::OverBudget { xp: 80, budget: 45 })
+        );
+    }
+
+    #[test]
+    fn test_suggest_fits_budget() {
+        let creatures = bestiary();
+
+        for party_level in 1..=4 {
+            for threat in ThreatLevel::ALL {
+                let builder = EncounterBuilder::new(4, party_level).unwrap();
+                let suggestion = builder.suggest(threat, &creatures);
+
+                assert!(!suggestion.is_empty());
+                assert!(builder.validate(threat, &suggestion).is_ok());
+            }
+        }
+
+        // 1레벨 4인 Moderate: 오거(80 XP) 하나로 예산을 채움
+        let suggestion = EncounterBuilder::new(4, 1)
+            .unwrap()
+            .suggest(Moderate, &creatures);
+        assert_eq!(suggestion.len(), 1);
+        assert_eq!(suggestion[0].level, 3);
+    }
+}
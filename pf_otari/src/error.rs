@@ -0,0 +1,146 @@
+use std::fmt;
+
+use crate::building::Tier;
+use crate::character::{Proficiency, Skill};
+use crate::class::Class;
+use crate::combat::ActionKind;
+use crate::feat::{FeatKind, Prerequisite};
+use crate::locale::{Locale, Localize};
+use crate::treasure::Coins;
+
+/// 규칙 위반, 잘못된 입력
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum RulesError {
+    /// 남은 액션보다 비용이 큰 행동
+    InsufficientActions { cost: u8, remaining: u8 },
+    /// 리액션을 이미 사용함
+    ReactionUsed,
+    /// d20 굴림값이 1~20을 벗어남
+    InvalidRoll(u8),
+    /// 음수 피해나 회복
+    NegativeAmount(i32),
+    /// 의식이 없는 (HP 0) 대상
+    TargetUnconscious,
+    /// 죽은 대상
+    TargetDead,
+    /// 범위를 벗어난 레벨: 캐릭터·파티·보물 레벨은 1~20, Earn Income 작업 레벨은 0~20,
+    /// 크리처 제작 표는 -1~24, Recall Knowledge의 크리처 레벨 DC는 -1~25
+    InvalidLevel(i32),
+    /// 파티 인원은 1명 이상
+    InvalidPartySize(u32),
+    /// 남은 히어로 포인트 없음
+    NoHeroPoints,
+    /// 한 굴림에는 행운 효과를 하나만 적용할 수 있다
+    FortuneAlreadyApplied,
+    /// 파티보다 4레벨 넘게 높은 크리처
+    CreatureTooPowerful(String),
+    /// 목표 위협도 예산 초과
+    OverBudget { xp: i32, budget: i32 },
+    /// 자기 턴이 아닌 참가자의 행동
+    NotYourTurn,
+    /// 근접 공격 간격 밖의 대상
+    OutOfReach,
+    /// 이동 속도보다 먼 거리
+    TooFar { distance: i32, speed: i32 },
+    /// 다른 참가자가 있는 칸으로 이동
+    SquareOccupied,
+    /// 자기 자신을 대상으로 한 행동
+    SelfTarget,
+    /// 활동에 필요한 기술 숙련도 미달
+    ProficiencyTooLow { skill: Skill, required: Proficiency },
+    /// 치료 면역 시간이 남음 (분)
+    Immune { minutes_left: u32 },
+    /// 손이 모자람
+    HandsFull { needed: u8, free: u8 },
+    /// 최대 Bulk 초과
+    OverMaxBulk { bulk: i32, max: i32 },
+    /// 소지품 목록에 없는 위치
+    NoSuchItem(usize),
+    /// 손에 든 무기가 없음
+    NoWeaponHeld,
+    /// 든 방패가 없음
+    NoShield,
+    /// 크리처 특성에 맞는 Recall Knowledge 기술이 없음
+    NoRecallSkill(String),
+    /// 남은 집중 포인트 없음
+    NoFocusPoints,
+    /// 집중 포인트가 이미 가득 참
+    FocusPoolFull,
+    /// 다른 클래스의 능력
+    WrongClass(Class),
+    /// 비어 있는 그 종류·레벨의 재주 슬롯이 없음
+    NoFeatSlot { kind: FeatKind, level: i32 },
+    /// 슬롯 레벨보다 높은 재주
+    FeatLevelTooHigh { feat: i32, slot: i32 },
+    /// 이미 고른 재주
+    FeatAlreadyTaken(String),
+    /// 만족하지 못한 선행 조건
+    PrerequisiteNotMet(Prerequisite),
+    /// 읽을 수 없는 주사위 표기
+    InvalidDice(String),
+    /// 읽을 수 없는 참가자·전투 파일의 줄 (1부터 셈)
+    InvalidRoster { line: usize, text: String },
+    /// 크리처 제작 표에 없는 단계
+    NoSuchTier { stat: String, tier: Tier },
+    /// 그 기술로 해제할 수 없는 위험 요소
+    NoDisableSkill(Skill),
+    /// 이미 해제되었거나 작동이 끝난 위험 요소
+    HazardInactive(String),
+    /// 읽을 수 없는 가격 표기
+    InvalidPrice(String),
+    /// 가진 돈보다 비싼 물건
+    NotEnoughCoins { price: Coins, coins: Coins },
+    /// 주인이 아닌 참가자의 명령
+    NotYourMinion,
+    /// 이번 턴에 이미 명령을 받은 미니언
+    AlreadyCommanded,
+    /// 남은 Quickened 추가 액션이 없음
+    NoQuickenedAction,
+    /// Quickened 추가 액션으로 할 수 없는 액션
+    QuickenedNotAllowed(ActionKind),
+    /// 엄폐나 은폐 없이 Hide
+    NoCoverOrConcealment,
+    /// 숨어 있지 않은 상태에서 Sneak
+    NotHidden,
+    /// 이번 턴에 이미 Flourish 액션을 함
+    FlourishUsed,
+}
+
+/// 한국어 문구, 다른 언어는 [`Localize`]로 옮긴다
+impl fmt::Display for RulesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.localize(Locale::Korean))
+    }
+}
+
+impl std::error::Error for RulesError {}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_messages() {
+        assert_eq!(
+            RulesError::InsufficientActions {
+                cost: 2,
+                remaining: 1
+            }
+            .to_string(),
+            "액션이 부족합니다 (필요 2, 남음 1)"
+        );
+        assert_eq!(
+            RulesError::InvalidRoll(21).to_string(),
+            "d20 굴림값은 1~20이어야 합니다 (입력 21)"
+        );
+        assert_eq!(
+            RulesError::OverBudget {
+                xp: 100,
+                budget: 80
+            }
+            .to_string(),
+            "XP 예산을 초과했습니다 (100 / 80)"
+        );
+    }
+}
@@ -0,0 +1,149 @@
+use rand::Rng;
+
+use crate::character::{Character, Skill};
+use crate::dice::roll_d20;
+
+/// 탐험 활동, 조우가 시작될 때의 우선권 기술과 시작 상태를 정한다
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ExplorationActivity {
+    /// 은신하며 이동, 우선권에 Stealth 사용
+    AvoidNotice,
+    /// 숨겨진 문과 함정을 찾으며 이동
+    Search,
+    /// 앞서 정찰, 파티 전원 우선권 +1 상황 보너스
+    Scout,
+    /// 방패를 든 채 이동, 방패를 든 상태로 조우 시작
+    Defend,
+}
+
+/// 우선권 판정에 쓰는 능력
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum InitiativeSkill {
+    Perception,
+    Skill(Skill),
+}
+
+/// 탐험 활동에 따른 조우 시작 상태
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct EncounterStart {
+    pub initiative_skill: InitiativeSkill,
+    /// 상황 보너스 (Scout)
+    pub initiative_bonus: i32,
+    /// 방패를 든 상태 (Defend)
+    pub shield_raised: bool,
+}
+
+impl ExplorationActivity {
+    pub fn initiative_skill(&self) -> InitiativeSkill {
+        match self {
+            ExplorationActivity::AvoidNotice => InitiativeSkill::Skill(Skill::Stealth),
+            ExplorationActivity::Search
+            | ExplorationActivity::Scout
+            | ExplorationActivity::Defend => InitiativeSkill::Perception,
+        }
+    }
+}
+
+/// 파티 구성원별 탐험 활동으로 조우 시작 상태를 정한다
+///
+/// Scout의 보너스는 파티 전원이 받고, 여러 명이 정찰해도 겹치지 않는다.
+pub fn encounter_start(activities: &[ExplorationActivity]) -> Vec<EncounterStart> {
+    let scouting = activities.contains(&ExplorationActivity::Scout);
+
+    activities
+        .iter()
+        .map(|activity| EncounterStart {
+            initiative_skill: activity.initiative_skill(),
+            initiative_bonus: if scouting { 1 } else { 0 },
+            shield_raised: *activity == ExplorationActivity::Defend,
+        })
+        .collect()
+}
+
+impl EncounterStart {
+    pub fn initiative_modifier(&self, character: &Character) -> i32 {
+        let base = match self.initiative_skill {
+            InitiativeSkill::Perception => character.perception_bonus(),
+            InitiativeSkill::Skill(skill) => character.skill_bonus(skill),
+        };
+        base + self.initiative_bonus
+    }
+
+    pub fn roll_initiative(&self, character: &Character, rng: &mut impl Rng) -> i32 {
+        roll_d20(rng) as i32 + self.initiative_modifier(character)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    use super::ExplorationActivity::*;
+    use super::*;
+    use crate::character::{Attribute, Defense, Proficiency};
+
+    fn rogue() -> Character {
+        Character::new(
+            8,
+            Attribute {
+                strength: 10,
+                dexterity: 18,
+                constitution: 12,
+                intelligence: 12,
+                wisdom: 12,
+                charisma: 10,
+            },
+            Defense {
+                armor_item: 1,
+                armor_proficiency: Proficiency::Trained,
+            },
+            Proficiency::Expert,
+        )
+        .with_skill(Skill::Stealth, Proficiency::Trained)
+    }
+
+    #[test]
+    fn test_activity_initiative_skill() {
+        assert_eq!(
+            AvoidNotice.initiative_skill(),
+            InitiativeSkill::Skill(Skill::Stealth)
+        );
+        assert_eq!(Search.initiative_skill(), InitiativeSkill::Perception);
+        assert_eq!(Defend.initiative_skill(), InitiativeSkill::Perception);
+    }
+
+    #[test]
+    fn test_encounter_start() {
+        let starts = encounter_start(&[AvoidNotice, Scout, Defend, Scout]);
+
+        // 정찰 보너스는 모두에게 +1, 중첩되지 않음
+        assert!(starts.iter().all(|s| s.initiative_bonus == 1));
+        assert!(!starts[0].shield_raised);
+        assert!(starts[2].shield_raised);
+
+        let starts = encounter_start(&[Search, Defend]);
+        assert!(starts.iter().all(|s| s.initiative_bonus == 0));
+    }
+
+    #[test]
+    fn test_initiative_modifier() {
+        let rogue = rogue();
+        let starts = encounter_start(&[AvoidNotice, Search]);
+
+        // Stealth: DEX +4, Trained 3
+        assert_eq!(starts[0].initiative_modifier(&rogue), 7);
+        // Perception: WIS +1, Expert 5
+        assert_eq!(starts[1].initiative_modifier(&rogue), 6);
+
+        let scouted = encounter_start(&[AvoidNotice, Scout]);
+        assert_eq!(scouted[0].initiative_modifier(&rogue), 8);
+
+        let mut rng = StdRng::seed_from_u64(5);
+        for _ in 0..100 {
+            let initiative = starts[0].roll_initiative(&rogue, &mut rng);
+            assert!((8..=27).contains(&initiative));
+        }
+    }
+}
@@ -0,0 +1,690 @@
+use rand::Rng;
+
+use crate::character::{Ability, Character, Proficiency, Skill};
+use crate::check::SaveType;
+use crate::class::Class;
+use crate::combat::{CombatTurn, calc_damage, multiple_attack_penalty, strike};
+use crate::dice::{DegreeOfSuccess, Dice, roll_d20};
+use crate::duration::{ActiveEffect, BonusType, EffectDuration, Modifier, Statistic};
+use crate::error::RulesError;
+use crate::simulation::Combatant;
+
+/// 재주 종류, 종류마다 정해진 레벨에 슬롯이 생긴다
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum FeatKind {
+    Ancestry,
+    Class,
+    Skill,
+    General,
+}
+
+/// 재주 특성
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum FeatTrait {
+    Archetype,
+    Barbarian,
+    Champion,
+    Dedication,
+    Fighter,
+    Flourish,
+    General,
+    Rogue,
+    Skill,
+}
+
+impl FeatTrait {
+    fn class(&self) -> Option<Class> {
+        match self {
+            FeatTrait::Barbarian => Some(Class::Barbarian),
+            FeatTrait::Champion => Some(Class::Champion),
+            FeatTrait::Fighter => Some(Class::Fighter),
+            FeatTrait::Rogue => Some(Class::Rogue),
+            _ => None,
+        }
+    }
+}
+
+/// 재주 선행 조건
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Prerequisite {
+    Skill(Skill, Proficiency),
+    Perception(Proficiency),
+    Save(SaveType, Proficiency),
+    Weapon(Proficiency),
+    Ability(Ability, i32),
+    Feat(String),
+    /// 하나만 만족하면 된다
+    Any(Vec<Prerequisite>),
+}
+
+impl Prerequisite {
+    pub fn is_met(&self, character: &Character) -> bool {
+        match self {
+            Prerequisite::Skill(skill, rank) => character.skill_proficiency(*skill) >= *rank,
+            Prerequisite::Perception(rank) => character.perception_proficiency() >= *rank,
+            Prerequisite::Save(save, rank) => character.save_proficiency(*save) >= *rank,
+            Prerequisite::Weapon(rank) => character.weapon_proficiency() >= *rank,
+            Prerequisite::Ability(ability, score) => character.stats().score(*ability) >= *score,
+            Prerequisite::Feat(name) => character.has_feat(name),
+            Prerequisite::Any(options) => options.iter().any(|p| p.is_met(character)),
+        }
+    }
+}
+
+/// 재주로 얻는 Strike 활동
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct StrikeActivity {
+    pub actions: u8,
+    /// 이 레벨마다 무기 피해 주사위가 하나씩 늘어난다
+    pub extra_die_levels: &'static [i32],
+    /// MAP 계산에서 이 활동을 센 공격 횟수
+    pub attacks: u8,
+    /// Flourish 특성, 턴마다 하나만 쓸 수 있다
+    pub flourish: bool,
+}
+
+impl StrikeActivity {
+    pub fn extra_dice(&self, level: i32) -> u8 {
+        self.extra_die_levels
+            .iter()
+            .filter(|l| level >= **l)
+            .count() as u8
+    }
+
+    /// 액션을 쓰고 MAP를 적용해 Strike한다, 이후 MAP는 `attacks`번 공격한 것으로 센다
+    ///
+    /// Flourish 활동은 이번 턴에 이미 Flourish를 썼으면 `FlourishUsed`
+    pub fn strike(
+        &self,
+        attacker: &Combatant,
+        turn: &mut CombatTurn,
+        strikes_made: &mut u8,
+        target_ac: i32,
+        rng: &mut impl Rng,
+    ) -> Result<(DegreeOfSuccess, i32), RulesError> {
+        if self.flourish {
+            turn.spend_flourish(self.actions)?;
+        } else {
+            turn.spend_action(self.actions)?;
+        }
+
+        let weapon = &attacker.weapon;
+        let map = multiple_attack_penalty(*strikes_made, weapon.agile);
+        *strikes_made += self.attacks;
+
+        let degree = strike(roll_d20(rng), attacker.attack_bonus + map, target_ac)?;
+        let dice = Dice::new(
+            weapon.damage.count + self.extra_dice(attacker.character.level()),
+            weapon.damage.sides,
+        );
+        let damage = calc_damage(degree, dice.roll(rng), attacker.damage_bonus);
+        Ok((degree, damage))
+    }
+}
+
+/// 재주가 규칙에 끼치는 효과
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum FeatEffect {
+    /// 다른 규칙 요소에서 다루는 재주
+    None,
+    /// 항상 적용되는 보정
+    Passive(Modifier),
+    /// 새 Strike 활동
+    Strike(StrikeActivity),
+    /// 레벨마다 이만큼 최대 HP가 늘어난다
+    HitPoints(i32),
+}
+
+/// 재주
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Feat {
+    pub name: String,
+    pub level: i32,
+    pub kind: FeatKind,
+    pub traits: Vec<FeatTrait>,
+    /// 모두 만족해야 한다
+    pub prerequisites: Vec<Prerequisite>,
+    pub effect: FeatEffect,
+}
+
+impl Feat {
+    pub fn has_trait(&self, feat_trait: FeatTrait) -> bool {
+        self.traits.contains(&feat_trait)
+    }
+
+    /// 기술 재주는 일반 재주 슬롯에도 고를 수 있다
+    pub fn fits(&self, slot: FeatSlot) -> bool {
+        self.kind == slot.kind || (self.kind == FeatKind::Skill && slot.kind == FeatKind::General)
+    }
+
+    /// `slot`에 고를 수 있는지 확인한다
+    pub fn check(&self, character: &Character, slot: FeatSlot) -> Result<(), RulesError> {
+        if character.has_feat(&self.name) {
+            return Err(RulesError::FeatAlreadyTaken(self.name.clone()));
+        }
+        if !self.fits(slot) {
+            return Err(RulesError::NoFeatSlot {
+                kind: self.kind,
+                level: slot.level,
+            });
+        }
+        if self.level > slot.level {
+            return Err(RulesError::FeatLevelTooHigh {
+                feat: self.level,
+                slot: slot.level,
+            });
+        }
+
+        // 클래스 재주는 그 클래스만, 아키타입 재주는 누구나
+        let classes: Vec<Class> = self.traits.iter().filter_map(FeatTrait::class).collect();
+        if !classes.is_empty()
+            && !self.has_trait(FeatTrait::Archetype)
+            && !character.class().is_some_and(|c| classes.contains(&c))
+        {
+            return Err(RulesError::WrongClass(classes[0]));
+        }
+
+        match self.prerequisites.iter().find(|p| !p.is_met(character)) {
+            Some(missing) => Err(RulesError::PrerequisiteNotMet(missing.clone())),
+            None => Ok(()),
+        }
+    }
+}
+
+/// 재주 슬롯
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct FeatSlot {
+    pub kind: FeatKind,
+    pub level: i32,
+}
+
+/// `level`까지 생기는 재주 슬롯, 레벨 순
+///
+/// 혈통 1·5·9·13·17, 클래스 1과 짝수 레벨, 기술 짝수 레벨 (Rogue는 매 레벨),
+/// 일반 3·7·11·15·19.
+pub fn feat_slots(class: Option<Class>, level: i32) -> Vec<FeatSlot> {
+    let mut slots = Vec::new();
+    for l in 1..=level {
+        let mut add = |kind| slots.push(FeatSlot { kind, level: l });
+        if l % 4 == 1 {
+            add(FeatKind::Ancestry);
+        }
+        if l == 1 || l % 2 == 0 {
+            add(FeatKind::Class);
+        }
+        if l % 2 == 0 || class == Some(Class::Rogue) {
+            add(FeatKind::Skill);
+        }
+        if l % 4 == 3 {
+            add(FeatKind::General);
+        }
+    }
+    slots
+}
+
+/// 고른 재주의 상시 보정을 지속 효과로 만든다
+pub fn feat_effects<Id>(character: &Character) -> Vec<ActiveEffect<Id>> {
+    character
+        .feats()
+        .iter()
+        .filter_map(|(_, feat)| match feat.effect {
+            FeatEffect::Passive(modifier) => Some(
+                ActiveEffect::new(&feat.name, EffectDuration::Unlimited).with_modifier(modifier),
+            ),
+            _ => None,
+        })
+        .collect()
+}
+
+fn feat(
+    name: &str,
+    level: i32,
+    kind: FeatKind,
+    traits: &[FeatTrait],
+    prerequisites: Vec<Prerequisite>,
+    effect: FeatEffect,
+) -> Feat {
+    Feat {
+        name: name.to_string(),
+        level,
+        kind,
+        traits: traits.to_vec(),
+        prerequisites,
+        effect,
+    }
+}
+
+/// Power Attack: 2 액션 Flourish Strike, 무기 피해 주사위 +1 (10레벨 +2, 18레벨 +3), MAP는 2번으로 센다
+pub const POWER_ATTACK: StrikeActivity = StrikeActivity {
+    actions: 2,
+    extra_die_levels: &[1, 10, 18],
+    attacks: 2,
+    flourish: true,
+};
+
+/// 기본 수록 재주 (Core Rulebook 일부)
+pub fn feat_registry() -> Vec<Feat> {
+    let skill_feat = [FeatTrait::General, FeatTrait::Skill];
+    let trained = |skill| vec![Prerequisite::Skill(skill, Proficiency::Trained)];
+
+    vec![
+        feat(
+            "Power Attack",
+            1,
+            FeatKind::Class,
+            &[FeatTrait::Fighter, FeatTrait::Flourish],
+            Vec::new(),
+            FeatEffect::Strike(POWER_ATTACK),
+        ),
+        feat(
+            "Furious Focus",
+            6,
+            FeatKind::Class,
+            &[FeatTrait::Fighter],
+            vec![Prerequisite::Feat("Power Attack".to_string())],
+            FeatEffect::None,
+        ),
+        feat(
+            "Sudden Charge",
+            1,
+            FeatKind::Class,
+            &[
+                FeatTrait::Barbarian,
+                FeatTrait::Fighter,
+                FeatTrait::Flourish,
+            ],
+            Vec::new(),
+            FeatEffect::None,
+        ),
+        feat(
+            "Incredible Initiative",
+            1,
+            FeatKind::General,
+            &[FeatTrait::General],
+            Vec::new(),
+            FeatEffect::Passive(Modifier::new(
+                Statistic::Initiative,
+                BonusType::Circumstance,
+                2,
+            )),
+        ),
+        feat(
+            "Toughness",
+            1,
+            FeatKind::General,
+            &[FeatTrait::General],
+            Vec::new(),
+            FeatEffect::HitPoints(1),
+        ),
+        feat(
+            "Battle Medicine",
+            1,
+            FeatKind::Skill,
+            &skill_feat,
+            trained(Skill::Medicine),
+            FeatEffect::None,
+        ),
+        feat(
+            "Intimidating Glare",
+            1,
+            FeatKind::Skill,
+            &skill_feat,
+            trained(Skill::Intimidation),
+            FeatEffect::None,
+        ),
+        feat(
+            "Kip Up",
+            7,
+            FeatKind::Skill,
+            &skill_feat,
+            vec![Prerequisite::Skill(Skill::Acrobatics, Proficiency::Master)],
+            FeatEffect::None,
+        ),
+        feat(
+            "Fighter Dedication",
+            2,
+            FeatKind::Class,
+            &[FeatTrait::Archetype, FeatTrait::Dedication],
+            vec![
+                Prerequisite::Ability(Ability::Strength, 14),
+                Prerequisite::Ability(Ability::Dexterity, 14),
+            ],
+            FeatEffect::None,
+        ),
+        feat(
+            "Rogue Dedication",
+            2,
+            FeatKind::Class,
+            &[
+                FeatTrait::Archetype,
+                FeatTrait::Dedication,
+                FeatTrait::Rogue,
+            ],
+            vec![Prerequisite::Ability(Ability::Dexterity, 14)],
+            FeatEffect::None,
+        ),
+    ]
+}
+
+/// 이름으로 기본 수록 재주를 찾는다
+pub fn find_feat(name: &str) -> Option<Feat> {
+    feat_registry().into_iter().find(|f| f.name == name)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    use super::*;
+    use crate::character::{Attribute, Defense};
+    use crate::combat::Weapon;
+    use crate::duration::EffectRegistry;
+    use crate::locale::{Locale, Localize};
+
+    fn fighter(level: i32) -> Character {
+        Character::new(
+            10,
+            Attribute {
+                strength: 18,
+                dexterity: 12,
+                constitution: 14,
+                intelligence: 10,
+                wisdom: 12,
+                charisma: 10,
+            },
+            Defense {
+                armor_item: 4,
+                armor_proficiency: Proficiency::Trained,
+            },
+            Proficiency::Trained,
+        )
+        .with_level(level)
+        .unwrap()
+        .with_class(Class::Fighter)
+    }
+
+    fn slot(kind: FeatKind, level: i32) -> FeatSlot {
+        FeatSlot { kind, level }
+    }
+
+    #[test]
+    fn test_feat_slots() {
+        use FeatKind::*;
+
+        assert_eq!(
+            feat_slots(Some(crate::class::Class::Fighter), 3),
+            vec![
+                slot(Ancestry, 1),
+                slot(Class, 1),
+                slot(Class, 2),
+                slot(Skill, 2),
+                slot(General, 3),
+            ]
+        );
+
+        let count = |class, kind| {
+            feat_slots(class, 20)
+                .iter()
+                .filter(|s| s.kind == kind)
+                .count()
+        };
+        assert_eq!(count(None, Ancestry), 5);
+        assert_eq!(count(None, Class), 11);
+        assert_eq!(count(None, Skill), 10);
+        assert_eq!(count(None, General), 5);
+        assert_eq!(count(Some(crate::class::Class::Rogue), Skill), 20);
+    }
+
+    #[test]
+    fn test_take_feat() {
+        let mut fighter = fighter(2);
+        assert_eq!(fighter.open_feat_slots().len(), 4);
+
+        let power_attack = find_feat("Power Attack").unwrap();
+        fighter
+            .take_feat(&power_attack, slot(FeatKind::Class, 1))
+            .unwrap();
+        assert!(fighter.has_feat("Power Attack"));
+        assert_eq!(
+            fighter.take_feat(&power_attack, slot(FeatKind::Class, 2)),
+            Err(RulesError::FeatAlreadyTaken("Power Attack".to_string()))
+        );
+
+        // 채운 슬롯과 아직 없는 슬롯
+        let sudden_charge = find_feat("Sudden Charge").unwrap();
+        assert_eq!(
+            fighter.take_feat(&sudden_charge, slot(FeatKind::Class, 1)),
+            Err(RulesError::NoFeatSlot {
+                kind: FeatKind::Class,
+                level: 1
+            })
+        );
+        assert_eq!(
+            fighter.take_feat(&sudden_charge, slot(FeatKind::Class, 4)),
+            Err(RulesError::NoFeatSlot {
+                kind: FeatKind::Class,
+                level: 4
+            })
+        );
+
+        // 재주 종류가 슬롯과 맞지 않음
+        let toughness = find_feat("Toughness").unwrap();
+        assert_eq!(
+            fighter.take_feat(&toughness, slot(FeatKind::Skill, 2)),
+            Err(RulesError::NoFeatSlot {
+                kind: FeatKind::General,
+                level: 2
+            })
+        );
+
+        // 2레벨 슬롯에 6레벨 재주
+        let furious_focus = find_feat("Furious Focus").unwrap();
+        assert_eq!(
+            fighter.take_feat(&furious_focus, slot(FeatKind::Class, 2)),
+            Err(RulesError::FeatLevelTooHigh { feat: 6, slot: 2 })
+        );
+        assert_eq!(fighter.open_feat_slots().len(), 3);
+    }
+
+    #[test]
+    fn test_prerequisites() {
+        let mut fighter = fighter(6);
+
+        // 다른 재주
+        let furious_focus = find_feat("Furious Focus").unwrap();
+        assert_eq!(
+            furious_focus.check(&fighter, slot(FeatKind::Class, 6)),
+            Err(RulesError::PrerequisiteNotMet(Prerequisite::Feat(
+                "Power Attack".to_string()
+            )))
+        );
+        fighter
+            .take_feat(
+                &find_feat("Power Attack").unwrap(),
+                slot(FeatKind::Class, 1),
+            )
+            .unwrap();
+        assert_eq!(
+            furious_focus.check(&fighter, slot(FeatKind::Class, 6)),
+            Ok(())
+        );
+
+        // 기술 숙련도, 기술 재주는 일반 재주 슬롯에도
+        let glare = find_feat("Intimidating Glare").unwrap();
+        assert_eq!(
+            glare.check(&fighter, slot(FeatKind::Skill, 2)),
+            Err(RulesError::PrerequisiteNotMet(Prerequisite::Skill(
+                Skill::Intimidation,
+                Proficiency::Trained
+            )))
+        );
+        let fighter = fighter.with_skill(Skill::Intimidation, Proficiency::Trained);
+        assert_eq!(glare.check(&fighter, slot(FeatKind::General, 3)), Ok(()));
+
+        // 능력치: STR 18, DEX 12
+        let dedication = find_feat("Fighter Dedication").unwrap();
+        assert_eq!(
+            dedication.check(&fighter, slot(FeatKind::Class, 2)),
+            Err(RulesError::PrerequisiteNotMet(Prerequisite::Ability(
+                Ability::Dexterity,
+                14
+            )))
+        );
+
+        // 다른 클래스의 재주, 아키타입은 누구나
+        let rogue = fighter.clone().with_class(Class::Rogue);
+        assert_eq!(
+            find_feat("Sudden Charge")
+                .unwrap()
+                .check(&rogue, slot(FeatKind::Class, 2)),
+            Err(RulesError::WrongClass(Class::Barbarian))
+        );
+        assert_eq!(
+            find_feat("Rogue Dedication")
+                .unwrap()
+                .check(&fighter, slot(FeatKind::Class, 2)),
+            Err(RulesError::PrerequisiteNotMet(Prerequisite::Ability(
+                Ability::Dexterity,
+                14
+            )))
+        );
+
+        // 하나만 만족하면 되는 조건
+        let either = Prerequisite::Any(vec![
+            Prerequisite::Skill(Skill::Athletics, Proficiency::Expert),
+            Prerequisite::Weapon(Proficiency::Expert),
+        ]);
+        assert!(either.is_met(&fighter));
+        // 1레벨 Rogue는 무기 Trained
+        assert!(!either.is_met(&self::fighter(1).with_class(Class::Rogue)));
+        assert_eq!(
+            either.localize(Locale::Korean),
+            "운동 전문가 또는 무기 전문가"
+        );
+        assert_eq!(
+            either.localize(Locale::English),
+            "Athletics Expert or Weapons Expert"
+        );
+    }
+
+    #[test]
+    fn test_passive_feat_effects() {
+        let mut fighter = fighter(3);
+        fighter
+            .take_feat(
+                &find_feat("Incredible Initiative").unwrap(),
+                slot(FeatKind::General, 3),
+            )
+            .unwrap();
+
+        let mut effects = EffectRegistry::new();
+        for effect in feat_effects(&fighter) {
+            effects.add(effect);
+        }
+        assert_eq!(effects.modifier(Statistic::Initiative), 2);
+        // 재주 효과는 끝나지 않는다
+        effects.start_turn(0);
+        assert_eq!(effects.modifier(Statistic::Initiative), 2);
+    }
+
+    #[test]
+    fn test_power_attack() {
+        assert_eq!(POWER_ATTACK.extra_dice(1), 1);
+        assert_eq!(POWER_ATTACK.extra_dice(10), 2);
+        assert_eq!(POWER_ATTACK.extra_dice(18), 3);
+
+        let greatsword = Weapon {
+            name: "Greatsword".to_string(),
+            damage: Dice::new(1, 12),
+            agile: false,
+        };
+        let attacker = Combatant::new("Fighter", fighter(1), greatsword, 9, 4);
+        let mut rng = StdRng::seed_from_u64(3);
+
+        for _ in 0..100 {
+            let mut turn = CombatTurn::new();
+            let mut strikes_made = 0;
+            let (degree, damage) = POWER_ATTACK
+                .strike(&attacker, &mut turn, &mut strikes_made, 18, &mut rng)
+                .unwrap();
+
+            // 2d12 + 4, 치명타면 두 배
+            let range = match degree {
+                DegreeOfSuccess::CriticalSuccess => 12..=56,
+                DegreeOfSuccess::Success => 6..=28,
+                _ => 0..=0,
+            };
+            assert!(range.contains(&damage));
+            assert_eq!(turn.remaining_actions(), 1);
+            // 다음 Strike는 -10
+            assert_eq!(strikes_made, 2);
+
+            assert_eq!(
+                POWER_ATTACK.strike(&attacker, &mut turn, &mut strikes_made, 18, &mut rng),
+                Err(RulesError::FlourishUsed)
+            );
+        }
+    }
+
+    #[test]
+    fn test_one_flourish_per_turn() {
+        let greatsword = Weapon {
+            name: "Greatsword".to_string(),
+            damage: Dice::new(1, 12),
+            agile: false,
+        };
+        let attacker = Combatant::new("Fighter", fighter(1), greatsword, 9, 4);
+        let mut rng = StdRng::seed_from_u64(5);
+
+        // 액션이 남아도 두 번째 Flourish는 할 수 없다
+        let mut turn = CombatTurn::with_actions(4);
+        let mut strikes_made = 0;
+        POWER_ATTACK
+            .strike(&attacker, &mut turn, &mut strikes_made, 18, &mut rng)
+            .unwrap();
+        assert_eq!(
+            POWER_ATTACK.strike(&attacker, &mut turn, &mut strikes_made, 18, &mut rng),
+            Err(RulesError::FlourishUsed)
+        );
+        assert_eq!(turn.remaining_actions(), 2);
+        assert_eq!(strikes_made, 2);
+
+        // 액션이 모자라 실패하면 Flourish를 쓰지 않은 것
+        let mut turn = CombatTurn::with_actions(1);
+        assert_eq!(
+            POWER_ATTACK.strike(&attacker, &mut turn, &mut strikes_made, 18, &mut rng),
+            Err(RulesError::InsufficientActions {
+                cost: 2,
+                remaining: 1
+            })
+        );
+        assert!(!turn.flourished());
+
+        // 새 턴이면 다시 쓸 수 있다
+        let mut turn = CombatTurn::new();
+        assert!(
+            POWER_ATTACK
+                .strike(&attacker, &mut turn, &mut strikes_made, 18, &mut rng)
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_toughness() {
+        let mut fighter = fighter(3);
+        // (10 + CON 2) × 3
+        assert_eq!(fighter.max_hp(), 36);
+        fighter.take_damage(6).unwrap();
+
+        fighter
+            .take_feat(&find_feat("Toughness").unwrap(), slot(FeatKind::General, 3))
+            .unwrap();
+        assert_eq!(fighter.max_hp(), 39);
+        assert_eq!(fighter.current_hp(), 33);
+        // 레벨이 오르면 함께 늘어난다
+        let fighter = fighter.with_level(5).unwrap();
+        assert_eq!(fighter.max_hp(), 65);
+    }
+}
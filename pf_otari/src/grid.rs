@@ -0,0 +1,182 @@
+/// 전투 격자 좌표, 한 칸은 5피트
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone, Default)]
+pub struct GridPos {
+    pub x: i32,
+    pub y: i32,
+}
+
+pub const SQUARE_FEET: i32 = 5;
+
+impl GridPos {
+    pub fn new(x: i32, y: i32) -> Self {
+        Self { x, y }
+    }
+
+    /// 두 칸 사이 거리 (피트)
+    ///
+    /// 대각선 이동은 5피트, 10피트를 번갈아 계산한다.
+    pub fn distance_feet(&self, other: GridPos) -> i32 {
+        let dx = (self.x - other.x).abs();
+        let dy = (self.y - other.y).abs();
+        let diagonal = i32::min(dx, dy);
+        let straight = i32::max(dx, dy) - diagonal;
+
+        (straight + diagonal + diagonal / 2) * SQUARE_FEET
+    }
+
+    /// 근접 공격 가능 여부 (기본 간격 5피트)
+    pub fn is_adjacent(&self, other: GridPos) -> bool {
+        *self != other && self.distance_feet(other) <= SQUARE_FEET
+    }
+
+    /// `from`에서 멀어지는 방향으로 최대 `feet`만큼 밀려난 위치
+    pub fn push_away(&self, from: GridPos, feet: i32) -> GridPos {
+        self.push_away_clear(from, feet, &[])
+    }
+
+    /// `push_away`와 같지만 `occupied` 칸에 부딪히면 그 앞에서 멈춘다
+    pub fn push_away_clear(&self, from: GridPos, feet: i32, occupied: &[GridPos]) -> GridPos {
+        let dx = (self.x - from.x).signum();
+        let dy = (self.y - from.y).signum();
+        if dx == 0 && dy == 0 {
+            return *self;
+        }
+
+        let mut pos = *self;
+        let mut moved = 0;
+        let mut diagonals = 0;
+        loop {
+            let cost = if dx != 0 && dy != 0 {
+                if diagonals % 2 == 1 { 10 } else { 5 }
+            } else {
+                SQUARE_FEET
+            };
+            let next = GridPos::new(pos.x + dx, pos.y + dy);
+            if moved + cost > feet || occupied.contains(&next) {
+                return pos;
+            }
+            if dx != 0 && dy != 0 {
+                diagonals += 1;
+            }
+            moved += cost;
+            pos = next;
+        }
+    }
+
+    /// `target` 방향으로 최대 `feet`만큼 이동한 위치, 도착 칸 바로 앞에서 멈춘다
+    pub fn step_toward(&self, target: GridPos, feet: i32) -> GridPos {
+        let mut pos = *self;
+        let mut diagonals = 0;
+        let mut moved = 0;
+
+        while !pos.is_adjacent(target) && pos != target {
+            let dx = (target.x - pos.x).signum();
+            let dy = (target.y - pos.y).signum();
+            let cost = if dx != 0 && dy != 0 {
+                // 두 번째 대각선마다 10피트
+                if diagonals % 2 == 1 { 10 } else { 5 }
+            } else {
+                SQUARE_FEET
+            };
+
+            if moved + cost > feet {
+                break;
+            }
+
+            if dx != 0 && dy != 0 {
+                diagonals += 1;
+            }
+            moved += cost;
+            pos = GridPos::new(pos.x + dx, pos.y + dy);
+        }
+
+        pos
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_distance_feet() {
+        let origin = GridPos::new(0, 0);
+
+        assert_eq!(origin.distance_feet(GridPos::new(3, 0)), 15);
+        assert_eq!(origin.distance_feet(GridPos::new(1, 1)), 5); // 첫 대각선 5
+        assert_eq!(origin.distance_feet(GridPos::new(2, 2)), 15); // 5 + 10
+        assert_eq!(origin.distance_feet(GridPos::new(3, 3)), 20); // 5 + 10 + 5
+        assert_eq!(origin.distance_feet(GridPos::new(4, 1)), 20); // 대각 1 + 직선 3
+    }
+
+    #[test]
+    fn test_adjacent() {
+        let origin = GridPos::new(0, 0);
+
+        assert!(origin.is_adjacent(GridPos::new(1, 1)));
+        assert!(origin.is_adjacent(GridPos::new(0, -1)));
+        assert!(!origin.is_adjacent(GridPos::new(2, 0)));
+        assert!(!origin.is_adjacent(origin));
+    }
+
+    #[test]
+    fn test_step_toward() {
+        let origin = GridPos::new(0, 0);
+
+        // 25피트 이동으로 6칸 떨어진 적 바로 앞까지
+        assert_eq!(
+            origin.step_toward(GridPos::new(6, 0), 25),
+            GridPos::new(5, 0)
+        );
+        // 속도가 모자라면 중간에서 멈춤
+        assert_eq!(
+            origin.step_toward(GridPos::new(10, 0), 25),
+            GridPos::new(5, 0)
+        );
+        // 대각선: 5 + 10 + 5 = 20피트로 3칸
+        assert_eq!(
+            origin.step_toward(GridPos::new(5, 5), 20),
+            GridPos::new(3, 3)
+        );
+        // 이미 인접하면 움직이지 않음
+        assert_eq!(origin.step_toward(GridPos::new(1, 0), 25), origin);
+    }
+
+    #[test]
+    fn test_push_away() {
+        let target = GridPos::new(1, 0);
+
+        assert_eq!(target.push_away(GridPos::new(0, 0), 10), GridPos::new(3, 0));
+        // 대각선: 5 + 10
+        assert_eq!(
+            target.push_away(GridPos::new(0, -1), 10),
+            GridPos::new(2, 1)
+        );
+        assert_eq!(
+            target.push_away(GridPos::new(0, -1), 15),
+            GridPos::new(3, 2)
+        );
+        assert_eq!(target.push_away(target, 10), target);
+    }
+
+    #[test]
+    fn test_push_away_clear() {
+        let target = GridPos::new(1, 0);
+        let wall = [GridPos::new(3, 0)];
+
+        assert_eq!(
+            target.push_away_clear(GridPos::new(0, 0), 10, &wall),
+            GridPos::new(2, 0)
+        );
+        // 바로 뒤가 막혀 있으면 움직이지 않음
+        assert_eq!(
+            target.push_away_clear(GridPos::new(0, 0), 10, &[GridPos::new(2, 0)]),
+            target
+        );
+        assert_eq!(
+            target.push_away_clear(GridPos::new(0, 0), 10, &[]),
+            target.push_away(GridPos::new(0, 0), 10)
+        );
+    }
+}
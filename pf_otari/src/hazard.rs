@@ -0,0 +1,517 @@
+use rand::Rng;
+
+use crate::character::{Character, Proficiency, Skill};
+use crate::check::{Check, CheckOutcome, SaveType};
+use crate::combat::{calc_damage, strike_character};
+use crate::dice::{DegreeOfSuccess, Dice, roll_d20};
+use crate::error::RulesError;
+use crate::outcome::OutcomeTable;
+
+/// 위험 요소의 공격: Strike 또는 기본 내성
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum HazardAttack {
+    Strike {
+        attack_bonus: i32,
+        damage: Dice,
+        damage_bonus: i32,
+    },
+    /// 기본 내성 (basic saving throw)
+    Save {
+        save: SaveType,
+        dc: i32,
+        damage: Dice,
+        damage_bonus: i32,
+    },
+}
+
+/// 공격 한 번의 결과, 등급은 Strike면 위험 요소 기준, 내성이면 대상 기준
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct HazardHit {
+    pub degree: DegreeOfSuccess,
+    pub damage: i32,
+}
+
+impl HazardAttack {
+    /// 대상에게 공격하고 피해를 적용한다
+    pub fn resolve(
+        &self,
+        target: &mut Character,
+        rng: &mut impl Rng,
+    ) -> Result<HazardHit, RulesError> {
+        match self {
+            HazardAttack::Strike {
+                attack_bonus,
+                damage,
+                damage_bonus,
+            } => {
+                let degree = strike_character(roll_d20(rng), *attack_bonus, target)?;
+                let damage = calc_damage(degree, damage.roll(rng), *damage_bonus);
+                if degree == DegreeOfSuccess::CriticalSuccess {
+                    target.take_critical_damage(damage)?;
+                } else {
+                    target.take_damage(damage)?;
+                }
+                Ok(HazardHit { degree, damage })
+            }
+            HazardAttack::Save {
+                save,
+                dc,
+                damage,
+                damage_bonus,
+            } => {
+                let degree = Check::new(target.save_bonus(*save), *dc).roll(rng).degree;
+                let damage = OutcomeTable::basic_save()
+                    .effect(degree)
+                    .damage
+                    .apply(damage.roll(rng) + damage_bonus);
+                if degree == DegreeOfSuccess::CriticalFailure {
+                    target.take_critical_damage(damage)?;
+                } else {
+                    target.take_damage(damage)?;
+                }
+                Ok(HazardHit { degree, damage })
+            }
+        }
+    }
+}
+
+/// 복합 위험 요소가 자기 턴마다 하는 행동
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Routine {
+    /// 턴마다 하는 공격 수
+    pub actions: u8,
+    pub attack: HazardAttack,
+}
+
+/// 해제 방법: 기술, DC, 필요한 최소 숙련도
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct Disable {
+    pub skill: Skill,
+    pub dc: i32,
+    pub proficiency: Proficiency,
+}
+
+/// Disable a Device 결과
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum DisableResult {
+    /// 성공했지만 더 해제해야 함
+    Progress {
+        remaining: u8,
+    },
+    Disabled,
+    NoEffect,
+    /// 치명적 실패로 위험 요소가 작동함
+    Triggered,
+}
+
+/// 위험 요소의 현재 상태
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum HazardState {
+    /// 작동 전
+    Armed,
+    /// 작동한 복합 위험 요소, 우선권 순서에 들어간다
+    Active,
+    /// 한 번 작동하고 끝난 단순 위험 요소
+    Spent,
+    Disabled,
+}
+
+/// 함정과 위험 요소
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Hazard {
+    pub name: String,
+    pub level: i32,
+    pub stealth_dc: i32,
+    /// 이 숙련도 미만이거나 수색하지 않으면 찾을 수 없다
+    pub minimum_proficiency: Option<Proficiency>,
+    pub disables: Vec<Disable>,
+    /// 해제에 필요한 성공 수
+    pub successes_needed: u8,
+    /// 작동할 때의 공격
+    pub trigger: HazardAttack,
+    /// 복합 위험 요소만 있음
+    pub routine: Option<Routine>,
+    state: HazardState,
+    successes: u8,
+}
+
+impl Hazard {
+    /// 한 번 작동하고 끝나는 단순 위험 요소
+    pub fn simple(name: &str, level: i32, stealth_dc: i32, trigger: HazardAttack) -> Self {
+        Self {
+            name: name.to_string(),
+            level,
+            stealth_dc,
+            minimum_proficiency: None,
+            disables: Vec::new(),
+            successes_needed: 1,
+            trigger,
+            routine: None,
+            state: HazardState::Armed,
+            successes: 0,
+        }
+    }
+
+    /// 작동하면 우선권을 굴리고 라운드마다 행동하는 복합 위험 요소
+    pub fn complex(
+        name: &str,
+        level: i32,
+        stealth_dc: i32,
+        trigger: HazardAttack,
+        routine: Routine,
+    ) -> Self {
+        Self {
+            routine: Some(routine),
+            ..Self::simple(name, level, stealth_dc, trigger)
+        }
+    }
+
+    pub fn with_minimum_proficiency(mut self, proficiency: Proficiency) -> Self {
+        self.minimum_proficiency = Some(proficiency);
+        self
+    }
+
+    pub fn with_disable(mut self, skill: Skill, dc: i32, proficiency: Proficiency) -> Self {
+        self.disables.push(Disable {
+            skill,
+            dc,
+            proficiency,
+        });
+        self
+    }
+
+    pub fn with_successes_needed(mut self, successes: u8) -> Self {
+        self.successes_needed = successes.max(1);
+        self
+    }
+
+    pub fn is_complex(&self) -> bool {
+        self.routine.is_some()
+    }
+
+    pub fn state(&self) -> HazardState {
+        self.state
+    }
+
+    /// 우선권은 Stealth 수정치 (`Stealth DC - 10`)로 굴린다
+    pub fn roll_initiative(&self, rng: &mut impl Rng) -> i32 {
+        roll_d20(rng) as i32 + self.stealth_dc - 10
+    }
+
+    /// 지각 판정으로 찾는다, Success 이상이면 발견.
+    /// 최소 숙련도가 있으면 그 숙련도로 수색(Search, Seek)하는 캐릭터만 판정한다
+    pub fn detect(
+        &self,
+        searcher: &Character,
+        searching: bool,
+        rng: &mut impl Rng,
+    ) -> Option<CheckOutcome> {
+        if let Some(minimum) = self.minimum_proficiency
+            && (!searching || searcher.perception_proficiency() < minimum)
+        {
+            return None;
+        }
+        Some(Check::new(searcher.perception_bonus(), self.stealth_dc).roll(rng))
+    }
+
+    /// 작동시킨다, 단순 위험 요소는 끝나고 복합 위험 요소는 활성화된다
+    pub fn trigger(&mut self) -> Result<&HazardAttack, RulesError> {
+        if self.state != HazardState::Armed {
+            return Err(RulesError::HazardInactive(self.name.clone()));
+        }
+        self.state = if self.is_complex() {
+            HazardState::Active
+        } else {
+            HazardState::Spent
+        };
+        Ok(&self.trigger)
+    }
+
+    /// 활성화된 복합 위험 요소의 이번 턴 행동
+    pub fn routine(&self) -> Option<&Routine> {
+        match self.state {
+            HazardState::Active => self.routine.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Disable a Device 등 해제 판정
+    ///
+    /// 성공 이상이면 해제 성공 하나, 실패는 효과 없음, 치명적 실패는 작동한다.
+    pub fn disable(
+        &mut self,
+        character: &Character,
+        skill: Skill,
+        rng: &mut impl Rng,
+    ) -> Result<(CheckOutcome, DisableResult), RulesError> {
+        if matches!(self.state, HazardState::Spent | HazardState::Disabled) {
+            return Err(RulesError::HazardInactive(self.name.clone()));
+        }
+        let disable = self
+            .disables
+            .iter()
+            .find(|d| d.skill == skill)
+            .copied()
+            .ok_or(RulesError::NoDisableSkill(skill))?;
+        if character.skill_proficiency(skill) < disable.proficiency {
+            return Err(RulesError::ProficiencyTooLow {
+                skill,
+                required: disable.proficiency,
+            });
+        }
+
+        let outcome = Check::new(character.skill_bonus(skill), disable.dc).roll(rng);
+        let result = match outcome.degree {
+            DegreeOfSuccess::CriticalSuccess | DegreeOfSuccess::Success => {
+                // 치명적 성공은 성공 두 번으로 센다
+                self.successes += if outcome.degree == DegreeOfSuccess::CriticalSuccess {
+                    2
+                } else {
+                    1
+                };
+                if self.successes >= self.successes_needed {
+                    self.state = HazardState::Disabled;
+                    DisableResult::Disabled
+                } else {
+                    DisableResult::Progress {
+                        remaining: self.successes_needed - self.successes,
+                    }
+                }
+            }
+            DegreeOfSuccess::Failure => DisableResult::NoEffect,
+            DegreeOfSuccess::CriticalFailure => {
+                // 이미 활성화된 복합 위험 요소는 그대로 행동한다
+                if self.state == HazardState::Armed {
+                    self.trigger()?;
+                }
+                DisableResult::Triggered
+            }
+        };
+        Ok((outcome, result))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    use super::*;
+    use crate::character::{Attribute, Defense};
+
+    fn rogue(thievery: Proficiency, perception: Proficiency) -> Character {
+        let stats = Attribute {
+            strength: 10,
+            dexterity: 18,
+            constitution: 12,
+            intelligence: 14,
+            wisdom: 12,
+            charisma: 10,
+        };
+        let armor = Defense {
+            armor_item: 1,
+            armor_proficiency: Proficiency::Trained,
+        };
+        Character::new(8, stats, armor, perception).with_skill(Skill::Thievery, thievery)
+    }
+
+    /// Core Rulebook의 Hidden Pit
+    fn hidden_pit() -> Hazard {
+        Hazard::simple(
+            "Hidden Pit",
+            0,
+            18,
+            HazardAttack::Save {
+                save: SaveType::Reflex,
+                dc: 20,
+                damage: Dice::new(2, 6),
+                damage_bonus: 0,
+            },
+        )
+        .with_disable(Skill::Thievery, 12, Proficiency::Trained)
+    }
+
+    fn poisoned_dart_gallery() -> Hazard {
+        let dart = HazardAttack::Strike {
+            attack_bonus: 21,
+            damage: Dice::new(3, 4),
+            damage_bonus: 0,
+        };
+        Hazard::complex(
+            "Poisoned Dart Gallery",
+            8,
+            31,
+            dart.clone(),
+            Routine {
+                actions: 1,
+                attack: dart,
+            },
+        )
+        .with_minimum_proficiency(Proficiency::Expert)
+        .with_disable(Skill::Thievery, 21, Proficiency::Expert)
+        .with_successes_needed(3)
+    }
+
+    #[test]
+    fn test_detect() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let trained = rogue(Proficiency::Trained, Proficiency::Trained);
+        let expert = rogue(Proficiency::Trained, Proficiency::Expert);
+
+        assert!(hidden_pit().detect(&trained, false, &mut rng).is_some());
+
+        let gallery = poisoned_dart_gallery();
+        assert_eq!(gallery.detect(&trained, true, &mut rng), None);
+        assert_eq!(gallery.detect(&expert, false, &mut rng), None);
+        let outcome = gallery.detect(&expert, true, &mut rng).unwrap();
+        assert_eq!(
+            outcome.total,
+            outcome.natural as i32 + expert.perception_bonus()
+        );
+    }
+
+    #[test]
+    fn test_simple_hazard_triggers_once() {
+        let mut rng = StdRng::seed_from_u64(5);
+        let mut target = rogue(Proficiency::Trained, Proficiency::Trained);
+        let before = target.current_hp();
+
+        let mut pit = hidden_pit();
+        let attack = pit.trigger().unwrap().clone();
+        let hit = attack.resolve(&mut target, &mut rng).unwrap();
+
+        assert_eq!(target.current_hp(), (before - hit.damage).max(0));
+        assert_eq!(pit.state(), HazardState::Spent);
+        assert_eq!(pit.routine(), None);
+        assert_eq!(
+            pit.trigger(),
+            Err(RulesError::HazardInactive("Hidden Pit".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_critical_failure_save_makes_dying_2() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let mut target = rogue(Proficiency::Trained, Proficiency::Trained);
+        let collapse = HazardAttack::Save {
+            save: SaveType::Reflex,
+            dc: 40,
+            damage: Dice::new(2, 6),
+            damage_bonus: 20,
+        };
+
+        let hit = collapse.resolve(&mut target, &mut rng).unwrap();
+        assert_eq!(hit.degree, DegreeOfSuccess::CriticalFailure);
+        assert_eq!(target.current_hp(), 0);
+        assert_eq!(target.dying(), 2);
+    }
+
+    #[test]
+    fn test_disable() {
+        let trained = rogue(Proficiency::Trained, Proficiency::Trained);
+        let untrained = rogue(Proficiency::Untrained, Proficiency::Trained);
+        let mut rng = StdRng::seed_from_u64(7);
+
+        let mut pit = hidden_pit();
+        assert_eq!(
+            pit.disable(&untrained, Skill::Thievery, &mut rng),
+            Err(RulesError::ProficiencyTooLow {
+                skill: Skill::Thievery,
+                required: Proficiency::Trained
+            })
+        );
+        assert_eq!(
+            pit.disable(&trained, Skill::Athletics, &mut rng),
+            Err(RulesError::NoDisableSkill(Skill::Athletics))
+        );
+
+        // Thievery +7 vs DC 12: 4 + 7 실패, 7 + 7 성공
+        let mut rng = StdRng::seed_from_u64(5);
+        let (outcome, result) = pit.disable(&trained, Skill::Thievery, &mut rng).unwrap();
+        assert_eq!(
+            (outcome.natural, outcome.degree),
+            (4, DegreeOfSuccess::Failure)
+        );
+        assert_eq!(result, DisableResult::NoEffect);
+        assert_eq!(pit.state(), HazardState::Armed);
+
+        let (outcome, result) = pit.disable(&trained, Skill::Thievery, &mut rng).unwrap();
+        assert_eq!(
+            (outcome.natural, outcome.degree),
+            (7, DegreeOfSuccess::Success)
+        );
+        assert_eq!(result, DisableResult::Disabled);
+        assert_eq!(pit.state(), HazardState::Disabled);
+        assert_eq!(
+            pit.disable(&trained, Skill::Thievery, &mut rng),
+            Err(RulesError::HazardInactive("Hidden Pit".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_critical_disable_counts_twice() {
+        let expert = rogue(Proficiency::Expert, Proficiency::Expert)
+            .with_level(8)
+            .unwrap();
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut gallery = poisoned_dart_gallery();
+        gallery.trigger().unwrap();
+
+        // Thievery +16 vs DC 21: 17 + 16 = 33, 치명적 성공은 성공 두 번
+        let (outcome, result) = gallery.disable(&expert, Skill::Thievery, &mut rng).unwrap();
+        assert_eq!(outcome.degree, DegreeOfSuccess::CriticalSuccess);
+        assert_eq!(result, DisableResult::Progress { remaining: 1 });
+
+        let (outcome, result) = gallery.disable(&expert, Skill::Thievery, &mut rng).unwrap();
+        assert_eq!(outcome.degree, DegreeOfSuccess::CriticalSuccess);
+        assert_eq!(result, DisableResult::Disabled);
+        assert_eq!(gallery.state(), HazardState::Disabled);
+    }
+
+    #[test]
+    fn test_complex_hazard_needs_several_successes() {
+        let mut expert = rogue(Proficiency::Expert, Proficiency::Expert)
+            .with_level(8)
+            .unwrap();
+        let mut rng = StdRng::seed_from_u64(11);
+
+        let mut gallery = poisoned_dart_gallery();
+        assert!(gallery.is_complex());
+        gallery.trigger().unwrap();
+        assert_eq!(gallery.state(), HazardState::Active);
+        assert_eq!(gallery.routine().unwrap().actions, 1);
+
+        assert_eq!(gallery.roll_initiative(&mut rng), 26);
+
+        // Thievery +16 vs DC 21: 성공, 성공, 치명적 성공
+        let mut results = Vec::new();
+        while gallery.state() == HazardState::Active && results.len() < 10 {
+            let (outcome, result) = gallery.disable(&expert, Skill::Thievery, &mut rng).unwrap();
+            results.push((outcome.degree, result));
+        }
+        assert_eq!(
+            results,
+            vec![
+                (
+                    DegreeOfSuccess::Success,
+                    DisableResult::Progress { remaining: 2 }
+                ),
+                (
+                    DegreeOfSuccess::Success,
+                    DisableResult::Progress { remaining: 1 }
+                ),
+                (DegreeOfSuccess::CriticalSuccess, DisableResult::Disabled),
+            ]
+        );
+        assert_eq!(gallery.state(), HazardState::Disabled);
+        assert_eq!(gallery.routine(), None);
+
+        // 다트 +21 vs AC 25, 치명타 3d4 두 배
+        let attack = poisoned_dart_gallery().trigger.clone();
+        let hit = attack.resolve(&mut expert, &mut rng).unwrap();
+        assert_eq!(hit.degree, DegreeOfSuccess::CriticalSuccess);
+        assert_eq!(hit.damage, 16);
+        assert_eq!(expert.current_hp(), 72 - 16);
+    }
+}
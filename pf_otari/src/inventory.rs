@@ -0,0 +1,325 @@
+use std::fmt;
+use std::iter::Sum;
+use std::ops::Add;
+
+use crate::combat::Weapon;
+use crate::error::RulesError;
+use crate::treasure::Coins;
+
+/// 손은 두 개
+pub const HANDS: u8 = 2;
+
+/// 부피 (Bulk), 가벼운 물건(L) 10개가 1 Bulk
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Default)]
+pub struct Bulk {
+    light: u32,
+}
+
+impl Bulk {
+    /// 무시할 만한 부피 (-)
+    pub const NEGLIGIBLE: Bulk = Bulk { light: 0 };
+    /// 가벼움 (L)
+    pub const LIGHT: Bulk = Bulk { light: 1 };
+
+    pub fn new(bulk: u32) -> Self {
+        Self { light: bulk * 10 }
+    }
+
+    /// 온전한 Bulk 값, 남는 L은 버린다
+    pub fn whole(&self) -> u32 {
+        self.light / 10
+    }
+}
+
+impl Add for Bulk {
+    type Output = Bulk;
+
+    fn add(self, other: Bulk) -> Bulk {
+        Bulk {
+            light: self.light + other.light,
+        }
+    }
+}
+
+impl Sum for Bulk {
+    fn sum<I: Iterator<Item = Bulk>>(iter: I) -> Bulk {
+        iter.fold(Bulk::NEGLIGIBLE, Add::add)
+    }
+}
+
+impl fmt::Display for Bulk {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.whole(), self.light % 10) {
+            (0, 0) => write!(f, "-"),
+            (0, _) => write!(f, "L"),
+            (whole, 0) => write!(f, "{whole}"),
+            (whole, _) => write!(f, "{whole}, L"),
+        }
+    }
+}
+
+/// 갑옷
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Armor {
+    pub name: String,
+    /// 아이템 AC 보너스
+    pub ac_bonus: i32,
+    /// 민첩 수정값 상한, 없으면 `None`
+    pub dex_cap: Option<i32>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ItemKind {
+    Weapon(Weapon),
+    Armor(Armor),
+    Gear,
+}
+
+/// 소지품
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Item {
+    pub name: String,
+    pub kind: ItemKind,
+    pub bulk: Bulk,
+    /// 들고 쓸 때 필요한 손
+    pub hands: u8,
+    pub price: Coins,
+    /// 아이템 레벨
+    pub level: i32,
+    /// 한 번 쓰면 사라지는 소모품
+    pub consumable: bool,
+}
+
+impl Item {
+    pub fn weapon(weapon: Weapon, bulk: Bulk, hands: u8) -> Self {
+        Self {
+            name: weapon.name.clone(),
+            kind: ItemKind::Weapon(weapon),
+            bulk,
+            hands,
+            price: Coins::ZERO,
+            level: 0,
+            consumable: false,
+        }
+    }
+
+    pub fn armor(armor: Armor, bulk: Bulk) -> Self {
+        Self {
+            name: armor.name.clone(),
+            kind: ItemKind::Armor(armor),
+            bulk,
+            hands: 0,
+            price: Coins::ZERO,
+            level: 0,
+            consumable: false,
+        }
+    }
+
+    pub fn gear(name: &str, bulk: Bulk, hands: u8) -> Self {
+        Self {
+            name: name.to_string(),
+            kind: ItemKind::Gear,
+            bulk,
+            hands,
+            price: Coins::ZERO,
+            level: 0,
+            consumable: false,
+        }
+    }
+
+    pub fn with_price(mut self, price: Coins) -> Self {
+        self.price = price;
+        self
+    }
+
+    pub fn with_level(mut self, level: i32) -> Self {
+        self.level = level;
+        self
+    }
+
+    pub fn consumable(mut self) -> Self {
+        self.consumable = true;
+        self
+    }
+}
+
+/// 소지 상태
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ItemState {
+    /// 몸에 착용 (갑옷은 착용해야 AC에 반영)
+    Worn,
+    /// 손에 듦 (무기는 들어야 Strike에 사용)
+    Held,
+    /// 가방에 넣음
+    Stowed,
+}
+
+/// 소지품 목록
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Inventory {
+    items: Vec<(Item, ItemState)>,
+}
+
+impl Inventory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn items(&self) -> impl Iterator<Item = (&Item, ItemState)> {
+        self.items.iter().map(|(item, state)| (item, *state))
+    }
+
+    pub fn total_bulk(&self) -> Bulk {
+        self.items.iter().map(|(item, _)| item.bulk).sum()
+    }
+
+    pub fn hands_used(&self) -> u8 {
+        self.items
+            .iter()
+            .filter(|(_, state)| *state == ItemState::Held)
+            .map(|(item, _)| item.hands)
+            .sum()
+    }
+
+    /// 착용한 갑옷, 여러 벌이면 첫 번째
+    pub fn worn_armor(&self) -> Option<&Armor> {
+        self.items
+            .iter()
+            .find_map(|(item, state)| match &item.kind {
+                ItemKind::Armor(armor) if *state == ItemState::Worn => Some(armor),
+                _ => None,
+            })
+    }
+
+    /// 손에 든 무기
+    pub fn held_weapons(&self) -> impl Iterator<Item = &Weapon> {
+        self.items
+            .iter()
+            .filter_map(|(item, state)| match &item.kind {
+                ItemKind::Weapon(weapon) if *state == ItemState::Held => Some(weapon),
+                _ => None,
+            })
+    }
+
+    /// 손이 모자라면 넣지 않는다, 추가한 위치를 돌려준다
+    pub fn add(&mut self, item: Item, state: ItemState) -> Result<usize, RulesError> {
+        if state == ItemState::Held {
+            self.check_hands(item.hands)?;
+        }
+        self.items.push((item, state));
+        Ok(self.items.len() - 1)
+    }
+
+    pub fn set_state(&mut self, index: usize, state: ItemState) -> Result<(), RulesError> {
+        let (item, current) = self.items.get(index).ok_or(RulesError::NoSuchItem(index))?;
+        if state == ItemState::Held && *current != ItemState::Held {
+            self.check_hands(item.hands)?;
+        }
+        self.items[index].1 = state;
+        Ok(())
+    }
+
+    pub fn remove(&mut self, index: usize) -> Result<Item, RulesError> {
+        if index >= self.items.len() {
+            return Err(RulesError::NoSuchItem(index));
+        }
+        Ok(self.items.remove(index).0)
+    }
+
+    fn check_hands(&self, needed: u8) -> Result<(), RulesError> {
+        let free = HANDS.saturating_sub(self.hands_used());
+        if needed > free {
+            return Err(RulesError::HandsFull { needed, free });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::dice::Dice;
+
+    fn longsword() -> Item {
+        Item::weapon(
+            Weapon {
+                name: "Longsword".to_string(),
+                damage: Dice::new(1, 8),
+                agile: false,
+            },
+            Bulk::new(1),
+            1,
+        )
+    }
+
+    fn greataxe() -> Item {
+        Item::weapon(
+            Weapon {
+                name: "Greataxe".to_string(),
+                damage: Dice::new(1, 12),
+                agile: false,
+            },
+            Bulk::new(2),
+            2,
+        )
+    }
+
+    #[test]
+    fn test_bulk_total() {
+        let mut bulk = Bulk::new(2);
+        for _ in 0..12 {
+            bulk = bulk + Bulk::LIGHT;
+        }
+        // 2 + L 12개 → 3 Bulk, 남는 L 2개는 버림
+        assert_eq!(bulk.whole(), 3);
+        assert_eq!(bulk.to_string(), "3, L");
+        assert_eq!(Bulk::LIGHT.to_string(), "L");
+        assert_eq!(Bulk::NEGLIGIBLE.to_string(), "-");
+    }
+
+    #[test]
+    fn test_hands() {
+        let mut inventory = Inventory::new();
+        let sword = inventory.add(longsword(), ItemState::Held).unwrap();
+        inventory
+            .add(Item::gear("Torch", Bulk::LIGHT, 1), ItemState::Held)
+            .unwrap();
+        assert_eq!(inventory.hands_used(), 2);
+
+        assert_eq!(
+            inventory.add(greataxe(), ItemState::Held),
+            Err(RulesError::HandsFull { needed: 2, free: 0 })
+        );
+
+        // 도끼는 집어넣은 채로 들고, 검을 넣은 뒤에도 횃불 때문에 한 손이 모자람
+        let axe = inventory.add(greataxe(), ItemState::Stowed).unwrap();
+        inventory.set_state(sword, ItemState::Stowed).unwrap();
+        assert_eq!(
+            inventory.set_state(axe, ItemState::Held),
+            Err(RulesError::HandsFull { needed: 2, free: 1 })
+        );
+        assert_eq!(inventory.held_weapons().count(), 0);
+        assert_eq!(inventory.total_bulk().whole(), 3);
+    }
+
+    #[test]
+    fn test_worn_armor() {
+        let mut inventory = Inventory::new();
+        let armor = Armor {
+            name: "Chain Mail".to_string(),
+            ac_bonus: 4,
+            dex_cap: Some(1),
+        };
+        let index = inventory
+            .add(Item::armor(armor.clone(), Bulk::new(2)), ItemState::Stowed)
+            .unwrap();
+        assert_eq!(inventory.worn_armor(), None);
+
+        inventory.set_state(index, ItemState::Worn).unwrap();
+        assert_eq!(inventory.worn_armor(), Some(&armor));
+
+        assert_eq!(inventory.remove(index).unwrap().name, "Chain Mail");
+        assert_eq!(inventory.remove(index), Err(RulesError::NoSuchItem(0)));
+    }
+}
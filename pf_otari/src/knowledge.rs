@@ -0,0 +1,282 @@
+use rand::Rng;
+
+use crate::bestiary::{Creature, CreatureTrait};
+use crate::character::{Character, Skill};
+use crate::check::{Check, CheckOutcome, SaveType, level_dc};
+use crate::dice::DegreeOfSuccess;
+use crate::error::RulesError;
+
+/// 크리처 특성으로 Recall Knowledge에 쓸 수 있는 기술
+pub fn recall_skills(creature: &Creature) -> Vec<Skill> {
+    let mut skills = Vec::new();
+    for creature_trait in &creature.traits {
+        let trait_skills: &[Skill] = match creature_trait {
+            CreatureTrait::Aberration | CreatureTrait::Ooze => &[Skill::Occultism],
+            CreatureTrait::Animal | CreatureTrait::Fey => &[Skill::Nature],
+            CreatureTrait::Beast => &[Skill::Arcana, Skill::Nature],
+            CreatureTrait::Construct => &[Skill::Arcana, Skill::Crafting],
+            CreatureTrait::Dragon => &[Skill::Arcana],
+            CreatureTrait::Fiend | CreatureTrait::Undead => &[Skill::Religion],
+            CreatureTrait::Giant
+            | CreatureTrait::Goblin
+            | CreatureTrait::Humanoid
+            | CreatureTrait::Orc => &[Skill::Society],
+            // 지능이 없다는 것은 정체를 알려주지 않는다
+            CreatureTrait::Mindless => &[],
+        };
+        for skill in trait_skills {
+            if !skills.contains(skill) {
+                skills.push(*skill);
+            }
+        }
+    }
+    skills
+}
+
+/// 크리처 레벨 DC에 희귀도 조정을 더한 값
+pub fn recall_dc(creature: &Creature) -> Result<i32, RulesError> {
+    let dc = level_dc(creature.level).ok_or(RulesError::InvalidLevel(creature.level))?;
+    Ok(creature.rarity.adjust(dc))
+}
+
+/// 능력치 블록에서 알아낸 사실
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum CreatureFact {
+    LowestSave(SaveType, i32),
+    HighestSave(SaveType, i32),
+    ArmorClass(i32),
+    Strike { name: String, attack_bonus: i32 },
+    HitPoints(i32),
+}
+
+impl CreatureFact {
+    fn same_kind(&self, other: &CreatureFact) -> bool {
+        std::mem::discriminant(self) == std::mem::discriminant(other)
+    }
+}
+
+const SAVES: [SaveType; 3] = [SaveType::Fortitude, SaveType::Reflex, SaveType::Will];
+
+/// 가장 약한 내성과 가장 강한 내성
+fn save_extremes(creature: &Creature) -> (SaveType, SaveType) {
+    let lowest = SAVES.into_iter().min_by_key(|s| creature.save(*s)).unwrap();
+    let highest = SAVES.into_iter().max_by_key(|s| creature.save(*s)).unwrap();
+    (lowest, highest)
+}
+
+/// 알려줄 순서대로의 사실: 가장 약한 내성, 가장 강한 내성, AC, Strike, HP
+fn true_facts(creature: &Creature) -> Vec<CreatureFact> {
+    let (lowest, highest) = save_extremes(creature);
+
+    vec![
+        CreatureFact::LowestSave(lowest, creature.save(lowest)),
+        CreatureFact::HighestSave(highest, creature.save(highest)),
+        CreatureFact::ArmorClass(creature.ac),
+        CreatureFact::Strike {
+            name: creature.strike.name.clone(),
+            attack_bonus: creature.strike.attack_bonus,
+        },
+        CreatureFact::HitPoints(creature.hp),
+    ]
+}
+
+/// 그럴듯한 거짓: 약한 내성과 강한 내성을 뒤바꾸고, 수치는 실제보다 만만하게
+fn false_fact(creature: &Creature, fact: &CreatureFact) -> CreatureFact {
+    let (lowest, highest) = save_extremes(creature);
+
+    match fact {
+        CreatureFact::LowestSave(..) => CreatureFact::LowestSave(highest, creature.save(highest)),
+        CreatureFact::HighestSave(..) => CreatureFact::HighestSave(lowest, creature.save(lowest)),
+        CreatureFact::ArmorClass(ac) => CreatureFact::ArmorClass(ac - 4),
+        CreatureFact::Strike { name, attack_bonus } => CreatureFact::Strike {
+            name: name.clone(),
+            attack_bonus: attack_bonus - 4,
+        },
+        CreatureFact::HitPoints(hp) => CreatureFact::HitPoints(hp / 2),
+    }
+}
+
+/// 등급별로 얻는 정보, 아직 모르는 사실부터
+///
+/// Critical Success 2개, Success 1개, Failure 없음, Critical Failure 거짓 1개
+fn facts_for(
+    degree: DegreeOfSuccess,
+    creature: &Creature,
+    known: &[CreatureFact],
+) -> (Vec<CreatureFact>, bool) {
+    let mut unknown = true_facts(creature)
+        .into_iter()
+        .filter(|fact| !known.iter().any(|k| k.same_kind(fact)));
+
+    match degree {
+        DegreeOfSuccess::CriticalSuccess => (unknown.by_ref().take(2).collect(), false),
+        DegreeOfSuccess::Success => (unknown.by_ref().take(1).collect(), false),
+        DegreeOfSuccess::Failure => (Vec::new(), false),
+        DegreeOfSuccess::CriticalFailure => (
+            unknown
+                .next()
+                .map(|f| false_fact(creature, &f))
+                .into_iter()
+                .collect(),
+            true,
+        ),
+    }
+}
+
+/// Recall Knowledge 결과
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct RecallKnowledge {
+    pub skill: Skill,
+    pub dc: i32,
+    pub outcome: CheckOutcome,
+    pub facts: Vec<CreatureFact>,
+    /// Critical Failure로 얻은 거짓 정보인지 (GM용)
+    pub misleading: bool,
+}
+
+/// 크리처에 대해 떠올린다 (Recall Knowledge, 1 액션)
+///
+/// 특성에 맞는 기술 중 보너스가 가장 높은 기술로 판정한다.
+/// `known`에 있는 종류의 사실은 다시 알려주지 않는다.
+pub fn recall_knowledge(
+    character: &Character,
+    creature: &Creature,
+    known: &[CreatureFact],
+    rng: &mut impl Rng,
+) -> Result<RecallKnowledge, RulesError> {
+    let skill = recall_skills(creature)
+        .into_iter()
+        .max_by_key(|s| character.skill_bonus(*s))
+        .ok_or_else(|| RulesError::NoRecallSkill(creature.name.clone()))?;
+    let dc = recall_dc(creature)?;
+
+    let outcome = Check::new(character.skill_bonus(skill), dc).roll(rng);
+    let (facts, misleading) = facts_for(outcome.degree, creature, known);
+
+    Ok(RecallKnowledge {
+        skill,
+        dc,
+        outcome,
+        facts,
+        misleading,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    use super::DegreeOfSuccess::*;
+    use super::*;
+    use crate::bestiary::bestiary;
+    use crate::character::{Attribute, Defense, Proficiency};
+    use crate::check::Rarity;
+
+    fn find(name: &str) -> Creature {
+        bestiary().into_iter().find(|c| c.name == name).unwrap()
+    }
+
+    fn wizard() -> Character {
+        Character::new(
+            6,
+            Attribute {
+                strength: 10,
+                dexterity: 14,
+                constitution: 12,
+                intelligence: 18,
+                wisdom: 12,
+                charisma: 10,
+            },
+            Defense {
+                armor_item: 0,
+                armor_proficiency: Proficiency::Trained,
+            },
+            Proficiency::Trained,
+        )
+        .with_skill(Skill::Arcana, Proficiency::Trained)
+        .with_skill(Skill::Society, Proficiency::Trained)
+        .with_skill(Skill::Nature, Proficiency::Trained)
+    }
+
+    #[test]
+    fn test_recall_skills() {
+        assert_eq!(recall_skills(&find("Wolf")), vec![Skill::Nature]);
+        assert_eq!(
+            recall_skills(&find("Skeleton Guard")),
+            vec![Skill::Religion]
+        );
+        assert_eq!(
+            recall_skills(&find("Gelatinous Cube")),
+            vec![Skill::Occultism]
+        );
+        assert_eq!(recall_skills(&find("Goblin Warrior")), vec![Skill::Society]);
+    }
+
+    #[test]
+    fn test_recall_dc() {
+        assert_eq!(recall_dc(&find("Wolf")), Ok(15));
+        assert_eq!(recall_dc(&find("Goblin Warrior")), Ok(13));
+        assert_eq!(
+            recall_dc(&find("Owlbear").with_rarity(Rarity::Rare)),
+            Ok(24)
+        );
+    }
+
+    #[test]
+    fn test_facts_by_degree() {
+        let owlbear = find("Owlbear");
+
+        let (facts, misleading) = facts_for(CriticalSuccess, &owlbear, &[]);
+        assert_eq!(
+            facts,
+            vec![
+                CreatureFact::LowestSave(SaveType::Reflex, 7),
+                CreatureFact::HighestSave(SaveType::Fortitude, 14),
+            ]
+        );
+        assert!(!misleading);
+
+        // 이미 아는 종류는 건너뜀
+        let (facts, _) = facts_for(Success, &owlbear, &facts);
+        assert_eq!(facts, vec![CreatureFact::ArmorClass(21)]);
+
+        assert_eq!(facts_for(Failure, &owlbear, &[]), (Vec::new(), false));
+
+        // 가장 강한 내성을 가장 약하다고 믿게 된다
+        assert_eq!(
+            facts_for(CriticalFailure, &owlbear, &[]),
+            (
+                vec![CreatureFact::LowestSave(SaveType::Fortitude, 14)],
+                true
+            )
+        );
+    }
+
+    #[test]
+    fn test_recall_knowledge() {
+        let wizard = wizard();
+        let wolf = find("Wolf");
+        let mut rng = StdRng::seed_from_u64(9);
+
+        for _ in 0..50 {
+            let recall = recall_knowledge(&wizard, &wolf, &[], &mut rng).unwrap();
+            // Nature: WIS +1, Trained 3
+            assert_eq!(recall.skill, Skill::Nature);
+            assert_eq!(recall.dc, 15);
+            assert_eq!(recall.outcome.total, recall.outcome.natural as i32 + 4);
+            assert_eq!(
+                (recall.facts, recall.misleading),
+                facts_for(recall.outcome.degree, &wolf, &[])
+            );
+        }
+
+        let mut mindless = find("Skeleton Guard");
+        mindless.traits = vec![CreatureTrait::Mindless];
+        assert_eq!(
+            recall_knowledge(&wizard, &mindless, &[], &mut rng),
+            Err(RulesError::NoRecallSkill("Skeleton Guard".to_string()))
+        );
+    }
+}
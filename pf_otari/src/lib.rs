@@ -1,3 +1,109 @@
-mod character;
-mod combat;
-mod dice;
+//! PF2e(Pathfinder 2판) 규칙 엔진
+//!
+//! 판정, 전투 턴, 캐릭터 능력치 계산과 이를 감싼 Bevy 플러그인을 제공한다.
+//! 자주 쓰는 타입은 [`prelude`]로 한 번에 가져올 수 있다.
+//!
+//! ```
+//! use pf_otari::prelude::*;
+//! use rand::SeedableRng;
+//! use rand::rngs::StdRng;
+//!
+//! let mut rng = StdRng::seed_from_u64(1);
+//! let mut turn = CombatTurn::new();
+//! let longsword = Weapon {
+//!     name: "Longsword".to_string(),
+//!     damage: Dice::new(1, 8),
+//!     agile: false,
+//! };
+//!
+//! turn.spend_action(1).unwrap();
+//! let degree = strike(roll_d20(&mut rng), 7, 18).unwrap();
+//! let damage = calc_damage(degree, longsword.damage.roll(&mut rng), 3);
+//!
+//! assert!(damage >= 0);
+//! assert_eq!(turn.remaining_actions(), 2);
+//! ```
+
+pub mod bestiary;
+pub mod building;
+pub mod character;
+pub mod check;
+pub mod class;
+pub mod combat;
+pub mod companion;
+pub mod condition;
+pub mod dice;
+pub mod downtime;
+pub mod duration;
+pub mod encounter;
+pub mod error;
+pub mod exploration;
+pub mod feat;
+pub mod grid;
+pub mod hazard;
+pub mod inventory;
+pub mod knowledge;
+pub mod locale;
+pub mod maneuver;
+pub mod outcome;
+pub mod plugin;
+pub mod probability;
+pub mod render;
+pub mod senses;
+pub mod simulation;
+pub mod table;
+pub mod tactics;
+pub mod treasure;
+
+/// 자주 쓰는 규칙 타입과 함수
+pub mod prelude {
+    pub use crate::bestiary::{Creature, CreatureStrike, CreatureTrait, bestiary};
+    pub use crate::building::{CreatureBlueprint, Tier};
+    pub use crate::character::{
+        Ability, Attribute, Character, Defense, Proficiency, Skill, ability_modifier,
+        proficiency_bonus,
+    };
+    pub use crate::check::{
+        Check, CheckKind, CheckOutcome, DcAdjustment, HeroPoints, Rarity, RollModifier, SaveType,
+        level_dc, simple_dc,
+    };
+    pub use crate::class::{Class, RetributiveStrike, rage, retributive_strike, sneak_attack};
+    pub use crate::combat::{
+        ACTIONS_PER_TURN, ActionKind, ActionSlot, CombatTurn, TurnStart, Weapon, calc_damage,
+        multiple_attack_penalty, strike, strike_character,
+    };
+    pub use crate::companion::{Minion, MinionKind};
+    pub use crate::condition::Condition;
+    pub use crate::dice::{DegreeOfSuccess, Dice, check_degree, roll_d20};
+    pub use crate::downtime::{FocusPool, Immunity, TreatWounds, earn_income};
+    pub use crate::duration::{
+        ActiveEffect, BonusType, EffectDuration, EffectRegistry, Modifier, Statistic,
+    };
+    pub use crate::encounter::{EncounterBuilder, ThreatLevel};
+    pub use crate::error::RulesError;
+    pub use crate::exploration::{EncounterStart, ExplorationActivity, encounter_start};
+    pub use crate::feat::{
+        Feat, FeatEffect, FeatKind, FeatSlot, FeatTrait, Prerequisite, StrikeActivity, feat_slots,
+        find_feat,
+    };
+    pub use crate::grid::GridPos;
+    pub use crate::hazard::{
+        Disable, DisableResult, Hazard, HazardAttack, HazardHit, HazardState, Routine,
+    };
+    pub use crate::inventory::{Armor, Bulk, Inventory, Item, ItemKind, ItemState};
+    pub use crate::knowledge::{CreatureFact, RecallKnowledge, recall_knowledge};
+    pub use crate::locale::{Locale, Localize};
+    pub use crate::maneuver::{DemoralizeImmunity, Maneuver, ManeuverEffects, ManeuverResult};
+    pub use crate::outcome::{DamageMultiplier, DegreeAdjustment, Effect, OutcomeTable};
+    pub use crate::senses::{
+        Awareness, DetectionState, LightLevel, Lighting, Observer, Vision, flat_check,
+    };
+    pub use crate::simulation::{Combatant, Side, Simulation, Tactics, Targeting};
+    pub use crate::table::{Fighter, StatLine, StrikeReport, TableFight, parse_roster};
+    pub use crate::tactics::{
+        Aggressive, CombatantView, Personality, ShieldState, Situation, TacticalAction,
+    };
+    pub use crate::treasure::{
+        Coins, PartyTreasure, TreasureBudget, TreasureReport, treasure_by_level,
+    };
+}
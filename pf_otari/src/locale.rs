@@ -0,0 +1,1511 @@
+use std::fmt;
+
+use crate::building::Tier;
+use crate::character::{Ability, Proficiency, Skill};
+use crate::check::SaveType;
+use crate::class::Class;
+use crate::combat::ActionKind;
+use crate::condition::Condition;
+use crate::dice::DegreeOfSuccess;
+use crate::error::RulesError;
+use crate::feat::{FeatKind, Prerequisite};
+use crate::maneuver::ManeuverEffects;
+use crate::outcome::Effect;
+use crate::senses::DetectionState;
+use crate::simulation::{Side, SimulationReport};
+
+/// 규칙 엔진이 내는 문구의 언어
+#[derive(Debug, Default, PartialEq, Eq, Copy, Clone, Hash)]
+pub enum Locale {
+    #[default]
+    Korean,
+    English,
+}
+
+impl Locale {
+    pub const ALL: [Locale; 2] = [Locale::Korean, Locale::English];
+
+    /// `(키, 문구)` 목록, 문구의 `{이름}` 자리는 [`Locale::format`]이 채운다
+    pub fn catalog(self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Locale::Korean => KOREAN,
+            Locale::English => ENGLISH,
+        }
+    }
+
+    pub fn has(self, key: &str) -> bool {
+        self.catalog().iter().any(|(k, _)| *k == key)
+    }
+
+    /// 키에 맞는 문구, 카탈로그에 없으면 키를 그대로 돌려준다
+    pub fn text(self, key: &str) -> &str {
+        self.catalog()
+            .iter()
+            .find(|(k, _)| *k == key)
+            .map_or(key, |(_, text)| text)
+    }
+
+    /// 문구의 `{이름}` 자리를 `args`로 채운다
+    ///
+    /// ```
+    /// use pf_otari::locale::Locale;
+    ///
+    /// let line = Locale::English.format("log.rage", &[("name", &"Amiri"), ("temp_hp", &4)]);
+    /// assert_eq!(line, "Amiri: Rage, 4 temporary HP");
+    /// ```
+    pub fn format(self, key: &str, args: &[(&str, &dyn fmt::Display)]) -> String {
+        args.iter()
+            .fold(self.text(key).to_string(), |text, (name, value)| {
+                text.replace(&format!("{{{name}}}"), &value.to_string())
+            })
+    }
+}
+
+/// 선택한 언어로 옮길 수 있는 규칙 출력
+pub trait Localize {
+    fn localize(&self, locale: Locale) -> String;
+}
+
+impl Localize for DegreeOfSuccess {
+    fn localize(&self, locale: Locale) -> String {
+        let key = match self {
+            DegreeOfSuccess::CriticalSuccess => "degree.critical_success",
+            DegreeOfSuccess::Success => "degree.success",
+            DegreeOfSuccess::Failure => "degree.failure",
+            DegreeOfSuccess::CriticalFailure => "degree.critical_failure",
+        };
+        locale.text(key).to_string()
+    }
+}
+
+impl Condition {
+    fn key(&self) -> &'static str {
+        match self {
+            Condition::Blinded => "condition.blinded",
+            Condition::Clumsy(_) => "condition.clumsy",
+            Condition::Dazzled => "condition.dazzled",
+            Condition::Deafened => "condition.deafened",
+            Condition::Drained(_) => "condition.drained",
+            Condition::Dying(_) => "condition.dying",
+            Condition::Enfeebled(_) => "condition.enfeebled",
+            Condition::Fatigued => "condition.fatigued",
+            Condition::Frightened(_) => "condition.frightened",
+            Condition::Grabbed => "condition.grabbed",
+            Condition::Immobilized => "condition.immobilized",
+            Condition::OffGuard => "condition.off_guard",
+            Condition::Prone => "condition.prone",
+            Condition::Quickened => "condition.quickened",
+            Condition::Restrained => "condition.restrained",
+            Condition::Sickened(_) => "condition.sickened",
+            Condition::Slowed(_) => "condition.slowed",
+            Condition::Stunned(_) => "condition.stunned",
+            Condition::Stupefied(_) => "condition.stupefied",
+            Condition::Unconscious => "condition.unconscious",
+            Condition::Wounded(_) => "condition.wounded",
+        }
+    }
+}
+
+/// 값이 있는 상태는 값을 붙인다 (예: 공포 2)
+impl Localize for Condition {
+    fn localize(&self, locale: Locale) -> String {
+        let name = locale.text(self.key());
+        match self.value() {
+            Some(value) => format!("{name} {value}"),
+            None => name.to_string(),
+        }
+    }
+}
+
+impl Ability {
+    fn key(&self) -> &'static str {
+        match self {
+            Ability::Strength => "ability.strength",
+            Ability::Dexterity => "ability.dexterity",
+            Ability::Constitution => "ability.constitution",
+            Ability::Intelligence => "ability.intelligence",
+            Ability::Wisdom => "ability.wisdom",
+            Ability::Charisma => "ability.charisma",
+        }
+    }
+}
+
+impl Localize for Ability {
+    fn localize(&self, locale: Locale) -> String {
+        locale.text(self.key()).to_string()
+    }
+}
+
+impl Skill {
+    fn key(&self) -> &'static str {
+        match self {
+            Skill::Acrobatics => "skill.acrobatics",
+            Skill::Arcana => "skill.arcana",
+            Skill::Athletics => "skill.athletics",
+            Skill::Crafting => "skill.crafting",
+            Skill::Deception => "skill.deception",
+            Skill::Diplomacy => "skill.diplomacy",
+            Skill::Intimidation => "skill.intimidation",
+            Skill::Medicine => "skill.medicine",
+            Skill::Nature => "skill.nature",
+            Skill::Occultism => "skill.occultism",
+            Skill::Performance => "skill.performance",
+            Skill::Religion => "skill.religion",
+            Skill::Society => "skill.society",
+            Skill::Stealth => "skill.stealth",
+            Skill::Survival => "skill.survival",
+            Skill::Thievery => "skill.thievery",
+        }
+    }
+}
+
+impl Localize for Skill {
+    fn localize(&self, locale: Locale) -> String {
+        locale.text(self.key()).to_string()
+    }
+}
+
+impl Proficiency {
+    fn key(&self) -> &'static str {
+        match self {
+            Proficiency::Untrained => "proficiency.untrained",
+            Proficiency::Trained => "proficiency.trained",
+            Proficiency::Expert => "proficiency.expert",
+            Proficiency::Master => "proficiency.master",
+            Proficiency::Legendary => "proficiency.legendary",
+        }
+    }
+}
+
+impl Localize for Proficiency {
+    fn localize(&self, locale: Locale) -> String {
+        locale.text(self.key()).to_string()
+    }
+}
+
+impl SaveType {
+    fn key(&self) -> &'static str {
+        match self {
+            SaveType::Fortitude => "save.fortitude",
+            SaveType::Reflex => "save.reflex",
+            SaveType::Will => "save.will",
+        }
+    }
+}
+
+impl Localize for SaveType {
+    fn localize(&self, locale: Locale) -> String {
+        locale.text(self.key()).to_string()
+    }
+}
+
+impl Class {
+    fn key(&self) -> &'static str {
+        match self {
+            Class::Fighter => "class.fighter",
+            Class::Rogue => "class.rogue",
+            Class::Barbarian => "class.barbarian",
+            Class::Champion => "class.champion",
+        }
+    }
+}
+
+impl Localize for Class {
+    fn localize(&self, locale: Locale) -> String {
+        locale.text(self.key()).to_string()
+    }
+}
+
+impl FeatKind {
+    fn key(&self) -> &'static str {
+        match self {
+            FeatKind::Ancestry => "feat_kind.ancestry",
+            FeatKind::Class => "feat_kind.class",
+            FeatKind::Skill => "feat_kind.skill",
+            FeatKind::General => "feat_kind.general",
+        }
+    }
+}
+
+impl Localize for FeatKind {
+    fn localize(&self, locale: Locale) -> String {
+        locale.text(self.key()).to_string()
+    }
+}
+
+impl Tier {
+    fn key(&self) -> &'static str {
+        match self {
+            Tier::Extreme => "tier.extreme",
+            Tier::High => "tier.high",
+            Tier::Moderate => "tier.moderate",
+            Tier::Low => "tier.low",
+            Tier::Terrible => "tier.terrible",
+        }
+    }
+}
+
+impl Localize for Tier {
+    fn localize(&self, locale: Locale) -> String {
+        locale.text(self.key()).to_string()
+    }
+}
+
+impl ActionKind {
+    fn key(&self) -> &'static str {
+        match self {
+            ActionKind::Strike => "action.strike",
+            ActionKind::Stride => "action.stride",
+            ActionKind::Step => "action.step",
+            ActionKind::Interact => "action.interact",
+            ActionKind::RaiseShield => "action.raise_shield",
+            ActionKind::Command => "action.command",
+            ActionKind::Skill => "action.skill",
+        }
+    }
+}
+
+impl Localize for ActionKind {
+    fn localize(&self, locale: Locale) -> String {
+        locale.text(self.key()).to_string()
+    }
+}
+
+/// 선행 조건 이름 (예: 운동 전문가, 민첩 14)
+impl Localize for Prerequisite {
+    fn localize(&self, locale: Locale) -> String {
+        let rank = |name: String, rank: &Proficiency| {
+            locale.format(
+                "prerequisite.rank",
+                &[("name", &name), ("rank", &rank.localize(locale))],
+            )
+        };
+        match self {
+            Prerequisite::Skill(skill, required) => rank(skill.localize(locale), required),
+            Prerequisite::Perception(required) => {
+                rank(locale.text("prerequisite.perception").to_string(), required)
+            }
+            Prerequisite::Save(save, required) => rank(save.localize(locale), required),
+            Prerequisite::Weapon(required) => {
+                rank(locale.text("prerequisite.weapon").to_string(), required)
+            }
+            Prerequisite::Ability(ability, score) => locale.format(
+                "prerequisite.rank",
+                &[("name", &ability.localize(locale)), ("rank", score)],
+            ),
+            Prerequisite::Feat(name) => name.clone(),
+            Prerequisite::Any(options) => options
+                .iter()
+                .map(|p| p.localize(locale))
+                .collect::<Vec<_>>()
+                .join(locale.text("prerequisite.or")),
+        }
+    }
+}
+
+impl Localize for DetectionState {
+    fn localize(&self, locale: Locale) -> String {
+        let key = match self {
+            DetectionState::Observed => "detection.observed",
+            DetectionState::Concealed => "detection.concealed",
+            DetectionState::Hidden => "detection.hidden",
+            DetectionState::Undetected => "detection.undetected",
+            DetectionState::Unnoticed => "detection.unnoticed",
+        };
+        locale.text(key).to_string()
+    }
+}
+
+impl Localize for Side {
+    fn localize(&self, locale: Locale) -> String {
+        let key = match self {
+            Side::Party => "side.party",
+            Side::Creatures => "side.creatures",
+        };
+        locale.text(key).to_string()
+    }
+}
+
+/// 전투 횟수, 승률, 종료 라운드와 참가자별 표
+impl Localize for SimulationReport {
+    fn localize(&self, locale: Locale) -> String {
+        let mut lines = vec![
+            locale.format("report.iterations", &[("count", &self.iterations)]),
+            locale.format(
+                "report.win_rate",
+                &[
+                    ("rate", &format!("{:.1}", self.win_rate() * 100.0)),
+                    ("wins", &self.party_wins),
+                    ("losses", &self.creature_wins),
+                    ("draws", &self.draws),
+                ],
+            ),
+        ];
+        if let (Some(min), Some(max)) = (self.min_rounds, self.max_rounds) {
+            lines.push(locale.format(
+                "report.rounds",
+                &[
+                    ("mean", &format!("{:.2}", self.mean_rounds())),
+                    ("min", &min),
+                    ("max", &max),
+                ],
+            ));
+        }
+        lines.push(locale.text("report.header").to_string());
+        for (i, c) in self.combatants.iter().enumerate() {
+            lines.push(format!(
+                "{} | {} | {:.2} | {:.2} | {:.1}%",
+                c.name,
+                c.side.localize(locale),
+                self.mean_damage_dealt(i),
+                self.mean_damage_taken(i),
+                self.knockout_rate(i) * 100.0
+            ));
+        }
+        lines.iter().map(|line| format!("{line}\n")).collect()
+    }
+}
+
+impl Localize for Effect {
+    fn localize(&self, locale: Locale) -> String {
+        locale.text(&self.text).to_string()
+    }
+}
+
+impl Localize for ManeuverEffects {
+    fn localize(&self, locale: Locale) -> String {
+        locale.text(&self.key).to_string()
+    }
+}
+
+impl Localize for RulesError {
+    fn localize(&self, locale: Locale) -> String {
+        let (key, args): (&str, Vec<(&str, String)>) = match self {
+            RulesError::InsufficientActions { cost, remaining } => (
+                "error.insufficient_actions",
+                vec![
+                    ("cost", cost.to_string()),
+                    ("remaining", remaining.to_string()),
+                ],
+            ),
+            RulesError::ReactionUsed => ("error.reaction_used", vec![]),
+            RulesError::InvalidRoll(roll) => {
+                ("error.invalid_roll", vec![("roll", roll.to_string())])
+            }
+            RulesError::NegativeAmount(amount) => (
+                "error.negative_amount",
+                vec![("amount", amount.to_string())],
+            ),
+            RulesError::TargetUnconscious => ("error.target_unconscious", vec![]),
+            RulesError::TargetDead => ("error.target_dead", vec![]),
+            RulesError::InvalidLevel(level) => {
+                ("error.invalid_level", vec![("level", level.to_string())])
+            }
+            RulesError::InvalidPartySize(size) => {
+                ("error.invalid_party_size", vec![("size", size.to_string())])
+            }
+            RulesError::NoHeroPoints => ("error.no_hero_points", vec![]),
+            RulesError::FortuneAlreadyApplied => ("error.fortune_already_applied", vec![]),
+            RulesError::CreatureTooPowerful(name) => {
+                ("error.creature_too_powerful", vec![("name", name.clone())])
+            }
+            RulesError::OverBudget { xp, budget } => (
+                "error.over_budget",
+                vec![("xp", xp.to_string()), ("budget", budget.to_string())],
+            ),
+            RulesError::NotYourTurn => ("error.not_your_turn", vec![]),
+            RulesError::OutOfReach => ("error.out_of_reach", vec![]),
+            RulesError::TooFar { distance, speed } => (
+                "error.too_far",
+                vec![
+                    ("distance", distance.to_string()),
+                    ("speed", speed.to_string()),
+                ],
+            ),
+            RulesError::SquareOccupied => ("error.square_occupied", vec![]),
+            RulesError::SelfTarget => ("error.self_target", vec![]),
+            RulesError::ProficiencyTooLow { skill, required } => (
+                "error.proficiency_too_low",
+                vec![
+                    ("skill", skill.localize(locale)),
+                    ("required", required.localize(locale)),
+                ],
+            ),
+            RulesError::Immune { minutes_left } => (
+                "error.immune",
+                vec![("minutes_left", minutes_left.to_string())],
+            ),
+            RulesError::HandsFull { needed, free } => (
+                "error.hands_full",
+                vec![("needed", needed.to_string()), ("free", free.to_string())],
+            ),
+            RulesError::OverMaxBulk { bulk, max } => (
+                "error.over_max_bulk",
+                vec![("bulk", bulk.to_string()), ("max", max.to_string())],
+            ),
+            RulesError::NoSuchItem(index) => {
+                ("error.no_such_item", vec![("index", index.to_string())])
+            }
+            RulesError::NoWeaponHeld => ("error.no_weapon_held", vec![]),
+            RulesError::NoShield => ("error.no_shield", vec![]),
+            RulesError::NoRecallSkill(name) => {
+                ("error.no_recall_skill", vec![("name", name.clone())])
+            }
+            RulesError::NoFocusPoints => ("error.no_focus_points", vec![]),
+            RulesError::FocusPoolFull => ("error.focus_pool_full", vec![]),
+            RulesError::WrongClass(class) => {
+                ("error.wrong_class", vec![("class", class.localize(locale))])
+            }
+            RulesError::NoFeatSlot { kind, level } => (
+                "error.no_feat_slot",
+                vec![
+                    ("kind", kind.localize(locale)),
+                    ("level", level.to_string()),
+                ],
+            ),
+            RulesError::FeatLevelTooHigh { feat, slot } => (
+                "error.feat_level_too_high",
+                vec![("feat", feat.to_string()), ("slot", slot.to_string())],
+            ),
+            RulesError::FeatAlreadyTaken(name) => {
+                ("error.feat_already_taken", vec![("name", name.clone())])
+            }
+            RulesError::PrerequisiteNotMet(prerequisite) => (
+                "error.prerequisite_not_met",
+                vec![("prerequisite", prerequisite.localize(locale))],
+            ),
+            RulesError::InvalidDice(text) => ("error.invalid_dice", vec![("text", text.clone())]),
+            RulesError::InvalidRoster { line, text } => (
+                "error.invalid_roster",
+                vec![("line", line.to_string()), ("text", text.clone())],
+            ),
+            RulesError::NoSuchTier { stat, tier } => (
+                "error.no_such_tier",
+                vec![("stat", stat.clone()), ("tier", tier.localize(locale))],
+            ),
+            RulesError::NoDisableSkill(skill) => (
+                "error.no_disable_skill",
+                vec![("skill", skill.localize(locale))],
+            ),
+            RulesError::HazardInactive(name) => {
+                ("error.hazard_inactive", vec![("name", name.clone())])
+            }
+            RulesError::InvalidPrice(text) => ("error.invalid_price", vec![("text", text.clone())]),
+            RulesError::NotEnoughCoins { price, coins } => (
+                "error.not_enough_coins",
+                vec![("price", price.to_string()), ("coins", coins.to_string())],
+            ),
+            RulesError::NotYourMinion => ("error.not_your_minion", vec![]),
+            RulesError::AlreadyCommanded => ("error.already_commanded", vec![]),
+            RulesError::NoQuickenedAction => ("error.no_quickened_action", vec![]),
+            RulesError::QuickenedNotAllowed(action) => (
+                "error.quickened_not_allowed",
+                vec![("action", action.localize(locale))],
+            ),
+            RulesError::NoCoverOrConcealment => ("error.no_cover_or_concealment", vec![]),
+            RulesError::NotHidden => ("error.not_hidden", vec![]),
+            RulesError::FlourishUsed => ("error.flourish_used", vec![]),
+        };
+        let args: Vec<(&str, &dyn fmt::Display)> = args
+            .iter()
+            .map(|(name, value)| (*name, value as &dyn fmt::Display))
+            .collect();
+        locale.format(key, &args)
+    }
+}
+
+const KOREAN: &[(&str, &str)] = &[
+    ("degree.critical_success", "치명적 성공"),
+    ("degree.success", "성공"),
+    ("degree.failure", "실패"),
+    ("degree.critical_failure", "치명적 실패"),
+    ("condition.blinded", "실명"),
+    ("condition.clumsy", "서투름"),
+    ("condition.dazzled", "눈부심"),
+    ("condition.deafened", "청각 상실"),
+    ("condition.drained", "소진"),
+    ("condition.dying", "빈사"),
+    ("condition.enfeebled", "쇠약"),
+    ("condition.fatigued", "피로"),
+    ("condition.frightened", "공포"),
+    ("condition.grabbed", "붙잡힘"),
+    ("condition.immobilized", "이동 불가"),
+    ("condition.off_guard", "무방비"),
+    ("condition.prone", "넘어짐"),
+    ("condition.quickened", "가속"),
+    ("condition.restrained", "구속"),
+    ("condition.sickened", "메스꺼움"),
+    ("condition.slowed", "감속"),
+    ("condition.stunned", "멍함"),
+    ("condition.stupefied", "혼미"),
+    ("condition.unconscious", "의식 없음"),
+    ("condition.wounded", "부상"),
+    ("side.party", "파티"),
+    ("side.creatures", "크리처"),
+    ("outcome.no_damage", "피해 없음"),
+    ("outcome.half_damage", "절반 피해"),
+    ("outcome.damage", "피해"),
+    ("outcome.double_damage", "2배 피해"),
+    ("outcome.critical_hit", "치명타"),
+    ("outcome.hit", "명중"),
+    ("outcome.miss", "빗나감"),
+    ("outcome.unaffected", "영향 없음"),
+    ("outcome.afflicted", "상태 이상에 걸림"),
+    ("report.iterations", "전투 횟수: {count}"),
+    (
+        "report.win_rate",
+        "파티 승률: {rate}% (승 {wins} / 패 {losses} / 무 {draws})",
+    ),
+    (
+        "report.rounds",
+        "종료 라운드: 평균 {mean} (최소 {min} / 최대 {max})",
+    ),
+    (
+        "report.header",
+        "참가자 | 진영 | 가한 피해 | 받은 피해 | 쓰러짐",
+    ),
+    ("ability.strength", "근력"),
+    ("ability.dexterity", "민첩"),
+    ("ability.constitution", "건강"),
+    ("ability.intelligence", "지능"),
+    ("ability.wisdom", "지혜"),
+    ("ability.charisma", "매력"),
+    ("skill.acrobatics", "곡예"),
+    ("skill.arcana", "비전"),
+    ("skill.athletics", "운동"),
+    ("skill.crafting", "제작"),
+    ("skill.deception", "기만"),
+    ("skill.diplomacy", "외교"),
+    ("skill.intimidation", "위협"),
+    ("skill.medicine", "의술"),
+    ("skill.nature", "자연"),
+    ("skill.occultism", "신비"),
+    ("skill.performance", "공연"),
+    ("skill.religion", "종교"),
+    ("skill.society", "사회"),
+    ("skill.stealth", "은신"),
+    ("skill.survival", "생존"),
+    ("skill.thievery", "도둑질"),
+    ("proficiency.untrained", "미숙련"),
+    ("proficiency.trained", "숙련"),
+    ("proficiency.expert", "전문가"),
+    ("proficiency.master", "달인"),
+    ("proficiency.legendary", "전설"),
+    ("save.fortitude", "인내"),
+    ("save.reflex", "반사"),
+    ("save.will", "의지"),
+    ("class.fighter", "파이터"),
+    ("class.rogue", "로그"),
+    ("class.barbarian", "바바리안"),
+    ("class.champion", "챔피언"),
+    ("feat_kind.ancestry", "혈통"),
+    ("feat_kind.class", "클래스"),
+    ("feat_kind.skill", "기술"),
+    ("feat_kind.general", "일반"),
+    ("tier.extreme", "극단"),
+    ("tier.high", "높음"),
+    ("tier.moderate", "보통"),
+    ("tier.low", "낮음"),
+    ("tier.terrible", "매우 낮음"),
+    ("action.strike", "Strike"),
+    ("action.stride", "Stride"),
+    ("action.step", "Step"),
+    ("action.interact", "Interact"),
+    ("action.raise_shield", "Raise a Shield"),
+    ("action.command", "Command"),
+    ("action.skill", "기술 액션"),
+    ("action.hide", "Hide"),
+    ("action.sneak", "Sneak"),
+    ("action.seek", "Seek"),
+    ("prerequisite.rank", "{name} {rank}"),
+    ("prerequisite.perception", "지각"),
+    ("prerequisite.weapon", "무기"),
+    ("prerequisite.or", " 또는 "),
+    ("maneuver.no_effect", "효과 없음"),
+    ("maneuver.attacker_prone", "시도한 쪽이 넘어짐"),
+    ("maneuver.trip.critical_success", "넘어지고 1d6 타격 피해"),
+    ("maneuver.trip.success", "넘어짐"),
+    ("maneuver.grapple.critical_success", "제압"),
+    ("maneuver.grapple.success", "붙잡힘"),
+    ("maneuver.grapple.failure", "붙잡고 있었다면 풀려남"),
+    (
+        "maneuver.grapple.critical_failure",
+        "풀려나고 시도한 쪽이 넘어짐",
+    ),
+    ("maneuver.shove.critical_success", "10피트 밀려남"),
+    ("maneuver.shove.success", "5피트 밀려남"),
+    ("maneuver.disarm.critical_success", "든 물건을 떨어뜨림"),
+    (
+        "maneuver.disarm.success",
+        "다음 턴 시작까지 그 물건으로 하는 공격 -2",
+    ),
+    (
+        "maneuver.disarm.critical_failure",
+        "시도한 쪽이 다음 턴 시작까지 무방비",
+    ),
+    ("maneuver.demoralize.critical_success", "공포 2"),
+    ("maneuver.demoralize.success", "공포 1"),
+    ("detection.observed", "드러남"),
+    ("detection.concealed", "흐릿함"),
+    ("detection.hidden", "숨음"),
+    ("detection.undetected", "감지되지 않음"),
+    ("detection.unnoticed", "눈치채지 못함"),
+    (
+        "log.detection",
+        "{actor} → {other}: {action} ({roll}{bonus}) {degree}, {state}",
+    ),
+    ("hud.finished", "전투 종료"),
+    (
+        "hud.status",
+        "라운드 {round} | {name} | 액션 {actions} | 리액션 {reaction} | [A] Strike [S] Stride [Space] 턴 종료",
+    ),
+    ("log.initiative", "{name} 우선권 {total}"),
+    ("log.error", "{name}: {error}"),
+    (
+        "log.anthem",
+        "{name}: Courageous Anthem, 아군 {count}명 명중·피해 +1",
+    ),
+    ("log.raise_shield", "{name}: Raise a Shield, AC +{bonus}"),
+    ("log.rage", "{name}: Rage, 임시 HP {temp_hp}"),
+    ("log.command", "{name}: {minion}에게 명령, {actions} 액션"),
+    ("log.actions_lost", "{name}: 액션 {lost}개를 잃음"),
+    (
+        "log.strike",
+        "{attacker} → {target}: {weapon} ({roll}{bonus}) {degree}, 피해 {damage}",
+    ),
+    ("log.sneak_attack", " (Sneak Attack)"),
+    (
+        "log.maneuver",
+        "{attacker} → {target}: {maneuver} ({roll}{bonus}) {degree}, {effect}",
+    ),
+    ("log.pushed", "{name}: {feet}피트 밀려남"),
+    (
+        "log.flat_check_failed",
+        "{attacker} → {target}: 보이지 않아 빗나감 (단순 판정 DC {dc})",
+    ),
+    (
+        "log.retributive_strike",
+        "{name}: Retributive Strike, 저항 {resistance} (피해 {damage} → {taken})",
+    ),
+    ("log.knocked_out", "{name}: 쓰러짐, {dying}"),
+    ("log.effect_ended", "{name}: {effect} 종료"),
+    (
+        "log.recovery_check",
+        "{name}: 회복 판정 ({roll} vs DC {dc}) {degree}, {result}",
+    ),
+    ("log.stable", "안정"),
+    ("log.dead", "사망"),
+    ("log.defeated", "{side} 전멸"),
+    (
+        "log.hazard_attack",
+        "{hazard} → {target}: {degree}, 피해 {damage}",
+    ),
+    ("log.hazard_disabled", "{name}: {hazard} 해제"),
+    (
+        "log.hazard_progress",
+        "{name}: {hazard} 해제 진행, 성공 {remaining}번 더 필요",
+    ),
+    ("log.hazard_no_effect", "{name}: {hazard} 해제 실패"),
+    ("log.hazard_triggered", "{name}: {hazard} 작동"),
+    ("table.round", "{round}라운드"),
+    ("table.turn", "{round}라운드, {name} 차례"),
+    (
+        "table.menu",
+        "[s] Strike  [1-3] 다른 행동  [r] 리액션  [e] 턴 종료  [w] 저장  [q] 끝내기",
+    ),
+    (
+        "table.choose_target",
+        "Strike 대상 번호 (Enter 선택, Esc 취소)",
+    ),
+    (
+        "table.choose_reactor",
+        "리액션을 쓸 참가자 번호 (Enter 선택, Esc 취소)",
+    ),
+    ("table.act", "{name}: 행동 ({cost} 액션)"),
+    ("table.react", "{name}: 리액션"),
+    ("table.saved", "{path}에 저장했습니다"),
+    (
+        "error.insufficient_actions",
+        "액션이 부족합니다 (필요 {cost}, 남음 {remaining})",
+    ),
+    ("error.reaction_used", "리액션을 이미 사용했습니다"),
+    (
+        "error.invalid_roll",
+        "d20 굴림값은 1~20이어야 합니다 (입력 {roll})",
+    ),
+    (
+        "error.negative_amount",
+        "피해와 회복량은 음수일 수 없습니다 (입력 {amount})",
+    ),
+    ("error.target_unconscious", "대상이 의식을 잃었습니다"),
+    ("error.target_dead", "대상이 죽었습니다"),
+    (
+        "error.invalid_level",
+        "레벨이 범위를 벗어났습니다 (입력 {level})",
+    ),
+    (
+        "error.invalid_party_size",
+        "파티 인원은 1명 이상이어야 합니다 (입력 {size})",
+    ),
+    ("error.no_hero_points", "히어로 포인트가 없습니다"),
+    (
+        "error.fortune_already_applied",
+        "이미 행운 효과가 적용된 굴림입니다",
+    ),
+    (
+        "error.creature_too_powerful",
+        "{name}은(는) 파티보다 너무 강합니다",
+    ),
+    (
+        "error.over_budget",
+        "XP 예산을 초과했습니다 ({xp} / {budget})",
+    ),
+    ("error.not_your_turn", "자기 턴이 아닙니다"),
+    ("error.out_of_reach", "대상이 공격 간격 밖에 있습니다"),
+    (
+        "error.too_far",
+        "이동 거리가 속도보다 깁니다 ({distance}피트 / {speed}피트)",
+    ),
+    ("error.square_occupied", "다른 참가자가 있는 칸입니다"),
+    ("error.self_target", "자기 자신을 대상으로 할 수 없습니다"),
+    (
+        "error.proficiency_too_low",
+        "{skill} 숙련도가 {required} 이상이어야 합니다",
+    ),
+    (
+        "error.immune",
+        "아직 면역 상태입니다 ({minutes_left}분 남음)",
+    ),
+    (
+        "error.hands_full",
+        "손이 모자랍니다 (필요 {needed}, 남음 {free})",
+    ),
+    (
+        "error.over_max_bulk",
+        "너무 무거워 들 수 없습니다 (Bulk {bulk} / 최대 {max})",
+    ),
+    ("error.no_such_item", "{index}번 소지품이 없습니다"),
+    ("error.no_weapon_held", "손에 든 무기가 없습니다"),
+    ("error.no_shield", "든 방패가 없습니다"),
+    (
+        "error.no_recall_skill",
+        "{name}에 대해 떠올릴 수 있는 기술이 없습니다",
+    ),
+    ("error.no_focus_points", "집중 포인트가 없습니다"),
+    ("error.focus_pool_full", "집중 포인트가 이미 가득 찼습니다"),
+    ("error.wrong_class", "{class} 클래스 능력입니다"),
+    (
+        "error.no_feat_slot",
+        "{level}레벨 {kind} 재주 슬롯이 없습니다",
+    ),
+    (
+        "error.feat_level_too_high",
+        "{slot}레벨 슬롯에 {feat}레벨 재주를 고를 수 없습니다",
+    ),
+    ("error.feat_already_taken", "이미 고른 재주입니다: {name}"),
+    (
+        "error.prerequisite_not_met",
+        "선행 조건을 만족하지 않습니다: {prerequisite}",
+    ),
+    (
+        "error.invalid_dice",
+        "주사위 표기를 읽을 수 없습니다: {text}",
+    ),
+    (
+        "error.invalid_roster",
+        "{line}번째 줄을 읽을 수 없습니다: {text}",
+    ),
+    ("error.no_such_tier", "{stat} 표에는 {tier} 단계가 없습니다"),
+    (
+        "error.no_disable_skill",
+        "{skill}(으)로는 해제할 수 없습니다",
+    ),
+    (
+        "error.hazard_inactive",
+        "{name}은(는) 더 이상 작동하지 않습니다",
+    ),
+    (
+        "error.invalid_price",
+        "가격 표기를 읽을 수 없습니다: {text}",
+    ),
+    (
+        "error.not_enough_coins",
+        "돈이 모자랍니다 (가격 {price}, 가진 돈 {coins})",
+    ),
+    ("error.not_your_minion", "주인만 명령할 수 있습니다"),
+    (
+        "error.already_commanded",
+        "이번 턴에 이미 명령을 받았습니다",
+    ),
+    (
+        "error.no_quickened_action",
+        "Quickened 추가 액션이 없습니다",
+    ),
+    (
+        "error.quickened_not_allowed",
+        "Quickened 추가 액션으로 {action}을(를) 할 수 없습니다",
+    ),
+    (
+        "error.no_cover_or_concealment",
+        "엄폐나 은폐가 있어야 숨을 수 있습니다",
+    ),
+    ("error.not_hidden", "숨어 있어야 Sneak할 수 있습니다"),
+    (
+        "error.flourish_used",
+        "이번 턴에 이미 Flourish 액션을 했습니다",
+    ),
+];
+
+const ENGLISH: &[(&str, &str)] = &[
+    ("degree.critical_success", "Critical Success"),
+    ("degree.success", "Success"),
+    ("degree.failure", "Failure"),
+    ("degree.critical_failure", "Critical Failure"),
+    ("condition.blinded", "Blinded"),
+    ("condition.clumsy", "Clumsy"),
+    ("condition.dazzled", "Dazzled"),
+    ("condition.deafened", "Deafened"),
+    ("condition.drained", "Drained"),
+    ("condition.dying", "Dying"),
+    ("condition.enfeebled", "Enfeebled"),
+    ("condition.fatigued", "Fatigued"),
+    ("condition.frightened", "Frightened"),
+    ("condition.grabbed", "Grabbed"),
+    ("condition.immobilized", "Immobilized"),
+    ("condition.off_guard", "Off-Guard"),
+    ("condition.prone", "Prone"),
+    ("condition.quickened", "Quickened"),
+    ("condition.restrained", "Restrained"),
+    ("condition.sickened", "Sickened"),
+    ("condition.slowed", "Slowed"),
+    ("condition.stunned", "Stunned"),
+    ("condition.stupefied", "Stupefied"),
+    ("condition.unconscious", "Unconscious"),
+    ("condition.wounded", "Wounded"),
+    ("side.party", "Party"),
+    ("side.creatures", "Creatures"),
+    ("outcome.no_damage", "No damage"),
+    ("outcome.half_damage", "Half damage"),
+    ("outcome.damage", "Damage"),
+    ("outcome.double_damage", "Double damage"),
+    ("outcome.critical_hit", "Critical hit"),
+    ("outcome.hit", "Hit"),
+    ("outcome.miss", "Miss"),
+    ("outcome.unaffected", "Unaffected"),
+    ("outcome.afflicted", "Afflicted"),
+    ("report.iterations", "Fights: {count}"),
+    (
+        "report.win_rate",
+        "Party win rate: {rate}% (won {wins} / lost {losses} / drawn {draws})",
+    ),
+    (
+        "report.rounds",
+        "Final round: mean {mean} (min {min} / max {max})",
+    ),
+    (
+        "report.header",
+        "Combatant | Side | Damage dealt | Damage taken | Knocked out",
+    ),
+    ("ability.strength", "Strength"),
+    ("ability.dexterity", "Dexterity"),
+    ("ability.constitution", "Constitution"),
+    ("ability.intelligence", "Intelligence"),
+    ("ability.wisdom", "Wisdom"),
+    ("ability.charisma", "Charisma"),
+    ("skill.acrobatics", "Acrobatics"),
+    ("skill.arcana", "Arcana"),
+    ("skill.athletics", "Athletics"),
+    ("skill.crafting", "Crafting"),
+    ("skill.deception", "Deception"),
+    ("skill.diplomacy", "Diplomacy"),
+    ("skill.intimidation", "Intimidation"),
+    ("skill.medicine", "Medicine"),
+    ("skill.nature", "Nature"),
+    ("skill.occultism", "Occultism"),
+    ("skill.performance", "Performance"),
+    ("skill.religion", "Religion"),
+    ("skill.society", "Society"),
+    ("skill.stealth", "Stealth"),
+    ("skill.survival", "Survival"),
+    ("skill.thievery", "Thievery"),
+    ("proficiency.untrained", "Untrained"),
+    ("proficiency.trained", "Trained"),
+    ("proficiency.expert", "Expert"),
+    ("proficiency.master", "Master"),
+    ("proficiency.legendary", "Legendary"),
+    ("save.fortitude", "Fortitude"),
+    ("save.reflex", "Reflex"),
+    ("save.will", "Will"),
+    ("class.fighter", "Fighter"),
+    ("class.rogue", "Rogue"),
+    ("class.barbarian", "Barbarian"),
+    ("class.champion", "Champion"),
+    ("feat_kind.ancestry", "Ancestry"),
+    ("feat_kind.class", "Class"),
+    ("feat_kind.skill", "Skill"),
+    ("feat_kind.general", "General"),
+    ("tier.extreme", "Extreme"),
+    ("tier.high", "High"),
+    ("tier.moderate", "Moderate"),
+    ("tier.low", "Low"),
+    ("tier.terrible", "Terrible"),
+    ("action.strike", "Strike"),
+    ("action.stride", "Stride"),
+    ("action.step", "Step"),
+    ("action.interact", "Interact"),
+    ("action.raise_shield", "Raise a Shield"),
+    ("action.command", "Command"),
+    ("action.skill", "skill action"),
+    ("action.hide", "Hide"),
+    ("action.sneak", "Sneak"),
+    ("action.seek", "Seek"),
+    ("prerequisite.rank", "{name} {rank}"),
+    ("prerequisite.perception", "Perception"),
+    ("prerequisite.weapon", "Weapons"),
+    ("prerequisite.or", " or "),
+    ("maneuver.no_effect", "No effect"),
+    ("maneuver.attacker_prone", "The attacker falls prone"),
+    (
+        "maneuver.trip.critical_success",
+        "Falls prone and takes 1d6 bludgeoning damage",
+    ),
+    ("maneuver.trip.success", "Falls prone"),
+    ("maneuver.grapple.critical_success", "Restrained"),
+    ("maneuver.grapple.success", "Grabbed"),
+    ("maneuver.grapple.failure", "Released if it was grabbed"),
+    (
+        "maneuver.grapple.critical_failure",
+        "Released, and the attacker falls prone",
+    ),
+    ("maneuver.shove.critical_success", "Pushed 10 feet"),
+    ("maneuver.shove.success", "Pushed 5 feet"),
+    ("maneuver.disarm.critical_success", "Drops the held item"),
+    (
+        "maneuver.disarm.success",
+        "-2 to attacks with that item until the start of its next turn",
+    ),
+    (
+        "maneuver.disarm.critical_failure",
+        "The attacker is off-guard until the start of its next turn",
+    ),
+    ("maneuver.demoralize.critical_success", "Frightened 2"),
+    ("maneuver.demoralize.success", "Frightened 1"),
+    ("detection.observed", "Observed"),
+    ("detection.concealed", "Concealed"),
+    ("detection.hidden", "Hidden"),
+    ("detection.undetected", "Undetected"),
+    ("detection.unnoticed", "Unnoticed"),
+    (
+        "log.detection",
+        "{actor} → {other}: {action} ({roll}{bonus}) {degree}, {state}",
+    ),
+    ("hud.finished", "Encounter over"),
+    (
+        "hud.status",
+        "Round {round} | {name} | Actions {actions} | Reaction {reaction} | [A] Strike [S] Stride [Space] End turn",
+    ),
+    ("log.initiative", "{name} initiative {total}"),
+    ("log.error", "{name}: {error}"),
+    (
+        "log.anthem",
+        "{name}: Courageous Anthem, +1 to attack and damage for {count} allies",
+    ),
+    ("log.raise_shield", "{name}: Raise a Shield, AC +{bonus}"),
+    ("log.rage", "{name}: Rage, {temp_hp} temporary HP"),
+    ("log.command", "{name} commands {minion}, {actions} actions"),
+    ("log.actions_lost", "{name} loses {lost} actions"),
+    (
+        "log.strike",
+        "{attacker} → {target}: {weapon} ({roll}{bonus}) {degree}, {damage} damage",
+    ),
+    ("log.sneak_attack", " (Sneak Attack)"),
+    (
+        "log.maneuver",
+        "{attacker} → {target}: {maneuver} ({roll}{bonus}) {degree}, {effect}",
+    ),
+    ("log.pushed", "{name} is pushed {feet} feet"),
+    (
+        "log.flat_check_failed",
+        "{attacker} → {target}: missed, can't see the target (flat check DC {dc})",
+    ),
+    (
+        "log.retributive_strike",
+        "{name}: Retributive Strike, resistance {resistance} ({damage} damage → {taken})",
+    ),
+    ("log.knocked_out", "{name}: knocked out, {dying}"),
+    ("log.effect_ended", "{name}: {effect} ended"),
+    (
+        "log.recovery_check",
+        "{name}: recovery check ({roll} vs DC {dc}) {degree}, {result}",
+    ),
+    ("log.stable", "stable"),
+    ("log.dead", "dead"),
+    ("log.defeated", "{side} defeated"),
+    (
+        "log.hazard_attack",
+        "{hazard} → {target}: {degree}, {damage} damage",
+    ),
+    ("log.hazard_disabled", "{name}: {hazard} disabled"),
+    (
+        "log.hazard_progress",
+        "{name}: {hazard} partly disabled, {remaining} more successes needed",
+    ),
+    ("log.hazard_no_effect", "{name}: {hazard} not disabled"),
+    ("log.hazard_triggered", "{name}: {hazard} triggered"),
+    ("table.round", "Round {round}"),
+    ("table.turn", "Round {round}, {name}'s turn"),
+    (
+        "table.menu",
+        "[s] Strike  [1-3] other action  [r] reaction  [e] end turn  [w] save  [q] quit",
+    ),
+    (
+        "table.choose_target",
+        "Strike target number (Enter selects, Esc cancels)",
+    ),
+    (
+        "table.choose_reactor",
+        "Who uses a reaction? (Enter selects, Esc cancels)",
+    ),
+    ("table.act", "{name}: action ({cost} actions)"),
+    ("table.react", "{name}: reaction"),
+    ("table.saved", "Saved to {path}"),
+    (
+        "error.insufficient_actions",
+        "Not enough actions (needs {cost}, {remaining} left)",
+    ),
+    ("error.reaction_used", "Reaction already used"),
+    ("error.invalid_roll", "A d20 roll must be 1-20 (got {roll})"),
+    (
+        "error.negative_amount",
+        "Damage and healing can't be negative (got {amount})",
+    ),
+    ("error.target_unconscious", "The target is unconscious"),
+    ("error.target_dead", "The target is dead"),
+    ("error.invalid_level", "Level out of range (got {level})"),
+    (
+        "error.invalid_party_size",
+        "Party needs at least one member (got {size})",
+    ),
+    ("error.no_hero_points", "No Hero Points left"),
+    (
+        "error.fortune_already_applied",
+        "A fortune effect already applies to this roll",
+    ),
+    (
+        "error.creature_too_powerful",
+        "{name} is too powerful for the party",
+    ),
+    ("error.over_budget", "XP budget exceeded ({xp} / {budget})"),
+    ("error.not_your_turn", "It's not your turn"),
+    ("error.out_of_reach", "The target is out of reach"),
+    (
+        "error.too_far",
+        "Farther than your Speed ({distance} feet / {speed} feet)",
+    ),
+    (
+        "error.square_occupied",
+        "Another combatant is in that square",
+    ),
+    ("error.self_target", "Can't target yourself"),
+    (
+        "error.proficiency_too_low",
+        "{skill} must be {required} or better",
+    ),
+    ("error.immune", "Still immune ({minutes_left} minutes left)"),
+    (
+        "error.hands_full",
+        "Not enough free hands (needs {needed}, {free} free)",
+    ),
+    (
+        "error.over_max_bulk",
+        "Too heavy to carry (Bulk {bulk} / max {max})",
+    ),
+    ("error.no_such_item", "No item at position {index}"),
+    ("error.no_weapon_held", "No weapon in hand"),
+    ("error.no_shield", "No shield in hand"),
+    (
+        "error.no_recall_skill",
+        "No skill can recall knowledge about {name}",
+    ),
+    ("error.no_focus_points", "No Focus Points left"),
+    ("error.focus_pool_full", "Focus pool is already full"),
+    ("error.wrong_class", "That's a {class} class feature"),
+    (
+        "error.no_feat_slot",
+        "No open level {level} {kind} feat slot",
+    ),
+    (
+        "error.feat_level_too_high",
+        "Can't take a level {feat} feat in a level {slot} slot",
+    ),
+    ("error.feat_already_taken", "Feat already taken: {name}"),
+    (
+        "error.prerequisite_not_met",
+        "Prerequisite not met: {prerequisite}",
+    ),
+    ("error.invalid_dice", "Can't read dice notation: {text}"),
+    ("error.invalid_roster", "Can't read line {line}: {text}"),
+    ("error.no_such_tier", "The {stat} table has no {tier} tier"),
+    (
+        "error.no_disable_skill",
+        "{skill} can't disable this hazard",
+    ),
+    ("error.hazard_inactive", "{name} is no longer active"),
+    ("error.invalid_price", "Can't read price: {text}"),
+    (
+        "error.not_enough_coins",
+        "Not enough money (costs {price}, have {coins})",
+    ),
+    ("error.not_your_minion", "Only its master can command it"),
+    ("error.already_commanded", "Already commanded this turn"),
+    ("error.no_quickened_action", "No quickened action left"),
+    (
+        "error.quickened_not_allowed",
+        "The quickened action can't be used for {action}",
+    ),
+    (
+        "error.no_cover_or_concealment",
+        "Need cover or concealment to Hide",
+    ),
+    ("error.not_hidden", "Must be hidden to Sneak"),
+    (
+        "error.flourish_used",
+        "Already used a flourish action this turn",
+    ),
+];
+
+#[cfg(test)]
+mod tests {
+
+    use std::collections::BTreeSet;
+
+    use super::*;
+    use crate::treasure::Coins;
+
+    fn keys(locale: Locale) -> BTreeSet<&'static str> {
+        locale.catalog().iter().map(|(key, _)| *key).collect()
+    }
+
+    /// 문구 안의 `{이름}` 자리
+    fn placeholders(text: &str) -> BTreeSet<&str> {
+        text.split('{')
+            .skip(1)
+            .filter_map(|part| part.split_once('}').map(|(name, _)| name))
+            .collect()
+    }
+
+    #[test]
+    fn test_catalogs_have_same_keys() {
+        for locale in Locale::ALL {
+            assert_eq!(
+                keys(locale).len(),
+                locale.catalog().len(),
+                "{locale:?} 중복 키"
+            );
+        }
+        let korean = keys(Locale::Korean);
+        let english = keys(Locale::English);
+        assert_eq!(
+            korean.difference(&english).collect::<Vec<_>>(),
+            Vec::<&&str>::new(),
+            "English 카탈로그에 없는 키"
+        );
+        assert_eq!(
+            english.difference(&korean).collect::<Vec<_>>(),
+            Vec::<&&str>::new(),
+            "Korean 카탈로그에 없는 키"
+        );
+
+        // 같은 키는 같은 자리를 채운다
+        for key in korean {
+            assert_eq!(
+                placeholders(Locale::Korean.text(key)),
+                placeholders(Locale::English.text(key)),
+                "{key}"
+            );
+        }
+    }
+
+    /// 엔진이 쓰는 모든 키가 두 카탈로그에 있다
+    #[test]
+    fn test_every_key_is_translated() {
+        let degrees = [
+            DegreeOfSuccess::CriticalSuccess,
+            DegreeOfSuccess::Success,
+            DegreeOfSuccess::Failure,
+            DegreeOfSuccess::CriticalFailure,
+        ];
+        let conditions = [
+            Condition::Blinded,
+            Condition::Clumsy(1),
+            Condition::Dazzled,
+            Condition::Deafened,
+            Condition::Drained(1),
+            Condition::Dying(1),
+            Condition::Enfeebled(1),
+            Condition::Fatigued,
+            Condition::Frightened(1),
+            Condition::Grabbed,
+            Condition::Immobilized,
+            Condition::OffGuard,
+            Condition::Prone,
+            Condition::Quickened,
+            Condition::Restrained,
+            Condition::Sickened(1),
+            Condition::Slowed(1),
+            Condition::Stunned(1),
+            Condition::Stupefied(1),
+            Condition::Unconscious,
+            Condition::Wounded(1),
+        ];
+        let detections = [
+            DetectionState::Observed,
+            DetectionState::Concealed,
+            DetectionState::Hidden,
+            DetectionState::Undetected,
+            DetectionState::Unnoticed,
+        ];
+        let errors = [
+            RulesError::InsufficientActions {
+                cost: 2,
+                remaining: 1,
+            },
+            RulesError::ReactionUsed,
+            RulesError::InvalidRoll(0),
+            RulesError::NegativeAmount(-1),
+            RulesError::TargetUnconscious,
+            RulesError::TargetDead,
+            RulesError::InvalidLevel(0),
+            RulesError::InvalidPartySize(0),
+            RulesError::NoHeroPoints,
+            RulesError::FortuneAlreadyApplied,
+            RulesError::CreatureTooPowerful("Owlbear".to_string()),
+            RulesError::OverBudget { xp: 1, budget: 0 },
+            RulesError::NotYourTurn,
+            RulesError::OutOfReach,
+            RulesError::TooFar {
+                distance: 30,
+                speed: 25,
+            },
+            RulesError::SquareOccupied,
+            RulesError::SelfTarget,
+            RulesError::ProficiencyTooLow {
+                skill: Skill::Medicine,
+                required: Proficiency::Trained,
+            },
+            RulesError::Immune { minutes_left: 1 },
+            RulesError::HandsFull { needed: 2, free: 1 },
+            RulesError::OverMaxBulk { bulk: 14, max: 13 },
+            RulesError::NoSuchItem(0),
+            RulesError::NoWeaponHeld,
+            RulesError::NoShield,
+            RulesError::NoRecallSkill("Goblin".to_string()),
+            RulesError::NoFocusPoints,
+            RulesError::FocusPoolFull,
+            RulesError::WrongClass(Class::Barbarian),
+            RulesError::NoFeatSlot {
+                kind: FeatKind::Class,
+                level: 1,
+            },
+            RulesError::FeatLevelTooHigh { feat: 2, slot: 1 },
+            RulesError::FeatAlreadyTaken("Power Attack".to_string()),
+            RulesError::PrerequisiteNotMet(Prerequisite::Any(vec![
+                Prerequisite::Skill(Skill::Athletics, Proficiency::Expert),
+                Prerequisite::Perception(Proficiency::Expert),
+                Prerequisite::Save(SaveType::Will, Proficiency::Expert),
+                Prerequisite::Weapon(Proficiency::Expert),
+                Prerequisite::Ability(Ability::Dexterity, 14),
+                Prerequisite::Feat("Power Attack".to_string()),
+            ])),
+            RulesError::InvalidDice("d6".to_string()),
+            RulesError::InvalidRoster {
+                line: 1,
+                text: "Goblin".to_string(),
+            },
+            RulesError::NoSuchTier {
+                stat: "HP".to_string(),
+                tier: Tier::Extreme,
+            },
+            RulesError::NoDisableSkill(Skill::Athletics),
+            RulesError::HazardInactive("Hidden Pit".to_string()),
+            RulesError::InvalidPrice("12 gold".to_string()),
+            RulesError::NotEnoughCoins {
+                price: Coins::gp(15),
+                coins: Coins::gp(2),
+            },
+            RulesError::NotYourMinion,
+            RulesError::AlreadyCommanded,
+            RulesError::NoQuickenedAction,
+            RulesError::QuickenedNotAllowed(ActionKind::Strike),
+            RulesError::NoCoverOrConcealment,
+            RulesError::NotHidden,
+            RulesError::FlourishUsed,
+        ];
+        let logs = [
+            "side.party",
+            "side.creatures",
+            "report.iterations",
+            "report.win_rate",
+            "report.rounds",
+            "report.header",
+            "action.hide",
+            "action.sneak",
+            "action.seek",
+            "log.initiative",
+            "log.error",
+            "log.anthem",
+            "log.raise_shield",
+            "log.rage",
+            "log.command",
+            "log.actions_lost",
+            "log.strike",
+            "log.sneak_attack",
+            "log.maneuver",
+            "log.pushed",
+            "log.flat_check_failed",
+            "log.retributive_strike",
+            "log.knocked_out",
+            "log.effect_ended",
+            "log.recovery_check",
+            "log.stable",
+            "log.dead",
+            "log.defeated",
+            "log.hazard_attack",
+            "log.hazard_disabled",
+            "log.hazard_progress",
+            "log.hazard_no_effect",
+            "log.hazard_triggered",
+            "table.round",
+            "table.turn",
+            "table.menu",
+            "table.choose_target",
+            "table.choose_reactor",
+            "table.act",
+            "table.react",
+            "table.saved",
+            "hud.finished",
+            "hud.status",
+            "log.detection",
+        ];
+        let names = [
+            Ability::Strength.key(),
+            Ability::Dexterity.key(),
+            Ability::Constitution.key(),
+            Ability::Intelligence.key(),
+            Ability::Wisdom.key(),
+            Ability::Charisma.key(),
+            Skill::Acrobatics.key(),
+            Skill::Arcana.key(),
+            Skill::Athletics.key(),
+            Skill::Crafting.key(),
+            Skill::Deception.key(),
+            Skill::Diplomacy.key(),
+            Skill::Intimidation.key(),
+            Skill::Medicine.key(),
+            Skill::Nature.key(),
+            Skill::Occultism.key(),
+            Skill::Performance.key(),
+            Skill::Religion.key(),
+            Skill::Society.key(),
+            Skill::Stealth.key(),
+            Skill::Survival.key(),
+            Skill::Thievery.key(),
+            Proficiency::Untrained.key(),
+            Proficiency::Trained.key(),
+            Proficiency::Expert.key(),
+            Proficiency::Master.key(),
+            Proficiency::Legendary.key(),
+            SaveType::Fortitude.key(),
+            SaveType::Reflex.key(),
+            SaveType::Will.key(),
+            Class::Fighter.key(),
+            Class::Rogue.key(),
+            Class::Barbarian.key(),
+            Class::Champion.key(),
+            FeatKind::Ancestry.key(),
+            FeatKind::Class.key(),
+            FeatKind::Skill.key(),
+            FeatKind::General.key(),
+            Tier::Extreme.key(),
+            Tier::High.key(),
+            Tier::Moderate.key(),
+            Tier::Low.key(),
+            Tier::Terrible.key(),
+            ActionKind::Strike.key(),
+            ActionKind::Stride.key(),
+            ActionKind::Step.key(),
+            ActionKind::Interact.key(),
+            ActionKind::RaiseShield.key(),
+            ActionKind::Command.key(),
+            ActionKind::Skill.key(),
+        ];
+
+        for locale in Locale::ALL {
+            for key in conditions
+                .iter()
+                .map(Condition::key)
+                .chain(logs)
+                .chain(names)
+            {
+                assert!(locale.has(key), "{locale:?}: {key}");
+            }
+            // 번역이 없으면 키가 그대로 나온다
+            for text in degrees
+                .iter()
+                .map(|d| d.localize(locale))
+                .chain(detections.iter().map(|d| d.localize(locale)))
+                .chain(errors.iter().map(|e| e.localize(locale)))
+            {
+                assert!(
+                    !text.starts_with("degree.")
+                        && !text.starts_with("detection.")
+                        && !text.starts_with("error."),
+                    "{locale:?}: {text}"
+                );
+                assert!(!text.contains('{'), "{locale:?}: {text}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_localize() {
+        assert_eq!(
+            DegreeOfSuccess::CriticalSuccess.localize(Locale::Korean),
+            "치명적 성공"
+        );
+        assert_eq!(
+            DegreeOfSuccess::CriticalSuccess.localize(Locale::English),
+            "Critical Success"
+        );
+        assert_eq!(Condition::Frightened(2).localize(Locale::Korean), "공포 2");
+        assert_eq!(Condition::OffGuard.localize(Locale::English), "Off-Guard");
+
+        let error = RulesError::InsufficientActions {
+            cost: 2,
+            remaining: 1,
+        };
+        assert_eq!(
+            error.localize(Locale::English),
+            "Not enough actions (needs 2, 1 left)"
+        );
+        assert_eq!(error.localize(Locale::Korean), error.to_string());
+
+        let error = RulesError::ProficiencyTooLow {
+            skill: Skill::Medicine,
+            required: Proficiency::Trained,
+        };
+        assert_eq!(
+            error.localize(Locale::Korean),
+            Locale::Korean.format(
+                "error.proficiency_too_low",
+                &[("skill", &"의술"), ("required", &"숙련")]
+            )
+        );
+        let error = RulesError::PrerequisiteNotMet(Prerequisite::Ability(Ability::Dexterity, 14));
+        assert_eq!(
+            error.localize(Locale::English),
+            "Prerequisite not met: Dexterity 14"
+        );
+        assert_eq!(Locale::Korean.text("log.unknown"), "log.unknown");
+    }
+}
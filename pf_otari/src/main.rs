@@ -0,0 +1,15 @@
+use bevy::prelude::*;
+use pf_otari::plugin::{PfOtariPlugin, spawn_demo_encounter};
+use pf_otari::render::PfOtariRenderPlugin;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins.set(AssetPlugin {
+            // 워크스페이스 공용 assets 폴더 (NanumGothic 폰트)
+            file_path: "../assets".to_string(),
+            ..default()
+        }))
+        .add_plugins((PfOtariPlugin::default(), PfOtariRenderPlugin))
+        .add_systems(Startup, spawn_demo_encounter)
+        .run();
+}
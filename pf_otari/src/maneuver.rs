@@ -0,0 +1,425 @@
+use rand::Rng;
+
+use crate::character::{Character, Skill};
+use crate::check::{Check, CheckOutcome, SaveType};
+use crate::combat::multiple_attack_penalty;
+use crate::condition::Condition;
+use crate::dice::{DegreeOfSuccess, Dice};
+use crate::error::RulesError;
+use crate::outcome::{DamageMultiplier, Effect, OutcomeTable};
+
+/// 기술로 하는 전투 기동, 대상의 방어 DC(10 + 내성)에 맞서 판정한다
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum Maneuver {
+    Trip,
+    Grapple,
+    Shove,
+    Disarm,
+    Demoralize,
+}
+
+/// 기동 결과 등급에 따른 효과
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ManeuverEffects {
+    pub degree: DegreeOfSuccess,
+    /// 대상에게 걸리는 상태
+    pub target: Vec<Condition>,
+    /// 시도한 쪽에게 걸리는 상태 (치명적 실패)
+    pub attacker: Vec<Condition>,
+    /// 대상이 밀려나는 거리 (피트)
+    pub push_feet: i32,
+    /// 대상이 받는 피해 주사위
+    pub damage: Option<Dice>,
+    /// 대상이 든 물건을 떨어뜨림
+    pub disarmed: bool,
+    /// 효과 설명의 카탈로그 키, [`Localize`](crate::locale::Localize)로 옮긴다
+    pub key: String,
+}
+
+/// 기동 판정 결과
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ManeuverResult {
+    pub outcome: CheckOutcome,
+    pub effects: ManeuverEffects,
+}
+
+impl Maneuver {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Maneuver::Trip => "Trip",
+            Maneuver::Grapple => "Grapple",
+            Maneuver::Shove => "Shove",
+            Maneuver::Disarm => "Disarm",
+            Maneuver::Demoralize => "Demoralize",
+        }
+    }
+
+    pub fn skill(&self) -> Skill {
+        match self {
+            Maneuver::Demoralize => Skill::Intimidation,
+            _ => Skill::Athletics,
+        }
+    }
+
+    /// 맞서는 방어
+    pub fn defense(&self) -> SaveType {
+        match self {
+            Maneuver::Trip | Maneuver::Disarm => SaveType::Reflex,
+            Maneuver::Grapple | Maneuver::Shove => SaveType::Fortitude,
+            Maneuver::Demoralize => SaveType::Will,
+        }
+    }
+
+    /// 공격(attack) 특성: 다중 공격 페널티를 받고, 이후 공격의 페널티를 늘린다
+    pub fn has_attack_trait(&self) -> bool {
+        *self != Maneuver::Demoralize
+    }
+
+    /// 대상에게 미치는 등급별 효과, 설명은 `maneuver.*` 카탈로그 키
+    pub fn table(&self) -> OutcomeTable {
+        let none = || Effect::text("maneuver.no_effect");
+        let attacker_prone = || Effect::text("maneuver.attacker_prone");
+        match self {
+            Maneuver::Trip => OutcomeTable::new(
+                Effect::new(
+                    DamageMultiplier::Full,
+                    vec![Condition::Prone],
+                    "maneuver.trip.critical_success",
+                ),
+                Effect::new(
+                    DamageMultiplier::None,
+                    vec![Condition::Prone],
+                    "maneuver.trip.success",
+                ),
+                none(),
+                attacker_prone(),
+            ),
+            Maneuver::Grapple => OutcomeTable::new(
+                Effect::new(
+                    DamageMultiplier::None,
+                    vec![Condition::Restrained],
+                    "maneuver.grapple.critical_success",
+                ),
+                Effect::new(
+                    DamageMultiplier::None,
+                    vec![Condition::Grabbed],
+                    "maneuver.grapple.success",
+                ),
+                Effect::text("maneuver.grapple.failure"),
+                Effect::text("maneuver.grapple.critical_failure"),
+            ),
+            Maneuver::Shove => OutcomeTable::new(
+                Effect::text("maneuver.shove.critical_success"),
+                Effect::text("maneuver.shove.success"),
+                none(),
+                attacker_prone(),
+            ),
+            Maneuver::Disarm => OutcomeTable::new(
+                Effect::text("maneuver.disarm.critical_success"),
+                Effect::text("maneuver.disarm.success"),
+                none(),
+                Effect::text("maneuver.disarm.critical_failure"),
+            ),
+            Maneuver::Demoralize => OutcomeTable::new(
+                Effect::new(
+                    DamageMultiplier::None,
+                    vec![Condition::Frightened(2)],
+                    "maneuver.demoralize.critical_success",
+                ),
+                Effect::new(
+                    DamageMultiplier::None,
+                    vec![Condition::Frightened(1)],
+                    "maneuver.demoralize.success",
+                ),
+                none(),
+                none(),
+            ),
+        }
+    }
+
+    pub fn effects(&self, degree: DegreeOfSuccess) -> ManeuverEffects {
+        use DegreeOfSuccess::*;
+
+        let table = self.table();
+        let effect = table.effect(degree);
+        let attacker = match (self, degree) {
+            (Maneuver::Trip | Maneuver::Grapple | Maneuver::Shove, CriticalFailure) => {
+                vec![Condition::Prone]
+            }
+            (Maneuver::Disarm, CriticalFailure) => vec![Condition::OffGuard],
+            _ => Vec::new(),
+        };
+        let push_feet = match (self, degree) {
+            (Maneuver::Shove, CriticalSuccess) => 10,
+            (Maneuver::Shove, Success) => 5,
+            _ => 0,
+        };
+        let damage = match (self, effect.damage) {
+            (Maneuver::Trip, DamageMultiplier::Full) => Some(Dice::new(1, 6)),
+            _ => None,
+        };
+
+        ManeuverEffects {
+            degree,
+            target: effect.conditions.clone(),
+            attacker,
+            push_feet,
+            damage,
+            disarmed: *self == Maneuver::Disarm && degree == CriticalSuccess,
+            key: effect.text.clone(),
+        }
+    }
+
+    /// `attacks_made`는 이번 턴에 이미 한 공격 횟수, 공격 특성이 있으면 MAP를 받고 1 늘린다
+    pub fn attempt(
+        &self,
+        attacker: &Character,
+        defense_dc: i32,
+        attacks_made: &mut u8,
+        rng: &mut impl Rng,
+    ) -> ManeuverResult {
+        let map = if self.has_attack_trait() {
+            let map = multiple_attack_penalty(*attacks_made, false);
+            *attacks_made += 1;
+            map
+        } else {
+            0
+        };
+        let outcome = Check::new(attacker.skill_bonus(self.skill()) + map, defense_dc).roll(rng);
+
+        ManeuverResult {
+            effects: self.effects(outcome.degree),
+            outcome,
+        }
+    }
+}
+
+/// Demoralize 면역: 시도 결과와 관계없이 대상은 10분 동안 같은 상대의 Demoralize에 면역
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct DemoralizeImmunity {
+    /// (시도한 쪽, 대상, 면역이 끝나는 라운드)
+    entries: Vec<(String, String, u32)>,
+}
+
+impl DemoralizeImmunity {
+    /// 10분 = 100라운드
+    pub const ROUNDS: u32 = 100;
+
+    pub fn rounds_left(&self, attacker: &str, target: &str, round: u32) -> u32 {
+        self.entries
+            .iter()
+            .find(|(a, t, _)| a == attacker && t == target)
+            .map_or(0, |(_, _, until)| until.saturating_sub(round))
+    }
+
+    /// 면역이면 굴리지 않는다
+    pub fn attempt(
+        &mut self,
+        attacker: (&str, &Character),
+        target: (&str, i32),
+        round: u32,
+        rng: &mut impl Rng,
+    ) -> Result<ManeuverResult, RulesError> {
+        let (attacker_name, character) = attacker;
+        let (target_name, will_dc) = target;
+
+        let rounds_left = self.rounds_left(attacker_name, target_name, round);
+        if rounds_left > 0 {
+            return Err(RulesError::Immune {
+                minutes_left: rounds_left.div_ceil(10),
+            });
+        }
+
+        let result = Maneuver::Demoralize.attempt(character, will_dc, &mut 0, rng);
+        self.entries
+            .retain(|(a, t, _)| !(a == attacker_name && t == target_name));
+        self.entries.push((
+            attacker_name.to_string(),
+            target_name.to_string(),
+            round + Self::ROUNDS,
+        ));
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    use super::DegreeOfSuccess::*;
+    use super::*;
+    use crate::bestiary::bestiary;
+    use crate::character::{Attribute, Defense, Proficiency};
+    use crate::locale::{Locale, Localize};
+
+    fn fighter() -> Character {
+        Character::new(
+            10,
+            Attribute {
+                strength: 18,
+                dexterity: 12,
+                constitution: 14,
+                intelligence: 10,
+                wisdom: 12,
+                charisma: 12,
+            },
+            Defense {
+                armor_item: 4,
+                armor_proficiency: Proficiency::Trained,
+            },
+            Proficiency::Trained,
+        )
+        .with_skill(Skill::Athletics, Proficiency::Trained)
+        .with_skill(Skill::Intimidation, Proficiency::Trained)
+    }
+
+    #[test]
+    fn test_maneuver_defense() {
+        assert_eq!(Maneuver::Trip.defense(), SaveType::Reflex);
+        assert_eq!(Maneuver::Grapple.defense(), SaveType::Fortitude);
+        assert_eq!(Maneuver::Demoralize.skill(), Skill::Intimidation);
+        assert!(!Maneuver::Demoralize.has_attack_trait());
+
+        let goblin = bestiary()
+            .into_iter()
+            .find(|c| c.name == "Goblin Warrior")
+            .unwrap();
+        // 10 + Reflex 7
+        assert_eq!(goblin.defense_dc(Maneuver::Trip.defense()), 17);
+    }
+
+    #[test]
+    fn test_trip_effects() {
+        let critical = Maneuver::Trip.effects(CriticalSuccess);
+        assert_eq!(critical.target, vec![Condition::Prone]);
+        assert_eq!(critical.damage, Some(Dice::new(1, 6)));
+
+        let success = Maneuver::Trip.effects(Success);
+        assert_eq!(success.target, vec![Condition::Prone]);
+        assert_eq!(success.damage, None);
+
+        let fumble = Maneuver::Trip.effects(CriticalFailure);
+        assert!(fumble.target.is_empty());
+        assert_eq!(fumble.attacker, vec![Condition::Prone]);
+    }
+
+    #[test]
+    fn test_other_effects() {
+        assert_eq!(
+            Maneuver::Grapple.effects(CriticalSuccess).target,
+            vec![Condition::Restrained]
+        );
+        assert_eq!(
+            Maneuver::Grapple.effects(Success).target,
+            vec![Condition::Grabbed]
+        );
+        assert_eq!(Maneuver::Shove.effects(CriticalSuccess).push_feet, 10);
+        assert_eq!(Maneuver::Shove.effects(Success).push_feet, 5);
+        assert_eq!(Maneuver::Shove.effects(Failure).push_feet, 0);
+        assert!(Maneuver::Disarm.effects(CriticalSuccess).disarmed);
+        assert!(!Maneuver::Disarm.effects(Success).disarmed);
+        assert_eq!(
+            Maneuver::Disarm.effects(CriticalFailure).attacker,
+            vec![Condition::OffGuard]
+        );
+        assert_eq!(
+            Maneuver::Demoralize.effects(CriticalSuccess).target,
+            vec![Condition::Frightened(2)]
+        );
+        assert!(
+            Maneuver::Demoralize
+                .effects(CriticalFailure)
+                .attacker
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_effects_are_translated() {
+        let maneuvers = [
+            Maneuver::Trip,
+            Maneuver::Grapple,
+            Maneuver::Shove,
+            Maneuver::Disarm,
+            Maneuver::Demoralize,
+        ];
+        for maneuver in maneuvers {
+            for degree in [CriticalSuccess, Success, Failure, CriticalFailure] {
+                let effects = maneuver.effects(degree);
+                for locale in Locale::ALL {
+                    assert!(locale.has(&effects.key), "{locale:?}: {}", effects.key);
+                }
+            }
+        }
+        assert_eq!(
+            Maneuver::Shove.effects(Success).localize(Locale::English),
+            "Pushed 5 feet"
+        );
+        assert_eq!(
+            Maneuver::Trip.effects(Failure).localize(Locale::Korean),
+            "효과 없음"
+        );
+    }
+
+    #[test]
+    fn test_attempt_applies_map() {
+        let fighter = fighter();
+        let mut rng = StdRng::seed_from_u64(4);
+
+        let mut attacks_made = 0;
+
+        // Athletics: STR 4 + Trained 3
+        let first = Maneuver::Shove.attempt(&fighter, 17, &mut attacks_made, &mut rng);
+        assert_eq!(first.outcome.total, first.outcome.natural as i32 + 7);
+        assert_eq!(attacks_made, 1);
+
+        let second = Maneuver::Trip.attempt(&fighter, 17, &mut attacks_made, &mut rng);
+        assert_eq!(second.outcome.total, second.outcome.natural as i32 + 2);
+        assert_eq!(attacks_made, 2);
+
+        // Demoralize는 공격 특성이 없어 MAP도 없고 공격 횟수도 늘리지 않음
+        let demoralize = Maneuver::Demoralize.attempt(&fighter, 13, &mut attacks_made, &mut rng);
+        assert_eq!(
+            demoralize.outcome.total,
+            demoralize.outcome.natural as i32 + 4
+        );
+        assert_eq!(attacks_made, 2);
+        assert_eq!(
+            demoralize.effects,
+            Maneuver::Demoralize.effects(demoralize.outcome.degree)
+        );
+    }
+
+    #[test]
+    fn test_demoralize_immunity() {
+        let fighter = fighter();
+        let mut immunity = DemoralizeImmunity::default();
+        let mut rng = StdRng::seed_from_u64(8);
+
+        immunity
+            .attempt(("Fighter", &fighter), ("Goblin", 13), 1, &mut rng)
+            .unwrap();
+        assert_eq!(
+            immunity.attempt(("Fighter", &fighter), ("Goblin", 13), 3, &mut rng),
+            Err(RulesError::Immune { minutes_left: 10 })
+        );
+        // 다른 대상, 다른 시도자는 상관없음
+        assert!(
+            immunity
+                .attempt(("Fighter", &fighter), ("Wolf", 15), 3, &mut rng)
+                .is_ok()
+        );
+        assert!(
+            immunity
+                .attempt(("Rogue", &fighter), ("Goblin", 13), 3, &mut rng)
+                .is_ok()
+        );
+        assert!(
+            immunity
+                .attempt(("Fighter", &fighter), ("Goblin", 13), 101, &mut rng)
+                .is_ok()
+        );
+    }
+}
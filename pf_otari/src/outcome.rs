@@ -0,0 +1,329 @@
+use crate::check::{CheckKind, SaveType};
+use crate::condition::Condition;
+use crate::dice::DegreeOfSuccess;
+
+/// 기본 피해에 적용하는 배수
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum DamageMultiplier {
+    None,
+    /// 절반, 내림
+    Half,
+    Full,
+    Double,
+}
+
+impl DamageMultiplier {
+    pub fn apply(&self, damage: i32) -> i32 {
+        match self {
+            DamageMultiplier::None => 0,
+            DamageMultiplier::Half => damage / 2,
+            DamageMultiplier::Full => damage,
+            DamageMultiplier::Double => damage * 2,
+        }
+    }
+}
+
+/// 한 판정 결과 등급에 따른 효과
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Effect {
+    pub damage: DamageMultiplier,
+    /// 피해를 주는 효과의 최소 피해 (Strike는 1)
+    pub minimum_damage: i32,
+    pub conditions: Vec<Condition>,
+    /// 효과 설명의 카탈로그 키, [`Localize`](crate::locale::Localize)로 옮긴다
+    pub text: String,
+}
+
+impl Effect {
+    pub fn new(damage: DamageMultiplier, conditions: Vec<Condition>, text: &str) -> Self {
+        Self {
+            damage,
+            minimum_damage: 0,
+            conditions,
+            text: text.to_string(),
+        }
+    }
+
+    pub fn with_minimum_damage(mut self, minimum: i32) -> Self {
+        self.minimum_damage = minimum;
+        self
+    }
+
+    /// 기본 피해에 배수를 적용한다, 피해가 있으면 최소 피해보다 작지 않다
+    pub fn apply(&self, base: i32) -> i32 {
+        match self.damage {
+            DamageMultiplier::None => 0,
+            multiplier => multiplier.apply(base).max(self.minimum_damage),
+        }
+    }
+
+    /// 효과 설명만 있는 경우
+    pub fn text(text: &str) -> Self {
+        Self::new(DamageMultiplier::None, Vec::new(), text)
+    }
+}
+
+/// 판정 결과 등급을 바꾸는 능력 (예: Evasion, Juggernaut)
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct DegreeAdjustment {
+    pub applies_to: CheckKind,
+    pub from: DegreeOfSuccess,
+    pub to: DegreeOfSuccess,
+}
+
+impl DegreeAdjustment {
+    /// Reflex 내성 Success → CriticalSuccess
+    pub fn evasion() -> Self {
+        Self::save_success_to_critical(SaveType::Reflex)
+    }
+
+    /// Fortitude 내성 Success → CriticalSuccess
+    pub fn juggernaut() -> Self {
+        Self::save_success_to_critical(SaveType::Fortitude)
+    }
+
+    /// Will 내성 Success → CriticalSuccess
+    pub fn resolve() -> Self {
+        Self::save_success_to_critical(SaveType::Will)
+    }
+
+    fn save_success_to_critical(save: SaveType) -> Self {
+        Self {
+            applies_to: CheckKind::Save(save),
+            from: DegreeOfSuccess::Success,
+            to: DegreeOfSuccess::CriticalSuccess,
+        }
+    }
+}
+
+/// 판정 결과 등급을 능력에 따라 조정한다. 조정은 한 번만 적용된다.
+pub fn adjust_degree(
+    kind: CheckKind,
+    degree: DegreeOfSuccess,
+    adjustments: &[DegreeAdjustment],
+) -> DegreeOfSuccess {
+    adjustments
+        .iter()
+        .find(|a| a.applies_to == kind && a.from == degree)
+        .map_or(degree, |a| a.to)
+}
+
+/// 판정 결과 등급별 효과 표
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct OutcomeTable {
+    pub critical_success: Effect,
+    pub success: Effect,
+    pub failure: Effect,
+    pub critical_failure: Effect,
+}
+
+/// 효과 표 적용 결과
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Resolution<'a> {
+    /// 능력 조정 후의 등급
+    pub degree: DegreeOfSuccess,
+    pub effect: &'a Effect,
+}
+
+impl Resolution<'_> {
+    /// 기본 피해에 등급별 배수를 적용
+    pub fn damage(&self, base: i32) -> i32 {
+        self.effect.apply(base)
+    }
+}
+
+impl OutcomeTable {
+    pub fn new(
+        critical_success: Effect,
+        success: Effect,
+        failure: Effect,
+        critical_failure: Effect,
+    ) -> Self {
+        Self {
+            critical_success,
+            success,
+            failure,
+            critical_failure,
+        }
+    }
+
+    /// 기본 내성 (basic saving throw): 피해 없음 / 절반 / 전부 / 2배
+    pub fn basic_save() -> Self {
+        Self::new(
+            Effect::new(DamageMultiplier::None, Vec::new(), "outcome.no_damage"),
+            Effect::new(DamageMultiplier::Half, Vec::new(), "outcome.half_damage"),
+            Effect::new(DamageMultiplier::Full, Vec::new(), "outcome.damage"),
+            Effect::new(
+                DamageMultiplier::Double,
+                Vec::new(),
+                "outcome.double_damage",
+            ),
+        )
+    }
+
+    /// Strike: `calc_damage`와 같은 배수, 명중하면 최소 1
+    pub fn strike() -> Self {
+        Self::new(
+            Effect::new(DamageMultiplier::Double, Vec::new(), "outcome.critical_hit")
+                .with_minimum_damage(1),
+            Effect::new(DamageMultiplier::Full, Vec::new(), "outcome.hit").with_minimum_damage(1),
+            Effect::text("outcome.miss"),
+            Effect::text("outcome.miss"),
+        )
+    }
+
+    pub fn effect(&self, degree: DegreeOfSuccess) -> &Effect {
+        match degree {
+            DegreeOfSuccess::CriticalSuccess => &self.critical_success,
+            DegreeOfSuccess::Success => &self.success,
+            DegreeOfSuccess::Failure => &self.failure,
+            DegreeOfSuccess::CriticalFailure => &self.critical_failure,
+        }
+    }
+
+    /// 등급 조정 능력을 적용한 뒤 해당 등급의 효과를 찾는다
+    pub fn resolve(
+        &self,
+        kind: CheckKind,
+        degree: DegreeOfSuccess,
+        adjustments: &[DegreeAdjustment],
+    ) -> Resolution<'_> {
+        let degree = adjust_degree(kind, degree, adjustments);
+        Resolution {
+            degree,
+            effect: self.effect(degree),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::DegreeOfSuccess::*;
+    use super::*;
+    use crate::locale::{Locale, Localize};
+
+    const REFLEX: CheckKind = CheckKind::Save(SaveType::Reflex);
+    const FORTITUDE: CheckKind = CheckKind::Save(SaveType::Fortitude);
+
+    #[test]
+    fn test_damage_multiplier() {
+        assert_eq!(DamageMultiplier::None.apply(15), 0);
+        assert_eq!(DamageMultiplier::Half.apply(15), 7); // 내림
+        assert_eq!(DamageMultiplier::Full.apply(15), 15);
+        assert_eq!(DamageMultiplier::Double.apply(15), 30);
+    }
+
+    #[test]
+    fn test_basic_save() {
+        // Fireball 6d6 = 21
+        let table = OutcomeTable::basic_save();
+
+        assert_eq!(table.resolve(REFLEX, CriticalSuccess, &[]).damage(21), 0);
+        assert_eq!(table.resolve(REFLEX, Success, &[]).damage(21), 10);
+        assert_eq!(table.resolve(REFLEX, Failure, &[]).damage(21), 21);
+        assert_eq!(table.resolve(REFLEX, CriticalFailure, &[]).damage(21), 42);
+    }
+
+    #[test]
+    fn test_strike_table_matches_calc_damage() {
+        use crate::combat::calc_damage;
+
+        let table = OutcomeTable::strike();
+        for degree in [CriticalSuccess, Success, Failure, CriticalFailure] {
+            let resolution = table.resolve(CheckKind::Attack, degree, &[]);
+            assert_eq!(resolution.damage(4 + 3), calc_damage(degree, 4, 3));
+            // 페널티가 커도 명중하면 최소 1
+            assert_eq!(resolution.damage(1 - 4), calc_damage(degree, 1, -4));
+        }
+        let hit = table.resolve(CheckKind::Attack, Success, &[]);
+        assert_eq!(hit.damage(1 - 4), 1);
+        assert_eq!(table.effect(CriticalSuccess).apply(-3), 1);
+    }
+
+    #[test]
+    fn test_evasion() {
+        let table = OutcomeTable::basic_save();
+        let evasion = [DegreeAdjustment::evasion()];
+
+        // Reflex 성공 → 치명적 성공, 피해 없음
+        let resolution = table.resolve(REFLEX, Success, &evasion);
+        assert_eq!(resolution.degree, CriticalSuccess);
+        assert_eq!(resolution.damage(21), 0);
+
+        // 다른 등급과 다른 내성에는 영향 없음
+        assert_eq!(table.resolve(REFLEX, Failure, &evasion).degree, Failure);
+        assert_eq!(table.resolve(FORTITUDE, Success, &evasion).degree, Success);
+    }
+
+    #[test]
+    fn test_juggernaut() {
+        let table = OutcomeTable::new(
+            Effect::text("outcome.unaffected"),
+            Effect::new(
+                DamageMultiplier::None,
+                vec![Condition::Sickened(1)],
+                "outcome.afflicted",
+            ),
+            Effect::new(
+                DamageMultiplier::None,
+                vec![Condition::Sickened(2)],
+                "outcome.afflicted",
+            ),
+            Effect::new(
+                DamageMultiplier::None,
+                vec![Condition::Sickened(3), Condition::Slowed(1)],
+                "outcome.afflicted",
+            ),
+        );
+        let juggernaut = [DegreeAdjustment::juggernaut()];
+
+        let resolution = table.resolve(FORTITUDE, Success, &juggernaut);
+        assert_eq!(resolution.degree, CriticalSuccess);
+        assert!(resolution.effect.conditions.is_empty());
+
+        let resolution = table.resolve(FORTITUDE, CriticalFailure, &juggernaut);
+        assert_eq!(
+            resolution.effect.conditions,
+            vec![Condition::Sickened(3), Condition::Slowed(1)]
+        );
+        assert_eq!(resolution.effect.localize(Locale::English), "Afflicted");
+    }
+
+    #[test]
+    fn test_effects_are_translated() {
+        for table in [OutcomeTable::basic_save(), OutcomeTable::strike()] {
+            for degree in [CriticalSuccess, Success, Failure, CriticalFailure] {
+                let effect = table.effect(degree);
+                for locale in Locale::ALL {
+                    assert!(locale.has(&effect.text), "{locale:?}: {}", effect.text);
+                }
+            }
+        }
+        let table = OutcomeTable::basic_save();
+        assert_eq!(
+            table.effect(Success).localize(Locale::English),
+            "Half damage"
+        );
+        assert_eq!(table.effect(Success).localize(Locale::Korean), "절반 피해");
+    }
+
+    #[test]
+    fn test_adjustment_applies_once() {
+        // 실패 → 성공, 성공 → 치명적 성공이 함께 있어도 한 단계만 조정
+        let adjustments = [
+            DegreeAdjustment {
+                applies_to: REFLEX,
+                from: Failure,
+                to: Success,
+            },
+            DegreeAdjustment::evasion(),
+        ];
+
+        assert_eq!(adjust_degree(REFLEX, Failure, &adjustments), Success);
+        assert_eq!(
+            adjust_degree(REFLEX, Success, &adjustments),
+            CriticalSuccess
+        );
+    }
+}
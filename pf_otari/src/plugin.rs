@@ -0,0 +1,3116 @@
+use std::cmp::Reverse;
+
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+use crate::character::{Attribute, Character, Defense, Proficiency, Skill};
+use crate::check::CheckOutcome;
+use crate::class::{Class, rage, retributive_strike, sneak_attack};
+use crate::combat::{
+    ACTIONS_PER_TURN, ActionKind, ActionSlot, CombatTurn, Weapon, calc_damage,
+    multiple_attack_penalty, strike,
+};
+use crate::companion::Minion;
+use crate::condition::{Condition, OFF_GUARD_AC_PENALTY};
+use crate::dice::{DegreeOfSuccess, Dice, roll_d20};
+use crate::duration::{ActiveEffect, EffectDuration, EffectRegistry, Statistic};
+use crate::error::RulesError;
+use crate::feat::feat_effects;
+use crate::grid::GridPos;
+use crate::hazard::{DisableResult, Hazard, HazardAttack, HazardState};
+use crate::inventory::{Bulk, Item, ItemState};
+use crate::locale::{Locale, Localize};
+use crate::maneuver::{Maneuver, ManeuverResult};
+use crate::senses::{Awareness, DetectionState, LightLevel, Lighting, Observer, Vision};
+use crate::simulation::Side;
+use crate::tactics::{
+    Aggressive, CombatantView, Personality, SHIELD_AC_BONUS, ShieldState, Situation, TacticalAction,
+};
+
+/// Disable a Device의 액션 수
+pub const DISABLE_ACTIONS: u8 = 2;
+
+/// 조우 진행 상태
+#[derive(States, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum EncounterState {
+    /// 참가자 배치
+    #[default]
+    Setup,
+    /// 우선권 순서대로 턴 진행
+    Combat,
+    /// 한쪽 진영이 모두 쓰러짐
+    Finished,
+}
+
+/// 캐릭터 능력치 블록
+#[derive(Component)]
+pub struct Sheet(pub Character);
+
+impl Sheet {
+    pub fn current_hp(&self) -> i32 {
+        self.0.current_hp()
+    }
+
+    pub fn max_hp(&self) -> i32 {
+        self.0.max_hp()
+    }
+
+    pub fn is_standing(&self) -> bool {
+        self.0.current_hp() > 0
+    }
+}
+
+/// 소속 진영
+#[derive(Component, Debug, PartialEq, Eq, Copy, Clone)]
+pub struct Faction(pub Side);
+
+/// 전투 격자 위치
+#[derive(Component, Debug, PartialEq, Eq, Copy, Clone)]
+pub struct GridPosition(pub GridPos);
+
+/// 손에 든 무기와 공격/피해 보너스
+pub struct Armament {
+    pub weapon: Weapon,
+    pub attack_bonus: i32,
+    pub damage_bonus: i32,
+}
+
+impl Armament {
+    /// 캐릭터가 손에 든 무기와 무기 숙련도에서 온 보너스, 든 무기가 없으면 `NoWeaponHeld`
+    pub fn of(character: &Character) -> Result<Self, RulesError> {
+        let weapon = character.weapon().ok_or(RulesError::NoWeaponHeld)?.clone();
+        Ok(Self {
+            weapon,
+            attack_bonus: character.attack_bonus(),
+            damage_bonus: character.damage_bonus(),
+        })
+    }
+}
+
+/// 이번 턴에 남은 액션과 리액션, 이번 턴에 한 Strike 횟수
+#[derive(Component)]
+pub struct TurnState {
+    pub(crate) turn: CombatTurn,
+    pub(crate) strikes_made: u8,
+}
+
+impl TurnState {
+    fn new(turn: CombatTurn) -> Self {
+        Self {
+            turn,
+            strikes_made: 0,
+        }
+    }
+
+    pub fn remaining_actions(&self) -> u8 {
+        self.turn.remaining_actions()
+    }
+
+    pub fn granted_actions(&self) -> u8 {
+        self.turn.granted_actions()
+    }
+
+    pub fn action_slots(&self) -> Vec<ActionSlot> {
+        self.turn.action_slots()
+    }
+
+    pub fn has_reaction(&self) -> bool {
+        self.turn.has_reaction()
+    }
+}
+
+/// 참가자에게 걸린 지속 효과, 턴 순서에 따라 끝난다
+#[derive(Component, Default)]
+pub struct Effects(pub EffectRegistry<Entity>);
+
+/// Courageous Anthem이 닿는 거리 (60피트 발산)
+pub const ANTHEM_RANGE_FEET: i32 = 60;
+
+/// 자기 턴이 되면 스스로 행동하는 참가자
+#[derive(Component)]
+pub struct AutoPlay;
+
+/// `AutoPlay` 참가자의 전술, 없으면 [`Aggressive`]
+#[derive(Component)]
+pub struct Tactician(pub Box<dyn Personality<Entity>>);
+
+/// 들고 있는 방패
+#[derive(Component)]
+pub struct Shield;
+
+/// 턴마다 얻는 액션, 없으면 3
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ActionBudget(pub u8);
+
+fn budget(actions: Option<&ActionBudget>) -> u8 {
+    actions.map_or(ACTIONS_PER_TURN, |a| a.0)
+}
+
+/// Quickened일 때 추가 액션으로 할 수 있는 액션 (Haste면 Strike, Stride)
+#[derive(Component, Debug, Clone)]
+pub struct QuickenedActions(pub Vec<ActionKind>);
+
+/// Slowed, Stunned, Quickened를 반영해 턴을 시작하고 잃은 액션을 기록한다
+fn start_turn(
+    locale: Locale,
+    log: &mut CombatLog,
+    name: &str,
+    sheet: &mut Sheet,
+    conditions: &[Condition],
+    (actions, quickened): (Option<&ActionBudget>, Option<&QuickenedActions>),
+) -> TurnState {
+    let quickened = quickened.map_or(&[][..], |q| &q.0);
+    let start = sheet.0.start_turn(budget(actions), conditions, quickened);
+    if start.lost > 0 {
+        log.0.push(locale.format(
+            "log.actions_lost",
+            &[("name", &name), ("lost", &start.lost)],
+        ));
+    }
+    TurnState::new(start.turn)
+}
+
+/// 미니언, 우선권 순서에 들지 않고 주인의 턴에 행동한다
+#[derive(Component)]
+pub struct MinionSheet(pub Minion<Entity>);
+
+/// 조우에 놓인 위험 요소, 활성화된 복합 위험 요소는 우선권 순서에 들어간다
+#[derive(Component)]
+pub struct HazardSheet(pub Hazard);
+
+/// 우선권 순서와 현재 턴
+#[derive(Resource, Default)]
+pub struct Initiative {
+    order: Vec<Entity>,
+    current: usize,
+    round: u32,
+    /// 현재 참가자의 턴에 함께 행동하는 미니언
+    minions: Vec<Entity>,
+}
+
+impl Initiative {
+    pub fn active(&self) -> Option<Entity> {
+        self.order.get(self.current).copied()
+    }
+
+    /// 현재 턴의 참가자나 그 미니언
+    pub fn is_acting(&self, entity: Entity) -> bool {
+        self.active() == Some(entity) || self.minions.contains(&entity)
+    }
+
+    pub fn order(&self) -> &[Entity] {
+        &self.order
+    }
+
+    pub fn round(&self) -> u32 {
+        self.round
+    }
+}
+
+/// 굴림에 쓰는 난수 생성기
+#[derive(Resource)]
+pub struct DiceRng(pub(crate) StdRng);
+
+/// 전투 기록
+#[derive(Resource, Default)]
+pub struct CombatLog(pub Vec<String>);
+
+/// 전투 기록에 쓰는 언어, 실행 중에 바꿀 수 있다
+#[derive(Resource, Default, Debug, Clone, Copy)]
+pub struct Language(pub Locale);
+
+/// 전투 지도의 조명, 기본은 모두 밝은 빛
+#[derive(Resource, Debug, Clone)]
+pub struct BattleMap(pub Lighting);
+
+impl Default for BattleMap {
+    fn default() -> Self {
+        Self(Lighting::new(LightLevel::Bright))
+    }
+}
+
+/// 참가자의 시각, 없으면 보통 시각
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Senses(pub Vision);
+
+/// 참가자 쌍마다 기록된 감지 상태, 조명은 `BattleMap`과 `Senses`로 따로 합친다
+#[derive(Resource, Default)]
+pub struct Detection(pub Awareness<Entity>);
+
+/// 현재 턴 참가자의 Strike
+#[derive(Message, Debug, Clone, Copy)]
+pub struct StrikeCommand {
+    pub attacker: Entity,
+    pub target: Entity,
+}
+
+/// 현재 턴 참가자의 Stride
+#[derive(Message, Debug, Clone, Copy)]
+pub struct StrideCommand {
+    pub mover: Entity,
+    pub to: GridPos,
+}
+
+/// 현재 턴 참가자의 Courageous Anthem (1 액션)
+#[derive(Message, Debug, Clone, Copy)]
+pub struct AnthemCommand {
+    pub performer: Entity,
+}
+
+/// 현재 턴 참가자의 Raise a Shield (1 액션)
+#[derive(Message, Debug, Clone, Copy)]
+pub struct RaiseShieldCommand {
+    pub holder: Entity,
+}
+
+/// 현재 턴 참가자의 Rage (1 액션, Barbarian)
+#[derive(Message, Debug, Clone, Copy)]
+pub struct RageCommand {
+    pub barbarian: Entity,
+}
+
+/// 현재 턴 참가자의 전투 기동 (1 액션)
+#[derive(Message, Debug, Clone, Copy)]
+pub struct ManeuverCommand {
+    pub attacker: Entity,
+    pub target: Entity,
+    pub maneuver: Maneuver,
+}
+
+/// 현재 턴 참가자의 위험 요소 해제 (2 액션)
+#[derive(Message, Debug, Clone, Copy)]
+pub struct DisableCommand {
+    pub actor: Entity,
+    pub hazard: Entity,
+    pub skill: Skill,
+}
+
+/// 현재 턴 참가자가 미니언에게 명령 (1 액션)
+#[derive(Message, Debug, Clone, Copy)]
+pub struct MinionCommand {
+    pub master: Entity,
+    pub minion: Entity,
+}
+
+/// 현재 턴 참가자의 Hide (1 액션)
+#[derive(Message, Debug, Clone, Copy)]
+pub struct HideCommand {
+    pub hider: Entity,
+}
+
+/// 현재 턴 참가자의 Sneak (1 액션), 이동력 절반까지
+#[derive(Message, Debug, Clone, Copy)]
+pub struct SneakCommand {
+    pub sneaker: Entity,
+    pub to: GridPos,
+}
+
+/// 현재 턴 참가자의 Seek (1 액션)
+#[derive(Message, Debug, Clone, Copy)]
+pub struct SeekCommand {
+    pub seeker: Entity,
+}
+
+/// 현재 턴 종료
+#[derive(Message, Debug, Clone, Copy)]
+pub struct EndTurnCommand;
+
+/// 규칙 엔진을 Bevy 상태와 시스템으로 감싼 플러그인
+///
+/// 렌더링을 하지 않으므로 `MinimalPlugins`와 함께 헤드리스로 실행할 수 있다.
+pub struct PfOtariPlugin {
+    pub seed: u64,
+}
+
+impl Default for PfOtariPlugin {
+    fn default() -> Self {
+        Self {
+            seed: rand::random(),
+        }
+    }
+}
+
+impl Plugin for PfOtariPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<StatesPlugin>() {
+            app.add_plugins(StatesPlugin);
+        }
+
+        app.init_state::<EncounterState>()
+            .init_resource::<Initiative>()
+            .init_resource::<CombatLog>()
+            .init_resource::<Language>()
+            .init_resource::<BattleMap>()
+            .init_resource::<Detection>()
+            .insert_resource(DiceRng(StdRng::seed_from_u64(self.seed)))
+            .add_message::<StrikeCommand>()
+            .add_message::<StrideCommand>()
+            .add_message::<AnthemCommand>()
+            .add_message::<RaiseShieldCommand>()
+            .add_message::<RageCommand>()
+            .add_message::<ManeuverCommand>()
+            .add_message::<DisableCommand>()
+            .add_message::<MinionCommand>()
+            .add_message::<HideCommand>()
+            .add_message::<SneakCommand>()
+            .add_message::<SeekCommand>()
+            .add_message::<EndTurnCommand>()
+            .add_systems(
+                Update,
+                begin_encounter.run_if(in_state(EncounterState::Setup)),
+            )
+            .add_systems(OnEnter(EncounterState::Combat), roll_initiative)
+            .add_systems(
+                Update,
+                (
+                    hazard_turns,
+                    auto_play,
+                    handle_strides,
+                    handle_anthems,
+                    handle_raise_shields,
+                    handle_rages,
+                    handle_minion_commands,
+                    handle_maneuvers,
+                    handle_disables,
+                    handle_hides,
+                    handle_sneaks,
+                    handle_seeks,
+                    handle_strikes,
+                    check_victory,
+                    handle_end_turn,
+                )
+                    .chain()
+                    .run_if(in_state(EncounterState::Combat)),
+            );
+    }
+}
+
+/// 한 손 무기를 쥐여 준다
+fn armed(mut character: Character, weapon: Weapon) -> Character {
+    character
+        .carry(Item::weapon(weapon, Bulk::new(1), 1), ItemState::Held)
+        .expect("무기 하나는 Bulk 한도 안에 든다");
+    character
+}
+
+/// 1 대 1 예제 조우: 전사와 고블린
+pub fn spawn_demo_encounter(mut commands: Commands) {
+    let stats = |strength, dexterity, constitution| Attribute {
+        strength,
+        dexterity,
+        constitution,
+        intelligence: 10,
+        wisdom: 12,
+        charisma: 10,
+    };
+
+    commands.spawn((
+        Name::new("Fighter"),
+        Sheet(armed(
+            Character::new(
+                10,
+                stats(16, 12, 14),
+                Defense {
+                    armor_item: 4,
+                    armor_proficiency: Proficiency::Trained,
+                },
+                Proficiency::Trained,
+            )
+            .with_class(Class::Fighter),
+            Weapon {
+                name: "Longsword".to_string(),
+                damage: Dice::new(1, 8),
+                agile: false,
+            },
+        )),
+        Faction(Side::Party),
+        GridPosition(GridPos::new(1, 3)),
+        Shield,
+    ));
+
+    commands.spawn((
+        Name::new("Goblin"),
+        Sheet(armed(
+            Character::new(
+                6,
+                stats(10, 16, 12),
+                Defense {
+                    armor_item: 2,
+                    armor_proficiency: Proficiency::Trained,
+                },
+                Proficiency::Trained,
+            ),
+            Weapon {
+                name: "Dogslicer".to_string(),
+                damage: Dice::new(1, 6),
+                agile: true,
+            },
+        )),
+        Faction(Side::Creatures),
+        GridPosition(GridPos::new(6, 3)),
+        AutoPlay,
+    ));
+}
+
+fn begin_encounter(
+    combatants: Query<&Faction, With<Sheet>>,
+    mut next_state: ResMut<NextState<EncounterState>>,
+) {
+    let has = |side| combatants.iter().any(|f| f.0 == side);
+    if has(Side::Party) && has(Side::Creatures) {
+        next_state.set(EncounterState::Combat);
+    }
+}
+
+type Roller<'a> = (
+    Entity,
+    &'a mut Sheet,
+    Option<&'a Name>,
+    Option<&'a ActionBudget>,
+    Option<&'a mut MinionSheet>,
+    Option<&'a QuickenedActions>,
+);
+
+fn roll_initiative(
+    mut commands: Commands,
+    mut combatants: Query<Roller>,
+    hazards: Query<(Entity, &HazardSheet, Option<&Name>)>,
+    mut initiative: ResMut<Initiative>,
+    mut rng: ResMut<DiceRng>,
+    mut log: ResMut<CombatLog>,
+    language: Res<Language>,
+) {
+    let mut rolls: Vec<(Entity, i32)> = Vec::new();
+    for (entity, sheet, name, actions, minion, _) in &mut combatants {
+        // 재주의 상시 보정은 조우 내내 유지된다
+        let mut effects = Effects::default();
+        for effect in feat_effects(&sheet.0) {
+            effects.0.add(effect);
+        }
+        // 미니언은 우선권을 굴리지 않는다
+        if let Some(mut minion) = minion {
+            let turn = minion.0.start_master_turn();
+            commands
+                .entity(entity)
+                .insert((TurnState::new(turn), effects));
+            continue;
+        }
+
+        let total = roll_d20(&mut rng.0) as i32
+            + sheet.0.perception_bonus()
+            + effects.0.modifier(Statistic::Initiative);
+        log.0.push(language.0.format(
+            "log.initiative",
+            &[("name", &display_name(name, entity)), ("total", &total)],
+        ));
+        rolls.push((entity, total));
+        commands.entity(entity).insert((
+            TurnState::new(CombatTurn::with_actions(budget(actions))),
+            effects,
+        ));
+    }
+    // 이미 작동한 복합 위험 요소도 Stealth로 우선권을 굴린다
+    for (entity, hazard, name) in &hazards {
+        if hazard.0.state() != HazardState::Active {
+            continue;
+        }
+        let total = hazard.0.roll_initiative(&mut rng.0);
+        log.0.push(language.0.format(
+            "log.initiative",
+            &[("name", &display_name(name, entity)), ("total", &total)],
+        ));
+        rolls.push((entity, total));
+    }
+    rolls.sort_by_key(|(_, total)| Reverse(*total));
+
+    let order: Vec<Entity> = rolls.into_iter().map(|(entity, _)| entity).collect();
+    // 첫 참가자는 바로 턴을 시작한다, 조우 전에 걸린 Stunned가 적용된다
+    if let Some(&first) = order.first()
+        && let Ok((_, mut sheet, name, actions, _, quickened)) = combatants.get_mut(first)
+    {
+        let name = display_name(name, first);
+        let state = start_turn(
+            language.0,
+            &mut log,
+            &name,
+            &mut sheet,
+            &[],
+            (actions, quickened),
+        );
+        commands.entity(first).insert(state);
+    }
+    let minions = combatants
+        .iter()
+        .filter_map(|(entity, _, _, _, minion, _)| {
+            minion
+                .filter(|m| Some(&m.0.master) == order.first())
+                .map(|_| entity)
+        })
+        .collect();
+    *initiative = Initiative {
+        order,
+        current: 0,
+        round: 1,
+        minions,
+    };
+}
+
+fn display_name(name: Option<&Name>, entity: Entity) -> String {
+    name.map_or_else(|| format!("{entity}"), |n| n.as_str().to_string())
+}
+
+/// 행동한 참가자 이름을 붙인 규칙 위반 기록
+fn error_line(locale: Locale, name: &str, error: &RulesError) -> String {
+    locale.format(
+        "log.error",
+        &[("name", &name), ("error", &error.localize(locale))],
+    )
+}
+
+/// 전술 판단에 보이는 참가자
+type Visible<'a> = (
+    Entity,
+    &'a Faction,
+    &'a GridPosition,
+    &'a Sheet,
+    Option<&'a Effects>,
+    Option<&'a ActionBudget>,
+    Option<&'a QuickenedActions>,
+);
+
+/// 자동 행동 참가자의 턴 상태, 방패, 전술
+type Automaton<'a> = (&'a TurnState, Option<&'a Shield>, Option<&'a Tactician>);
+
+/// 손에 든 무기가 없으면 전술 판단에서 빠진다
+fn combatant_view(
+    (entity, _, position, sheet, effects, actions, quickened): Visible,
+) -> Option<CombatantView<Entity>> {
+    let armament = Armament::of(&sheet.0).ok()?;
+    let modifier = effects.map_or(0, |e| e.0.modifier(Statistic::ArmorClass));
+    let mut conditions = sheet.0.conditions();
+    conditions.extend(effects.map_or(Vec::new(), |e| e.0.conditions()));
+    let quickened = quickened.map_or(&[][..], |q| &q.0);
+    Some(CombatantView {
+        id: entity,
+        position: position.0,
+        speed: sheet.0.speed(),
+        ac: sheet.0.ac() + modifier,
+        hp: sheet.current_hp(),
+        weapon: armament.weapon,
+        attack_bonus: armament.attack_bonus,
+        damage_bonus: armament.damage_bonus,
+        next_turn: CombatTurn::start(budget(actions), &conditions, quickened).turn,
+    })
+}
+
+/// 명령을 낸 참가자, 라운드, 그때의 액션 칸
+type Issued = (Entity, u32, Vec<ActionSlot>);
+
+/// `AutoPlay` 참가자는 프레임마다 전술이 고른 명령 하나를 낸다,
+/// 지난 명령이 거부되어 턴 상태가 그대로면 턴을 끝낸다
+#[allow(clippy::too_many_arguments)]
+fn auto_play(
+    initiative: Res<Initiative>,
+    actors: Query<Automaton, With<AutoPlay>>,
+    combatants: Query<Visible>,
+    mut issued: Local<Option<Issued>>,
+    mut strikes: MessageWriter<StrikeCommand>,
+    mut strides: MessageWriter<StrideCommand>,
+    mut shields: MessageWriter<RaiseShieldCommand>,
+    mut end_turn: MessageWriter<EndTurnCommand>,
+) {
+    let Some(active) = initiative.active() else {
+        return;
+    };
+    let (Ok((state, shield, tactician)), Ok(me)) = (actors.get(active), combatants.get(active))
+    else {
+        return;
+    };
+    let snapshot = (active, initiative.round(), state.action_slots());
+    if issued.take().as_ref() == Some(&snapshot) {
+        end_turn.write(EndTurnCommand);
+        return;
+    }
+
+    let faction = me.1.0;
+    let shield = match (shield, me.4) {
+        (None, _) => ShieldState::NoShield,
+        (Some(_), Some(effects)) if effects.0.contains("Raise a Shield") => ShieldState::Raised,
+        (Some(_), _) => ShieldState::Lowered,
+    };
+    // 무기가 없으면 할 수 있는 일이 없다
+    let Some(view) = combatant_view(me) else {
+        end_turn.write(EndTurnCommand);
+        return;
+    };
+    let situation = Situation {
+        me: view,
+        turn: state.turn.clone(),
+        strikes_made: state.strikes_made,
+        shield,
+        enemies: combatants
+            .iter()
+            .filter(|(_, f, _, sheet, ..)| f.0 != faction && sheet.is_standing())
+            .filter_map(combatant_view)
+            .collect(),
+    };
+
+    let action = match tactician {
+        Some(tactician) => tactician.0.next_action(&situation),
+        None => Aggressive::default().next_action(&situation),
+    };
+    if action != TacticalAction::EndTurn {
+        *issued = Some(snapshot);
+    }
+    match action {
+        TacticalAction::Stride(to) => {
+            strides.write(StrideCommand { mover: active, to });
+        }
+        TacticalAction::Strike(target) => {
+            strikes.write(StrikeCommand {
+                attacker: active,
+                target,
+            });
+        }
+        TacticalAction::RaiseShield => {
+            shields.write(RaiseShieldCommand { holder: active });
+        }
+        TacticalAction::EndTurn => {
+            end_turn.write(EndTurnCommand);
+        }
+    }
+}
+
+fn handle_strides(
+    mut commands: MessageReader<StrideCommand>,
+    initiative: Res<Initiative>,
+    mut log: ResMut<CombatLog>,
+    language: Res<Language>,
+    mut detection: ResMut<Detection>,
+    mut movers: Query<(
+        Entity,
+        &mut GridPosition,
+        &mut TurnState,
+        &Sheet,
+        Option<&Name>,
+    )>,
+) {
+    for command in commands.read() {
+        let occupied: Vec<GridPos> = movers
+            .iter()
+            .filter(|(entity, ..)| *entity != command.mover)
+            .map(|(_, p, ..)| p.0)
+            .collect();
+        let Ok((_, mut position, mut state, sheet, name)) = movers.get_mut(command.mover) else {
+            continue;
+        };
+        let result = stride(
+            initiative.is_acting(command.mover),
+            sheet.0.speed(),
+            &mut position,
+            &mut state,
+            (command.to, &occupied),
+        );
+        match result {
+            // Hide, Sneak, Step 외의 행동을 하면 드러난다
+            Ok(()) => detection.0.reveal(command.mover),
+            Err(error) => {
+                let name = display_name(name, command.mover);
+                log.0.push(error_line(language.0, &name, &error));
+            }
+        }
+    }
+}
+
+fn stride(
+    is_active: bool,
+    speed: i32,
+    position: &mut GridPosition,
+    state: &mut TurnState,
+    (to, occupied): (GridPos, &[GridPos]),
+) -> Result<(), RulesError> {
+    if !is_active {
+        return Err(RulesError::NotYourTurn);
+    }
+    let distance = position.0.distance_feet(to);
+    if distance > speed {
+        return Err(RulesError::TooFar { distance, speed });
+    }
+    if occupied.contains(&to) {
+        return Err(RulesError::SquareOccupied);
+    }
+    state.turn.spend_action_for(ActionKind::Stride, 1)?;
+    position.0 = to;
+    Ok(())
+}
+
+fn handle_anthems(
+    mut commands: MessageReader<AnthemCommand>,
+    initiative: Res<Initiative>,
+    mut log: ResMut<CombatLog>,
+    language: Res<Language>,
+    mut performers: Query<(&Faction, &GridPosition, &mut TurnState, Option<&Name>)>,
+    mut allies: Query<(Entity, &Faction, &GridPosition, &Sheet, &mut Effects)>,
+) {
+    for command in commands.read() {
+        let Ok((faction, position, mut state, name)) = performers.get_mut(command.performer) else {
+            continue;
+        };
+        let result = if !initiative.is_acting(command.performer) {
+            Err(RulesError::NotYourTurn)
+        } else {
+            state.turn.spend_action(1)
+        };
+        let name = display_name(name, command.performer);
+        if let Err(error) = result {
+            log.0.push(error_line(language.0, &name, &error));
+            continue;
+        }
+
+        let mut inspired = 0;
+        for (_, ally_faction, ally_pos, sheet, mut effects) in &mut allies {
+            if ally_faction == faction
+                && sheet.is_standing()
+                && position.0.distance_feet(ally_pos.0) <= ANTHEM_RANGE_FEET
+            {
+                effects
+                    .0
+                    .add(ActiveEffect::courageous_anthem(command.performer));
+                inspired += 1;
+            }
+        }
+        log.0.push(
+            language
+                .0
+                .format("log.anthem", &[("name", &name), ("count", &inspired)]),
+        );
+    }
+}
+
+fn handle_raise_shields(
+    mut commands: MessageReader<RaiseShieldCommand>,
+    initiative: Res<Initiative>,
+    mut log: ResMut<CombatLog>,
+    language: Res<Language>,
+    mut holders: Query<(&mut TurnState, &mut Effects, Option<&Shield>, Option<&Name>)>,
+) {
+    for command in commands.read() {
+        let Ok((mut state, mut effects, shield, name)) = holders.get_mut(command.holder) else {
+            continue;
+        };
+        let result = if !initiative.is_acting(command.holder) {
+            Err(RulesError::NotYourTurn)
+        } else if shield.is_none() {
+            Err(RulesError::NoShield)
+        } else {
+            state.turn.spend_action_for(ActionKind::RaiseShield, 1)
+        };
+
+        let name = display_name(name, command.holder);
+        match result {
+            Ok(()) => {
+                effects.0.add(ActiveEffect::raised_shield(command.holder));
+                log.0.push(language.0.format(
+                    "log.raise_shield",
+                    &[("name", &name), ("bonus", &SHIELD_AC_BONUS)],
+                ));
+            }
+            Err(error) => log.0.push(error_line(language.0, &name, &error)),
+        }
+    }
+}
+
+fn handle_rages(
+    mut commands: MessageReader<RageCommand>,
+    initiative: Res<Initiative>,
+    mut log: ResMut<CombatLog>,
+    language: Res<Language>,
+    mut barbarians: Query<(&mut Sheet, &mut TurnState, &mut Effects, Option<&Name>)>,
+) {
+    for command in commands.read() {
+        let Ok((mut sheet, mut state, mut effects, name)) = barbarians.get_mut(command.barbarian)
+        else {
+            continue;
+        };
+        let result = if !initiative.is_acting(command.barbarian) {
+            Err(RulesError::NotYourTurn)
+        } else {
+            // 액션이 모자라면 임시 HP도 얻지 않는다
+            let mut turn = state.turn.clone();
+            let result = turn
+                .spend_action(1)
+                .and_then(|()| rage(command.barbarian, &mut sheet.0));
+            if result.is_ok() {
+                state.turn = turn;
+            }
+            result
+        };
+
+        let name = display_name(name, command.barbarian);
+        match result {
+            Ok(effect) => {
+                effects.0.add(effect);
+                log.0.push(language.0.format(
+                    "log.rage",
+                    &[("name", &name), ("temp_hp", &sheet.0.temp_hp())],
+                ));
+            }
+            Err(error) => log.0.push(error_line(language.0, &name, &error)),
+        }
+    }
+}
+
+/// 위험 요소의 공격 하나를 대상에게 적용하고 기록한다
+fn hazard_attack(
+    locale: Locale,
+    hazard: &str,
+    attack: &HazardAttack,
+    (sheet, target): (&mut Sheet, &str),
+    rng: &mut StdRng,
+    log: &mut CombatLog,
+) {
+    let was_standing = sheet.is_standing();
+    match attack.resolve(&mut sheet.0, rng) {
+        Ok(hit) => log.0.push(locale.format(
+            "log.hazard_attack",
+            &[
+                ("hazard", &hazard),
+                ("target", &target),
+                ("degree", &hit.degree.localize(locale)),
+                ("damage", &hit.damage),
+            ],
+        )),
+        Err(error) => log.0.push(error_line(locale, hazard, &error)),
+    }
+    if was_standing && !sheet.is_standing() {
+        log.0.push(locale.format(
+            "log.knocked_out",
+            &[
+                ("name", &target),
+                ("dying", &Condition::Dying(sheet.0.dying()).localize(locale)),
+            ],
+        ));
+    }
+}
+
+/// 활성화된 복합 위험 요소는 자기 턴에 파티의 선 참가자들을 차례로 공격하고 턴을 마친다
+fn hazard_turns(
+    initiative: Res<Initiative>,
+    hazards: Query<(&HazardSheet, Option<&Name>)>,
+    mut targets: Query<(Entity, &mut Sheet, &Faction, Option<&Name>)>,
+    mut rng: ResMut<DiceRng>,
+    mut log: ResMut<CombatLog>,
+    language: Res<Language>,
+    mut end_turn: MessageWriter<EndTurnCommand>,
+) {
+    let Some(active) = initiative.active() else {
+        return;
+    };
+    let Ok((hazard, name)) = hazards.get(active) else {
+        return;
+    };
+    let hazard_name = display_name(name, active);
+
+    if let Some(routine) = hazard.0.routine() {
+        for action in 0..routine.actions as usize {
+            let standing: Vec<Entity> = targets
+                .iter()
+                .filter(|(_, sheet, faction, _)| faction.0 == Side::Party && sheet.is_standing())
+                .map(|(entity, ..)| entity)
+                .collect();
+            if standing.is_empty() {
+                break;
+            }
+            let victim = standing[action % standing.len()];
+            let (_, mut sheet, _, target) = targets.get_mut(victim).expect("방금 찾은 참가자");
+            let target = display_name(target, victim);
+            hazard_attack(
+                language.0,
+                &hazard_name,
+                &routine.attack,
+                (&mut sheet, &target),
+                &mut rng.0,
+                &mut log,
+            );
+        }
+    }
+    end_turn.write(EndTurnCommand);
+}
+
+fn handle_minion_commands(
+    mut commands: MessageReader<MinionCommand>,
+    initiative: Res<Initiative>,
+    mut log: ResMut<CombatLog>,
+    language: Res<Language>,
+    mut turns: Query<(&mut TurnState, Option<&Name>)>,
+    mut minions: Query<&mut MinionSheet>,
+) {
+    for command in commands.read() {
+        let Ok(mut minion) = minions.get_mut(command.minion) else {
+            continue;
+        };
+        let Ok([(mut master, name), (mut state, minion_name)]) =
+            turns.get_many_mut([command.master, command.minion])
+        else {
+            continue;
+        };
+        let name = display_name(name, command.master);
+        let result = if initiative.active() != Some(command.master) {
+            Err(RulesError::NotYourTurn)
+        } else {
+            minion.0.command(&command.master, &mut master.turn)
+        };
+
+        match result {
+            Ok(turn) => {
+                *state = TurnState::new(turn);
+                log.0.push(language.0.format(
+                    "log.command",
+                    &[
+                        ("name", &name),
+                        ("minion", &display_name(minion_name, command.minion)),
+                        ("actions", &state.remaining_actions()),
+                    ],
+                ));
+            }
+            Err(error) => log.0.push(error_line(language.0, &name, &error)),
+        }
+    }
+}
+
+/// 기동을 하거나 받는 참가자
+type Grappler<'a> = (
+    &'a mut Sheet,
+    &'a mut GridPosition,
+    Option<&'a mut TurnState>,
+    Option<&'a mut Effects>,
+    Option<&'a Name>,
+);
+
+/// 판정까지 한다, 공격 특성 기동은 이번 턴 공격 횟수를 늘린다
+fn attempt_maneuver(
+    command: &ManeuverCommand,
+    is_active: bool,
+    (attacker, attacker_pos, state): (&Character, GridPos, Option<Mut<TurnState>>),
+    (target, target_pos): (&Character, GridPos),
+    rng: &mut StdRng,
+) -> Result<ManeuverResult, RulesError> {
+    let Some(mut state) = state.filter(|_| is_active) else {
+        return Err(RulesError::NotYourTurn);
+    };
+    if target.current_hp() <= 0 {
+        return Err(RulesError::TargetUnconscious);
+    }
+    if !attacker_pos.is_adjacent(target_pos) {
+        return Err(RulesError::OutOfReach);
+    }
+    state.turn.spend_action_for(ActionKind::Skill, 1)?;
+    let dc = target.defense_dc(command.maneuver.defense());
+    Ok(command
+        .maneuver
+        .attempt(attacker, dc, &mut state.strikes_made, rng))
+}
+
+/// 걸린 상태는 시도한 쪽의 다음 턴 시작까지 간다
+fn maneuver_conditions(
+    command: &ManeuverCommand,
+    conditions: &[Condition],
+    effects: Option<Mut<Effects>>,
+) {
+    let Some(mut effects) = effects.filter(|_| !conditions.is_empty()) else {
+        return;
+    };
+    let effect = conditions.iter().fold(
+        ActiveEffect::new(
+            command.maneuver.name(),
+            EffectDuration::UntilStartOfTurn(command.attacker),
+        ),
+        |effect, condition| effect.with_condition(*condition),
+    );
+    effects.0.add(effect);
+}
+
+fn handle_maneuvers(
+    mut commands: MessageReader<ManeuverCommand>,
+    initiative: Res<Initiative>,
+    mut rng: ResMut<DiceRng>,
+    mut log: ResMut<CombatLog>,
+    language: Res<Language>,
+    mut combatants: Query<Grappler>,
+) {
+    let locale = language.0;
+    for command in commands.read() {
+        if command.attacker == command.target {
+            let name = combatants.get(command.attacker).ok().and_then(|c| c.4);
+            let name = display_name(name, command.attacker);
+            log.0
+                .push(error_line(locale, &name, &RulesError::SelfTarget));
+            continue;
+        }
+        let occupied: Vec<GridPos> = combatants.iter().map(|(_, p, ..)| p.0).collect();
+        let Ok([attacker, target]) = combatants.get_many_mut([command.attacker, command.target])
+        else {
+            continue;
+        };
+        let (attacker_sheet, attacker_pos, state, attacker_effects, attacker_name) = attacker;
+        let (mut target_sheet, mut target_pos, _, target_effects, target_name) = target;
+        let attacker_name = display_name(attacker_name, command.attacker);
+        let target_name = display_name(target_name, command.target);
+
+        let result = attempt_maneuver(
+            command,
+            initiative.is_acting(command.attacker),
+            (&attacker_sheet.0, attacker_pos.0, state),
+            (&target_sheet.0, target_pos.0),
+            &mut rng.0,
+        );
+        let ManeuverResult { outcome, effects } = match result {
+            Ok(result) => result,
+            Err(error) => {
+                log.0.push(error_line(locale, &attacker_name, &error));
+                continue;
+            }
+        };
+
+        log.0.push(locale.format(
+            "log.maneuver",
+            &[
+                ("attacker", &attacker_name),
+                ("target", &target_name),
+                ("maneuver", &command.maneuver.name()),
+                ("roll", &outcome.natural),
+                (
+                    "bonus",
+                    &format!("{:+}", outcome.total - outcome.natural as i32),
+                ),
+                ("degree", &outcome.degree.localize(locale)),
+                ("effect", &effects.localize(locale)),
+            ],
+        ));
+
+        if effects.push_feet > 0 {
+            let to = target_pos
+                .0
+                .push_away_clear(attacker_pos.0, effects.push_feet, &occupied);
+            let feet = target_pos.0.distance_feet(to);
+            target_pos.0 = to;
+            log.0
+                .push(locale.format("log.pushed", &[("name", &target_name), ("feet", &feet)]));
+        }
+        maneuver_conditions(command, &effects.target, target_effects);
+        maneuver_conditions(command, &effects.attacker, attacker_effects);
+        if let Some(dice) = effects.damage {
+            // Strike가 아니므로 치명타 Dying 2는 없다
+            let damage = dice.roll(&mut rng.0);
+            apply_damage(
+                locale,
+                &mut target_sheet,
+                damage,
+                DegreeOfSuccess::Success,
+                &target_name,
+                &mut log,
+            );
+        }
+    }
+}
+
+fn handle_disables(
+    mut commands: MessageReader<DisableCommand>,
+    mut initiative: ResMut<Initiative>,
+    mut rng: ResMut<DiceRng>,
+    mut log: ResMut<CombatLog>,
+    language: Res<Language>,
+    mut actors: Query<(&mut Sheet, &mut TurnState, Option<&Name>)>,
+    mut hazards: Query<(&mut HazardSheet, Option<&Name>)>,
+) {
+    let locale = language.0;
+    for command in commands.read() {
+        let (Ok((mut sheet, mut state, name)), Ok((mut hazard, hazard_name))) = (
+            actors.get_mut(command.actor),
+            hazards.get_mut(command.hazard),
+        ) else {
+            continue;
+        };
+        let name = display_name(name, command.actor);
+        let hazard_name = display_name(hazard_name, command.hazard);
+        let was_armed = hazard.0.state() == HazardState::Armed;
+
+        let result = if !initiative.is_acting(command.actor) {
+            Err(RulesError::NotYourTurn)
+        } else {
+            // 액션이 모자라면 판정하지 않는다
+            let mut turn = state.turn.clone();
+            let result = turn
+                .spend_action_for(ActionKind::Skill, DISABLE_ACTIONS)
+                .and_then(|()| hazard.0.disable(&sheet.0, command.skill, &mut rng.0));
+            if result.is_ok() {
+                state.turn = turn;
+            }
+            result
+        };
+
+        let args: [(&str, &dyn std::fmt::Display); 2] = [("name", &name), ("hazard", &hazard_name)];
+        match result {
+            Ok((_, DisableResult::Disabled)) => {
+                log.0.push(locale.format("log.hazard_disabled", &args));
+            }
+            Ok((_, DisableResult::Progress { remaining })) => {
+                log.0.push(locale.format(
+                    "log.hazard_progress",
+                    &[
+                        ("name", &name),
+                        ("hazard", &hazard_name),
+                        ("remaining", &remaining),
+                    ],
+                ));
+            }
+            Ok((_, DisableResult::NoEffect)) => {
+                log.0.push(locale.format("log.hazard_no_effect", &args));
+            }
+            Ok((_, DisableResult::Triggered)) => {
+                log.0.push(locale.format("log.hazard_triggered", &args));
+                if was_armed {
+                    hazard_attack(
+                        locale,
+                        &hazard_name,
+                        &hazard.0.trigger,
+                        (&mut sheet, &name),
+                        &mut rng.0,
+                        &mut log,
+                    );
+                }
+                // 전투 중에 작동한 복합 위험 요소는 우선권 순서 끝에 들어간다
+                if hazard.0.state() == HazardState::Active
+                    && !initiative.order.contains(&command.hazard)
+                {
+                    initiative.order.push(command.hazard);
+                }
+            }
+            Err(error) => log.0.push(error_line(locale, &name, &error)),
+        }
+    }
+}
+
+/// Hide, Sneak, Seek에서 읽는 컴포넌트
+type Lurker<'a> = (
+    Entity,
+    &'a mut GridPosition,
+    &'a mut TurnState,
+    &'a Sheet,
+    &'a Faction,
+    Option<&'a Senses>,
+    Option<&'a Name>,
+);
+
+/// 1 액션 기술 행동의 턴, 판정이 끝난 뒤에 원래 턴과 바꾼다
+fn skill_action_turn(is_active: bool, state: &TurnState) -> Result<CombatTurn, RulesError> {
+    if !is_active {
+        return Err(RulesError::NotYourTurn);
+    }
+    let mut turn = state.turn.clone();
+    turn.spend_action_for(ActionKind::Skill, 1)?;
+    Ok(turn)
+}
+
+/// `at`에 있는 `actor`를 보는, 서 있는 상대 진영 참가자
+fn observers(
+    actor: Entity,
+    at: GridPos,
+    battle_map: &BattleMap,
+    lurkers: &Query<Lurker>,
+) -> Vec<Observer<Entity>> {
+    let Ok((.., faction, _, _)) = lurkers.get(actor) else {
+        return Vec::new();
+    };
+    let light = battle_map.0.light_at(at);
+    lurkers
+        .iter()
+        .filter(|(_, _, _, sheet, other, ..)| *other != faction && sheet.is_standing())
+        .map(|(observer, _, _, sheet, _, senses, _)| {
+            let vision = senses.map_or(Vision::Normal, |s| s.0);
+            Observer::new(observer, sheet.0.perception_dc(), vision.sight(light))
+        })
+        .collect()
+}
+
+/// 판정마다 한 줄, 판정 뒤의 감지 상태를 붙인다
+fn log_detection(
+    locale: Locale,
+    log: &mut CombatLog,
+    (actor, action): (Entity, &str),
+    results: &[(Entity, CheckOutcome)],
+    lurkers: &Query<Lurker>,
+    state: impl Fn(Entity) -> DetectionState,
+) {
+    let name =
+        |entity: Entity| display_name(lurkers.get(entity).ok().and_then(|(.., name)| name), entity);
+    for (other, outcome) in results {
+        log.0.push(locale.format(
+            "log.detection",
+            &[
+                ("actor", &name(actor)),
+                ("other", &name(*other)),
+                ("action", &action),
+                ("roll", &outcome.natural),
+                (
+                    "bonus",
+                    &format!("{:+}", outcome.total - outcome.natural as i32),
+                ),
+                ("degree", &outcome.degree.localize(locale)),
+                ("state", &state(*other).localize(locale)),
+            ],
+        ));
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn handle_hides(
+    mut commands: MessageReader<HideCommand>,
+    initiative: Res<Initiative>,
+    mut rng: ResMut<DiceRng>,
+    mut log: ResMut<CombatLog>,
+    language: Res<Language>,
+    battle_map: Res<BattleMap>,
+    mut detection: ResMut<Detection>,
+    mut lurkers: Query<Lurker>,
+) {
+    let locale = language.0;
+    for command in commands.read() {
+        let Ok((_, position, state, sheet, .., name)) = lurkers.get(command.hider) else {
+            continue;
+        };
+        let observers = observers(command.hider, position.0, &battle_map, &lurkers);
+        let result =
+            skill_action_turn(initiative.is_acting(command.hider), state).and_then(|turn| {
+                let results = detection
+                    .0
+                    .hide(command.hider, &sheet.0, &observers, &mut rng.0)?;
+                Ok((turn, results))
+            });
+        let (turn, results) = match result {
+            Ok(resolved) => resolved,
+            Err(error) => {
+                let name = display_name(name, command.hider);
+                log.0.push(error_line(locale, &name, &error));
+                continue;
+            }
+        };
+
+        log_detection(
+            locale,
+            &mut log,
+            (command.hider, locale.text("action.hide")),
+            &results,
+            &lurkers,
+            |observer| detection.0.state(observer, command.hider),
+        );
+        if let Ok((_, _, mut state, ..)) = lurkers.get_mut(command.hider) {
+            state.turn = turn;
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn handle_sneaks(
+    mut commands: MessageReader<SneakCommand>,
+    initiative: Res<Initiative>,
+    mut rng: ResMut<DiceRng>,
+    mut log: ResMut<CombatLog>,
+    language: Res<Language>,
+    battle_map: Res<BattleMap>,
+    mut detection: ResMut<Detection>,
+    mut lurkers: Query<Lurker>,
+) {
+    let locale = language.0;
+    for command in commands.read() {
+        let Ok((_, position, state, sheet, .., name)) = lurkers.get(command.sneaker) else {
+            continue;
+        };
+        // 관찰자의 시각은 도착한 칸 기준
+        let observers = observers(command.sneaker, command.to, &battle_map, &lurkers);
+        let result =
+            skill_action_turn(initiative.is_acting(command.sneaker), state).and_then(|turn| {
+                let results = detection.0.sneak(
+                    command.sneaker,
+                    &sheet.0,
+                    (position.0, command.to),
+                    &observers,
+                    &mut rng.0,
+                )?;
+                Ok((turn, results))
+            });
+        let (turn, results) = match result {
+            Ok(resolved) => resolved,
+            Err(error) => {
+                let name = display_name(name, command.sneaker);
+                log.0.push(error_line(locale, &name, &error));
+                continue;
+            }
+        };
+
+        log_detection(
+            locale,
+            &mut log,
+            (command.sneaker, locale.text("action.sneak")),
+            &results,
+            &lurkers,
+            |observer| detection.0.state(observer, command.sneaker),
+        );
+        if let Ok((_, mut position, mut state, ..)) = lurkers.get_mut(command.sneaker) {
+            position.0 = command.to;
+            state.turn = turn;
+        }
+    }
+}
+
+fn handle_seeks(
+    mut commands: MessageReader<SeekCommand>,
+    initiative: Res<Initiative>,
+    mut rng: ResMut<DiceRng>,
+    mut log: ResMut<CombatLog>,
+    language: Res<Language>,
+    mut detection: ResMut<Detection>,
+    mut lurkers: Query<Lurker>,
+) {
+    let locale = language.0;
+    for command in commands.read() {
+        let Ok((_, position, state, sheet, faction, _, name)) = lurkers.get(command.seeker) else {
+            continue;
+        };
+        let turn = match skill_action_turn(initiative.is_acting(command.seeker), state) {
+            Ok(turn) => turn,
+            Err(error) => {
+                let name = display_name(name, command.seeker);
+                log.0.push(error_line(locale, &name, &error));
+                continue;
+            }
+        };
+        let targets: Vec<_> = lurkers
+            .iter()
+            .filter(|(_, _, _, other_sheet, other, ..)| {
+                *other != faction && other_sheet.is_standing()
+            })
+            .map(|(target, at, _, other_sheet, ..)| (target, &other_sheet.0, at.0))
+            .collect();
+        let results = detection
+            .0
+            .seek(command.seeker, &sheet.0, position.0, &targets, &mut rng.0);
+
+        log_detection(
+            locale,
+            &mut log,
+            (command.seeker, locale.text("action.seek")),
+            &results,
+            &lurkers,
+            |target| detection.0.state(command.seeker, target),
+        );
+        if let Ok((_, _, mut state, ..)) = lurkers.get_mut(command.seeker) {
+            state.turn = turn;
+        }
+    }
+}
+
+/// Strike를 하는 쪽에서 읽는 컴포넌트
+type Attacker<'a> = (
+    &'a GridPosition,
+    &'a mut TurnState,
+    Option<&'a Effects>,
+    Option<&'a Name>,
+    Option<&'a Senses>,
+);
+
+/// Strike를 받는 쪽에서 읽는 컴포넌트
+type Target<'a> = (
+    Entity,
+    &'a mut Sheet,
+    &'a GridPosition,
+    &'a Faction,
+    Option<&'a Effects>,
+    Option<&'a Name>,
+);
+
+fn modifier(effects: Option<&Effects>, statistic: Statistic) -> i32 {
+    effects.map_or(0, |e| e.0.modifier(statistic))
+}
+
+fn is_off_guard(effects: Option<&Effects>) -> bool {
+    effects.is_some_and(|e| e.0.conditions().contains(&Condition::OffGuard))
+}
+
+/// 효과를 포함한 AC, Off-Guard면 -2
+fn effective_ac(sheet: &Sheet, effects: Option<&Effects>, off_guard: bool) -> i32 {
+    let penalty = if off_guard { OFF_GUARD_AC_PENALTY } else { 0 };
+    sheet.0.ac() + modifier(effects, Statistic::ArmorClass) - penalty
+}
+
+/// Strike 한 번의 굴림, 명중 보너스, 판정, 피해
+fn roll_strike(
+    rng: &mut StdRng,
+    armament: &Armament,
+    effects: Option<&Effects>,
+    map: i32,
+    target_ac: i32,
+    precision: Option<Dice>,
+) -> Result<(u8, i32, DegreeOfSuccess, i32), RulesError> {
+    let attack_bonus = armament.attack_bonus + modifier(effects, Statistic::AttackRolls) + map;
+    let roll = roll_d20(rng);
+    let degree = strike(roll, attack_bonus, target_ac)?;
+    let dice_roll = armament.weapon.damage.roll(rng) + precision.map_or(0, |d| d.roll(rng));
+    let damage = calc_damage(
+        degree,
+        dice_roll,
+        armament.damage_bonus + modifier(effects, Statistic::Damage),
+    );
+    Ok((roll, attack_bonus, degree, damage))
+}
+
+/// Strike 한 번의 기록
+fn strike_line(
+    locale: Locale,
+    (attacker, target, weapon): (&str, &str, &str),
+    (roll, attack_bonus, degree, damage): (u8, i32, DegreeOfSuccess, i32),
+    sneak_attack: bool,
+) -> String {
+    let line = locale.format(
+        "log.strike",
+        &[
+            ("attacker", &attacker),
+            ("target", &target),
+            ("weapon", &weapon),
+            ("roll", &roll),
+            ("bonus", &format!("{attack_bonus:+}")),
+            ("degree", &degree.localize(locale)),
+            ("damage", &damage),
+        ],
+    );
+    if sneak_attack {
+        line + locale.text("log.sneak_attack")
+    } else {
+        line
+    }
+}
+
+/// 판정과 피해를 굴린다, 피해는 아직 적용하지 않는다
+fn resolve_strike(
+    locale: Locale,
+    command: &StrikeCommand,
+    initiative: &Initiative,
+    rng: &mut StdRng,
+    attackers: &mut Query<Attacker>,
+    targets: &Query<Target>,
+    (battle_map, detection): (&BattleMap, &Awareness<Entity>),
+) -> Result<(String, i32, DegreeOfSuccess), RulesError> {
+    let target_vision = attackers
+        .get(command.target)
+        .ok()
+        .and_then(|(.., senses)| senses.map(|s| s.0))
+        .unwrap_or(Vision::Normal);
+    let (attacker_pos, mut state, effects, attacker_name, senses) = attackers
+        .get_mut(command.attacker)
+        .map_err(|_| RulesError::NotYourTurn)?;
+    let (_, sheet, target_pos, _, target_effects, target_name) = targets
+        .get(command.target)
+        .map_err(|_| RulesError::OutOfReach)?;
+
+    if !initiative.is_acting(command.attacker) {
+        return Err(RulesError::NotYourTurn);
+    }
+    if !sheet.is_standing() {
+        return Err(RulesError::TargetUnconscious);
+    }
+    if !attacker_pos.0.is_adjacent(target_pos.0) {
+        return Err(RulesError::OutOfReach);
+    }
+    let (_, attacker, ..) = targets
+        .get(command.attacker)
+        .map_err(|_| RulesError::NotYourTurn)?;
+    let armament = Armament::of(&attacker.0)?;
+    state.turn.spend_action_for(ActionKind::Strike, 1)?;
+
+    // 보이지 않는 공격자에게는 Off-Guard
+    let unseen = detection
+        .perceive(
+            command.target,
+            command.attacker,
+            target_vision,
+            battle_map.0.light_at(attacker_pos.0),
+        )
+        .off_guard();
+    let off_guard = unseen || is_off_guard(target_effects);
+    let sneak = sneak_attack(&attacker.0, &armament.weapon, off_guard);
+
+    let map = multiple_attack_penalty(state.strikes_made, armament.weapon.agile);
+    state.strikes_made += 1;
+
+    // 대상이 잘 보이지 않으면 단순 판정에 실패한 Strike는 빗나간다
+    let vision = senses.map_or(Vision::Normal, |s| s.0);
+    let seen = detection.perceive(
+        command.attacker,
+        command.target,
+        vision,
+        battle_map.0.light_at(target_pos.0),
+    );
+    if !seen.target(rng) {
+        let line = locale.format(
+            "log.flat_check_failed",
+            &[
+                ("attacker", &display_name(attacker_name, command.attacker)),
+                ("target", &display_name(target_name, command.target)),
+                ("dc", &seen.flat_check_dc().unwrap_or_default()),
+            ],
+        );
+        return Ok((line, 0, DegreeOfSuccess::Failure));
+    }
+    let target_ac = effective_ac(sheet, target_effects, off_guard);
+    let (roll, attack_bonus, degree, damage) =
+        roll_strike(rng, &armament, effects, map, target_ac, sneak)?;
+
+    Ok((
+        strike_line(
+            locale,
+            (
+                &display_name(attacker_name, command.attacker),
+                &display_name(target_name, command.target),
+                &armament.weapon.name,
+            ),
+            (roll, attack_bonus, degree, damage),
+            sneak.is_some(),
+        ),
+        damage,
+        degree,
+    ))
+}
+
+/// 피해를 받는 아군 곁의 챔피언이 리액션을 쓴다, 저항을 뺀 피해와 반격할 챔피언을 돌려준다
+fn champion_reaction(
+    locale: Locale,
+    command: &StrikeCommand,
+    damage: i32,
+    attackers: &mut Query<Attacker>,
+    targets: &Query<Target>,
+    log: &mut CombatLog,
+) -> (i32, Option<Entity>) {
+    let (Ok((.., enemy_pos, _, _, _)), Ok((_, _, ally_pos, ally_faction, _, _))) =
+        (targets.get(command.attacker), targets.get(command.target))
+    else {
+        return (damage, None);
+    };
+
+    for (champion, sheet, position, faction, _, name) in targets.iter() {
+        if champion == command.target || faction != ally_faction || !sheet.is_standing() {
+            continue;
+        }
+        let Ok((_, mut state, ..)) = attackers.get_mut(champion) else {
+            continue;
+        };
+        let Ok(reaction) = retributive_strike(
+            &sheet.0,
+            &mut state.turn,
+            (position.0, enemy_pos.0, ally_pos.0),
+            damage,
+        ) else {
+            continue;
+        };
+
+        log.0.push(locale.format(
+            "log.retributive_strike",
+            &[
+                ("name", &display_name(name, champion)),
+                ("resistance", &reaction.resistance),
+                ("damage", &damage),
+                ("taken", &reaction.damage_taken),
+            ],
+        ));
+        return (
+            reaction.damage_taken,
+            reaction.can_strike.then_some(champion),
+        );
+    }
+    (damage, None)
+}
+
+/// Strike 피해를 적용한다, 치명타로 쓰러지면 Dying 2
+fn apply_damage(
+    locale: Locale,
+    sheet: &mut Sheet,
+    damage: i32,
+    degree: DegreeOfSuccess,
+    name: &str,
+    log: &mut CombatLog,
+) {
+    let was_standing = sheet.is_standing();
+    let result = if degree == DegreeOfSuccess::CriticalSuccess {
+        sheet.0.take_critical_damage(damage)
+    } else {
+        sheet.0.take_damage(damage)
+    };
+    result.expect("calc_damage는 음수를 반환하지 않는다");
+    if was_standing && !sheet.is_standing() {
+        log.0.push(locale.format(
+            "log.knocked_out",
+            &[
+                ("name", &name),
+                ("dying", &Condition::Dying(sheet.0.dying()).localize(locale)),
+            ],
+        ));
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn handle_strikes(
+    mut commands: MessageReader<StrikeCommand>,
+    initiative: Res<Initiative>,
+    mut rng: ResMut<DiceRng>,
+    mut log: ResMut<CombatLog>,
+    language: Res<Language>,
+    battle_map: Res<BattleMap>,
+    mut detection: ResMut<Detection>,
+    mut attackers: Query<Attacker>,
+    mut targets: Query<Target>,
+) {
+    let locale = language.0;
+    for command in commands.read() {
+        if attackers.get(command.attacker).is_err() || targets.get(command.target).is_err() {
+            continue;
+        }
+        let result = resolve_strike(
+            locale,
+            command,
+            &initiative,
+            &mut rng.0,
+            &mut attackers,
+            &targets,
+            (&battle_map, &detection.0),
+        );
+        let (line, damage, degree) = match result {
+            Ok(resolved) => resolved,
+            Err(error) => {
+                let name = attackers.get(command.attacker).ok().and_then(|a| a.3);
+                let name = display_name(name, command.attacker);
+                log.0.push(error_line(locale, &name, &error));
+                continue;
+            }
+        };
+        log.0.push(line);
+        detection.0.reveal(command.attacker);
+
+        let (damage, retaliation) = if damage > 0 {
+            champion_reaction(locale, command, damage, &mut attackers, &targets, &mut log)
+        } else {
+            (damage, None)
+        };
+        if let Ok((_, mut sheet, .., name)) = targets.get_mut(command.target) {
+            let name = display_name(name, command.target);
+            apply_damage(locale, &mut sheet, damage, degree, &name, &mut log);
+        }
+
+        let Some(champion) = retaliation else {
+            continue;
+        };
+        let Ok((_, _, effects, name, _)) = attackers.get(champion) else {
+            continue;
+        };
+        let Some(armament) = targets
+            .get(champion)
+            .ok()
+            .and_then(|(_, sheet, ..)| Armament::of(&sheet.0).ok())
+        else {
+            continue;
+        };
+        let Ok((_, mut sheet, _, _, enemy_effects, enemy_name)) = targets.get_mut(command.attacker)
+        else {
+            continue;
+        };
+        // 리액션 Strike는 자기 턴이 아니므로 MAP가 없다
+        let target_ac = effective_ac(&sheet, enemy_effects, is_off_guard(enemy_effects));
+        if let Ok((roll, attack_bonus, degree, damage)) =
+            roll_strike(&mut rng.0, &armament, effects, 0, target_ac, None)
+        {
+            let enemy_name = display_name(enemy_name, command.attacker);
+            log.0.push(strike_line(
+                locale,
+                (
+                    &display_name(name, champion),
+                    &enemy_name,
+                    &armament.weapon.name,
+                ),
+                (roll, attack_bonus, degree, damage),
+                false,
+            ));
+            apply_damage(locale, &mut sheet, damage, degree, &enemy_name, &mut log);
+        }
+    }
+}
+
+fn check_victory(
+    combatants: Query<(&Faction, &Sheet)>,
+    mut next_state: ResMut<NextState<EncounterState>>,
+    mut log: ResMut<CombatLog>,
+    language: Res<Language>,
+) {
+    let standing = |side| {
+        combatants
+            .iter()
+            .any(|(f, sheet)| f.0 == side && sheet.is_standing())
+    };
+
+    for side in [Side::Party, Side::Creatures] {
+        if !standing(side) {
+            let side = side.localize(language.0);
+            log.0
+                .push(language.0.format("log.defeated", &[("side", &side)]));
+            next_state.set(EncounterState::Finished);
+            return;
+        }
+    }
+}
+
+/// 턴 경계
+#[derive(Clone, Copy)]
+enum Boundary {
+    Start,
+    End,
+}
+
+/// 턴이 도는 참가자
+type Participant<'a> = (
+    Entity,
+    &'a mut TurnState,
+    &'a mut Sheet,
+    &'a mut Effects,
+    Option<&'a Name>,
+    Option<&'a ActionBudget>,
+    Option<&'a mut MinionSheet>,
+    Option<&'a QuickenedActions>,
+);
+
+/// `who`의 턴 경계에서 끝나는 효과를 모든 참가자에게서 지운다, 그 효과가 준 임시 HP도 사라진다
+fn expire_effects(
+    locale: Locale,
+    participants: &mut Query<Participant>,
+    log: &mut CombatLog,
+    who: Entity,
+    boundary: Boundary,
+) {
+    for (entity, _, mut sheet, mut registry, name, ..) in participants.iter_mut() {
+        let expired = match boundary {
+            Boundary::Start => registry.0.start_turn(who),
+            Boundary::End => registry.0.end_turn(who),
+        };
+        for effect in expired {
+            sheet.0.end_effect(&effect.name);
+            log.0.push(locale.format(
+                "log.effect_ended",
+                &[
+                    ("name", &display_name(name, entity)),
+                    ("effect", &effect.name),
+                ],
+            ));
+        }
+    }
+}
+
+/// 쓰러져 죽어가는 참가자는 턴 시작에 회복 판정을 한다
+fn recovery_check(
+    locale: Locale,
+    participants: &mut Query<Participant>,
+    rng: &mut StdRng,
+    log: &mut CombatLog,
+    who: Entity,
+) {
+    let Ok((_, _, mut sheet, _, name, ..)) = participants.get_mut(who) else {
+        return;
+    };
+    if sheet.0.dying() == 0 || sheet.0.is_dead() {
+        return;
+    }
+    let dc = sheet.0.recovery_dc();
+    let roll = roll_d20(rng);
+    let degree = sheet
+        .0
+        .recovery_check(roll)
+        .expect("roll_d20은 1~20을 반환한다");
+    let result = match (sheet.0.is_dead(), sheet.0.dying()) {
+        (true, _) => locale.text("log.dead").to_string(),
+        (false, 0) => locale.text("log.stable").to_string(),
+        (false, dying) => Condition::Dying(dying).localize(locale),
+    };
+    log.0.push(locale.format(
+        "log.recovery_check",
+        &[
+            ("name", &display_name(name, who)),
+            ("roll", &roll),
+            ("dc", &dc),
+            ("degree", &degree.localize(locale)),
+            ("result", &result),
+        ],
+    ));
+}
+
+fn handle_end_turn(
+    mut commands: MessageReader<EndTurnCommand>,
+    mut initiative: ResMut<Initiative>,
+    mut rng: ResMut<DiceRng>,
+    mut log: ResMut<CombatLog>,
+    language: Res<Language>,
+    mut participants: Query<Participant>,
+    hazards: Query<&HazardSheet>,
+) {
+    let locale = language.0;
+    if commands.read().count() == 0 || initiative.order.is_empty() {
+        return;
+    }
+
+    let ending = initiative.order[initiative.current];
+    expire_effects(locale, &mut participants, &mut log, ending, Boundary::End);
+    initiative.minions.clear();
+
+    // 쓰러진 참가자는 턴 시작과 끝, 회복 판정만 하고 건너뛴다
+    for _ in 0..initiative.order.len() {
+        initiative.current += 1;
+        if initiative.current >= initiative.order.len() {
+            initiative.current = 0;
+            initiative.round += 1;
+        }
+
+        let next = initiative.order[initiative.current];
+        expire_effects(locale, &mut participants, &mut log, next, Boundary::Start);
+
+        // 해제된 위험 요소는 더 이상 행동하지 않는다
+        if let Ok(hazard) = hazards.get(next) {
+            if hazard.0.routine().is_some() {
+                return;
+            }
+            continue;
+        }
+        if let Ok((_, mut state, mut sheet, effects, name, actions, _, quickened)) =
+            participants.get_mut(next)
+            && sheet.is_standing()
+        {
+            *state = start_turn(
+                locale,
+                &mut log,
+                &display_name(name, next),
+                &mut sheet,
+                &effects.0.conditions(),
+                (actions, quickened),
+            );
+            // 주인의 턴이 시작되면 미니언은 명령을 기다린다
+            for (minion, mut state, _, _, _, _, sheet, _) in participants.iter_mut() {
+                if let Some(mut sheet) = sheet.filter(|m| m.0.master == next) {
+                    *state = TurnState::new(sheet.0.start_master_turn());
+                    initiative.minions.push(minion);
+                }
+            }
+            return;
+        }
+        recovery_check(locale, &mut participants, &mut rng.0, &mut log, next);
+        expire_effects(locale, &mut participants, &mut log, next, Boundary::End);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::check::SaveType;
+    use crate::companion::MinionKind;
+    use crate::hazard::Routine;
+    use crate::senses::DetectionState;
+
+    fn app(seed: u64) -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, PfOtariPlugin { seed }))
+            .add_systems(Startup, spawn_demo_encounter);
+        app
+    }
+
+    fn state(app: &App) -> EncounterState {
+        *app.world().resource::<State<EncounterState>>().get()
+    }
+
+    fn find(app: &mut App, name: &str) -> Entity {
+        let world = app.world_mut();
+        world
+            .query::<(Entity, &Name)>()
+            .iter(world)
+            .find(|(_, n)| n.as_str() == name)
+            .map(|(e, _)| e)
+            .unwrap()
+    }
+
+    /// 무기 없는 아군
+    fn guard() -> Character {
+        Character::new(
+            8,
+            Attribute {
+                strength: 10,
+                dexterity: 10,
+                constitution: 10,
+                intelligence: 10,
+                wisdom: 10,
+                charisma: 10,
+            },
+            Defense {
+                armor_item: 2,
+                armor_proficiency: Proficiency::Trained,
+            },
+            Proficiency::Trained,
+        )
+    }
+
+    /// 동물 동료
+    fn wolf() -> Character {
+        Character::new(
+            1,
+            Attribute {
+                strength: 14,
+                dexterity: 14,
+                constitution: 12,
+                intelligence: 4,
+                wisdom: 12,
+                charisma: 8,
+            },
+            Defense {
+                armor_item: 2,
+                armor_proficiency: Proficiency::Trained,
+            },
+            Proficiency::Trained,
+        )
+    }
+
+    /// 롱소드를 든 챔피언
+    fn champion() -> Character {
+        let champion = Character::new(
+            10,
+            Attribute {
+                strength: 16,
+                dexterity: 10,
+                constitution: 14,
+                intelligence: 10,
+                wisdom: 10,
+                charisma: 14,
+            },
+            Defense {
+                armor_item: 6,
+                armor_proficiency: Proficiency::Trained,
+            },
+            Proficiency::Trained,
+        );
+        armed(
+            champion.with_class(Class::Champion),
+            Weapon {
+                name: "Longsword".to_string(),
+                damage: Dice::new(1, 8),
+                agile: false,
+            },
+        )
+    }
+
+    /// 메이스를 든 클레릭
+    fn cleric() -> Character {
+        let cleric = Character::new(
+            8,
+            Attribute {
+                strength: 10,
+                dexterity: 10,
+                constitution: 12,
+                intelligence: 10,
+                wisdom: 16,
+                charisma: 12,
+            },
+            Defense {
+                armor_item: 2,
+                armor_proficiency: Proficiency::Trained,
+            },
+            Proficiency::Expert,
+        );
+        armed(
+            cleric,
+            Weapon {
+                name: "Mace".to_string(),
+                damage: Dice::new(1, 6),
+                agile: false,
+            },
+        )
+    }
+
+    #[test]
+    fn test_encounter_starts_with_initiative() {
+        let mut app = app(1);
+        app.update(); // Startup, Setup → Combat
+        app.update(); // OnEnter(Combat)
+
+        assert_eq!(state(&app), EncounterState::Combat);
+
+        let initiative = app.world().resource::<Initiative>();
+        assert_eq!(initiative.order().len(), 2);
+        assert_eq!(initiative.round(), 1);
+    }
+
+    #[test]
+    fn test_turn_spends_actions() {
+        let mut app = app(3);
+        app.update();
+        app.update();
+
+        let fighter = find(&mut app, "Fighter");
+        let goblin = find(&mut app, "Goblin");
+
+        // 전사 턴이 올 때까지 진행
+        while app.world().resource::<Initiative>().active() != Some(fighter) {
+            app.update();
+        }
+
+        let turn = app.world().get::<TurnState>(fighter).unwrap();
+        assert_eq!(turn.remaining_actions(), 3);
+        assert!(turn.has_reaction());
+
+        // 한 번의 Strike로 전투가 끝나지 않도록 고블린을 20레벨로 올림
+        let sturdy = app.world().get::<Sheet>(goblin).unwrap().0.clone();
+        app.world_mut()
+            .entity_mut(goblin)
+            .insert(Sheet(sturdy.with_level(20).unwrap()));
+
+        // 고블린에게 다가간 뒤 Strike
+        let goblin_pos = app.world().get::<GridPosition>(goblin).unwrap().0;
+        let fighter_pos = app.world().get::<GridPosition>(fighter).unwrap().0;
+        let speed = app.world().get::<Sheet>(fighter).unwrap().0.speed();
+        app.world_mut().write_message(StrideCommand {
+            mover: fighter,
+            to: fighter_pos.step_toward(goblin_pos, speed),
+        });
+        app.update();
+
+        app.world_mut().write_message(StrikeCommand {
+            attacker: fighter,
+            target: goblin,
+        });
+        app.update();
+
+        let turn = app.world().get::<TurnState>(fighter).unwrap();
+        assert_eq!(turn.remaining_actions(), 1);
+        assert_eq!(turn.strikes_made, 1);
+
+        // 이동력을 넘는 Stride는 행동한 이름과 함께 기록됨
+        app.world_mut().write_message(StrideCommand {
+            mover: fighter,
+            to: GridPos::new(40, 3),
+        });
+        app.update();
+        let log = &app.world().resource::<CombatLog>().0;
+        assert!(log.last().unwrap().starts_with("Fighter: "));
+        let turn = app.world().get::<TurnState>(fighter).unwrap();
+        assert_eq!(turn.remaining_actions(), 1);
+
+        // 턴 종료 후 고블린 차례
+        app.world_mut().write_message(EndTurnCommand);
+        app.update();
+        assert_eq!(state(&app), EncounterState::Combat);
+        assert_eq!(app.world().resource::<Initiative>().active(), Some(goblin));
+    }
+
+    #[test]
+    fn test_strike_and_stride_read_the_sheet() {
+        let mut app = app(3);
+        app.update();
+        app.update();
+
+        let fighter = find(&mut app, "Fighter");
+        let goblin = find(&mut app, "Goblin");
+        // 한 번의 Strike로 전투가 끝나지 않도록 고블린을 20레벨로 올림
+        let sturdy = app.world().get::<Sheet>(goblin).unwrap().0.clone();
+        app.world_mut()
+            .entity_mut(goblin)
+            .remove::<AutoPlay>()
+            .insert((
+                GridPosition(GridPos::new(2, 3)),
+                Sheet(sturdy.with_level(20).unwrap()),
+            ));
+        while app.world().resource::<Initiative>().active() != Some(fighter) {
+            app.world_mut().write_message(EndTurnCommand);
+            app.update();
+        }
+
+        // Fighter 클래스의 Expert 무기 숙련도: STR 3 + 5
+        app.world_mut().write_message(StrikeCommand {
+            attacker: fighter,
+            target: goblin,
+        });
+        app.update();
+        let log = &app.world().resource::<CombatLog>().0;
+        assert!(
+            log.iter()
+                .any(|line| line.contains("Longsword") && line.contains("+8"))
+        );
+
+        // 무기를 넣으면 Strike할 수 없고 액션도 쓰지 않는다
+        let mut sheet = app.world_mut().get_mut::<Sheet>(fighter).unwrap();
+        sheet.0.set_item_state(0, ItemState::Stowed).unwrap();
+        app.world_mut().write_message(StrikeCommand {
+            attacker: fighter,
+            target: goblin,
+        });
+        app.update();
+        let log = &app.world().resource::<CombatLog>().0;
+        assert_eq!(
+            log.last().unwrap(),
+            &format!("Fighter: {}", RulesError::NoWeaponHeld)
+        );
+        let turn = app.world().get::<TurnState>(fighter).unwrap();
+        assert_eq!(turn.remaining_actions(), 2);
+
+        // 이동 속도는 캐릭터 시트에서 온다
+        let mut sheet = app.world_mut().get_mut::<Sheet>(fighter).unwrap();
+        sheet.0 = sheet.0.clone().with_speed(10);
+        app.world_mut().write_message(StrideCommand {
+            mover: fighter,
+            to: GridPos::new(1, 6),
+        });
+        app.update();
+        let log = &app.world().resource::<CombatLog>().0;
+        assert_eq!(
+            log.last().unwrap(),
+            &format!(
+                "Fighter: {}",
+                RulesError::TooFar {
+                    distance: 15,
+                    speed: 10
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn test_shove_pushes_and_counts_toward_map() {
+        let mut app = app(3);
+        // 고블린 뒤를 막는 아군
+        app.world_mut().spawn((
+            Name::new("Guard"),
+            Sheet(guard()),
+            Faction(Side::Party),
+            GridPosition(GridPos::new(4, 3)),
+        ));
+        app.update();
+        app.update();
+
+        let fighter = find(&mut app, "Fighter");
+        let goblin = find(&mut app, "Goblin");
+        app.world_mut()
+            .entity_mut(goblin)
+            .remove::<AutoPlay>()
+            .insert(GridPosition(GridPos::new(2, 3)));
+        // Athletics +31: 내추럴 1이어도 성공
+        let mut sheet = app.world_mut().get_mut::<Sheet>(fighter).unwrap();
+        let strong = sheet
+            .0
+            .clone()
+            .with_skill(Skill::Athletics, Proficiency::Legendary);
+        sheet.0 = strong.with_level(20).unwrap();
+        for _ in 0..10 {
+            if app.world().resource::<Initiative>().active() == Some(fighter) {
+                break;
+            }
+            app.world_mut().write_message(EndTurnCommand);
+            app.update();
+        }
+
+        app.world_mut().write_message(ManeuverCommand {
+            attacker: fighter,
+            target: goblin,
+            maneuver: Maneuver::Shove,
+        });
+        app.update();
+
+        // 10피트를 밀어도 경비 앞에서 멈춘다
+        let position = app.world().get::<GridPosition>(goblin).unwrap();
+        assert_eq!(position.0, GridPos::new(3, 3));
+        let log = &app.world().resource::<CombatLog>().0;
+        assert_eq!(log.last().unwrap(), "Goblin: 5피트 밀려남");
+        let turn = app.world().get::<TurnState>(fighter).unwrap();
+        assert_eq!(turn.remaining_actions(), 2);
+        assert_eq!(turn.strikes_made, 1);
+
+        // Shove가 공격 횟수에 들어가 다음 Strike는 MAP -5
+        app.world_mut().write_message(StrideCommand {
+            mover: fighter,
+            to: GridPos::new(2, 3),
+        });
+        app.world_mut().write_message(StrikeCommand {
+            attacker: fighter,
+            target: goblin,
+        });
+        app.update();
+        let bonus = app.world().get::<Sheet>(fighter).unwrap().0.attack_bonus() - 5;
+        let log = &app.world().resource::<CombatLog>().0;
+        assert!(
+            log.iter()
+                .any(|line| line.contains("Longsword") && line.contains(&format!("{bonus:+})")))
+        );
+    }
+
+    #[test]
+    fn test_illegal_command_is_logged() {
+        let mut app = app(3);
+        app.update();
+        app.update();
+
+        let fighter = find(&mut app, "Fighter");
+        let goblin = find(&mut app, "Goblin");
+        while app.world().resource::<Initiative>().active() != Some(fighter) {
+            app.update();
+        }
+
+        // 전사 턴에 고블린이 공격하려 함
+        let hp = app.world().get::<Sheet>(fighter).unwrap().current_hp();
+        app.world_mut().write_message(StrikeCommand {
+            attacker: goblin,
+            target: fighter,
+        });
+        app.update();
+
+        let log = &app.world().resource::<CombatLog>().0;
+        assert_eq!(
+            log.last().unwrap(),
+            &format!("Goblin: {}", RulesError::NotYourTurn)
+        );
+        let sheet = app.world().get::<Sheet>(fighter).unwrap();
+        assert_eq!(sheet.current_hp(), hp);
+    }
+
+    #[test]
+    fn test_maneuver_on_self_is_logged() {
+        let mut app = app(3);
+        app.update();
+        app.update();
+
+        let fighter = find(&mut app, "Fighter");
+        while app.world().resource::<Initiative>().active() != Some(fighter) {
+            app.update();
+        }
+
+        app.world_mut().write_message(ManeuverCommand {
+            attacker: fighter,
+            target: fighter,
+            maneuver: Maneuver::Trip,
+        });
+        app.update();
+
+        let log = &app.world().resource::<CombatLog>().0;
+        assert_eq!(
+            log.last().unwrap(),
+            &format!("Fighter: {}", RulesError::SelfTarget)
+        );
+        let turn = app.world().get::<TurnState>(fighter).unwrap();
+        assert_eq!(turn.remaining_actions(), 3);
+    }
+
+    #[test]
+    fn test_stride_into_occupied_square() {
+        let mut app = app(3);
+        app.update();
+        app.update();
+
+        let fighter = find(&mut app, "Fighter");
+        let goblin = find(&mut app, "Goblin");
+        app.world_mut().entity_mut(goblin).remove::<AutoPlay>();
+        while app.world().resource::<Initiative>().active() != Some(fighter) {
+            app.world_mut().write_message(EndTurnCommand);
+            app.update();
+        }
+
+        let fighter_pos = app.world().get::<GridPosition>(fighter).unwrap().0;
+        let goblin_pos = GridPos::new(fighter_pos.x + 1, fighter_pos.y);
+        app.world_mut()
+            .entity_mut(goblin)
+            .insert(GridPosition(goblin_pos));
+        app.world_mut().write_message(StrideCommand {
+            mover: fighter,
+            to: goblin_pos,
+        });
+        app.update();
+
+        let log = &app.world().resource::<CombatLog>().0;
+        assert_eq!(
+            log.last().unwrap(),
+            &format!("Fighter: {}", RulesError::SquareOccupied)
+        );
+        assert_eq!(
+            app.world().get::<GridPosition>(fighter).unwrap().0,
+            fighter_pos
+        );
+        let turn = app.world().get::<TurnState>(fighter).unwrap();
+        assert_eq!(turn.remaining_actions(), 3);
+    }
+
+    /// 처음부터 방패를 들고 버티는 성격
+    struct Turtle;
+
+    impl<Id> Personality<Id> for Turtle {
+        fn choose(&self, situation: &Situation<Id>) -> TacticalAction<Id> {
+            match situation.shield {
+                ShieldState::Lowered => TacticalAction::RaiseShield,
+                _ => TacticalAction::EndTurn,
+            }
+        }
+    }
+
+    /// 언제나 닿지 않는 칸으로 Stride하려는 전술
+    struct Wanderer;
+
+    impl Personality<Entity> for Wanderer {
+        fn choose(&self, _: &Situation<Entity>) -> TacticalAction<Entity> {
+            TacticalAction::Stride(GridPos::new(40, 40))
+        }
+    }
+
+    #[test]
+    fn test_rejected_auto_play_ends_turn() {
+        let mut app = app(3);
+        app.update();
+        app.update();
+
+        let fighter = find(&mut app, "Fighter");
+        let goblin = find(&mut app, "Goblin");
+        app.world_mut().entity_mut(goblin).remove::<AutoPlay>();
+        app.world_mut()
+            .entity_mut(fighter)
+            .insert((AutoPlay, Tactician(Box::new(Wanderer))));
+        while app.world().resource::<Initiative>().active() != Some(fighter) {
+            app.world_mut().write_message(EndTurnCommand);
+            app.update();
+        }
+
+        // 거부된 Stride를 한 번 기록하고 다음 프레임에 턴을 넘긴다
+        app.update();
+        app.update();
+        assert_eq!(app.world().resource::<Initiative>().active(), Some(goblin));
+        let log = &app.world().resource::<CombatLog>().0;
+        let rejected = log
+            .iter()
+            .filter(|line| line.starts_with("Fighter: 이동 거리가"))
+            .count();
+        assert_eq!(rejected, 1);
+    }
+
+    #[test]
+    fn test_anthem_expires_at_next_turn() {
+        let mut app = app(3);
+        app.update();
+        app.update();
+
+        let fighter = find(&mut app, "Fighter");
+        while app.world().resource::<Initiative>().active() != Some(fighter) {
+            app.update();
+        }
+
+        app.world_mut()
+            .write_message(AnthemCommand { performer: fighter });
+        app.update();
+
+        let effects = &app.world().get::<Effects>(fighter).unwrap().0;
+        assert_eq!(effects.modifier(Statistic::AttackRolls), 1);
+        assert_eq!(effects.modifier(Statistic::Damage), 1);
+        let turn = app.world().get::<TurnState>(fighter).unwrap();
+        assert_eq!(turn.remaining_actions(), 2);
+
+        // 한 라운드가 지나 전사의 다음 턴이 시작되면 끝남
+        app.world_mut().write_message(EndTurnCommand);
+        app.update();
+        while state(&app) == EncounterState::Combat
+            && app.world().resource::<Initiative>().active() != Some(fighter)
+        {
+            app.update();
+        }
+
+        let effects = &app.world().get::<Effects>(fighter).unwrap().0;
+        assert_eq!(effects.modifier(Statistic::AttackRolls), 0);
+        let log = &app.world().resource::<CombatLog>().0;
+        assert!(log.contains(&"Fighter: Courageous Anthem 종료".to_string()));
+    }
+
+    #[test]
+    fn test_tactician_raises_shield() {
+        let mut app = app(3);
+        app.update();
+        app.update();
+
+        let fighter = find(&mut app, "Fighter");
+        app.world_mut()
+            .entity_mut(fighter)
+            .insert((AutoPlay, Tactician(Box::new(Turtle))));
+        let ac = app.world().get::<Sheet>(fighter).unwrap().0.ac();
+
+        while app.world().resource::<Initiative>().active() != Some(fighter) {
+            app.update();
+        }
+        app.update();
+
+        let effects = &app.world().get::<Effects>(fighter).unwrap().0;
+        assert_eq!(effects.modifier(Statistic::ArmorClass), 2);
+        let log = &app.world().resource::<CombatLog>().0;
+        assert_eq!(log.last().unwrap(), "Fighter: Raise a Shield, AC +2");
+
+        // 고블린이 보는 전사 AC도 오른다
+        let mut combatants = app.world_mut().query::<Visible>();
+        let view = combatant_view(combatants.get(app.world(), fighter).unwrap()).unwrap();
+        assert_eq!(view.ac, ac + 2);
+    }
+
+    #[test]
+    fn test_log_language() {
+        let mut app = app(3);
+        app.update();
+        app.update();
+
+        let fighter = find(&mut app, "Fighter");
+        let goblin = find(&mut app, "Goblin");
+        while app.world().resource::<Initiative>().active() != Some(fighter) {
+            app.update();
+        }
+
+        // 실행 중에 영어로 바꾼다
+        app.insert_resource(Language(Locale::English));
+        app.world_mut().write_message(StrikeCommand {
+            attacker: goblin,
+            target: fighter,
+        });
+        app.world_mut()
+            .write_message(RaiseShieldCommand { holder: fighter });
+        app.update();
+
+        let log = &app.world().resource::<CombatLog>().0;
+        assert_eq!(
+            log[log.len() - 2..],
+            [
+                "Fighter: Raise a Shield, AC +2".to_string(),
+                "Goblin: It's not your turn".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rage_requires_barbarian() {
+        let mut app = app(3);
+        app.update();
+        app.update();
+
+        let fighter = find(&mut app, "Fighter");
+        while app.world().resource::<Initiative>().active() != Some(fighter) {
+            app.update();
+        }
+
+        app.world_mut()
+            .write_message(RageCommand { barbarian: fighter });
+        app.update();
+        let log = &app.world().resource::<CombatLog>().0;
+        assert_eq!(log.last().unwrap(), "Fighter: 바바리안 클래스 능력입니다");
+
+        // 바바리안으로 바꾸면 임시 HP 1 + CON 2, 피해 +2, AC -1
+        let mut sheet = app.world_mut().get_mut::<Sheet>(fighter).unwrap();
+        sheet.0 = sheet.0.clone().with_class(Class::Barbarian);
+        app.world_mut()
+            .write_message(RageCommand { barbarian: fighter });
+        app.update();
+
+        assert_eq!(app.world().get::<Sheet>(fighter).unwrap().0.temp_hp(), 3);
+        let effects = &app.world().get::<Effects>(fighter).unwrap().0;
+        assert_eq!(effects.modifier(Statistic::Damage), 2);
+        assert_eq!(effects.modifier(Statistic::ArmorClass), -1);
+        let turn = app.world().get::<TurnState>(fighter).unwrap();
+        assert_eq!(turn.remaining_actions(), 2);
+    }
+
+    #[test]
+    fn test_conditions_change_actions() {
+        let mut app = app(3);
+        app.update();
+        app.update();
+
+        let fighter = find(&mut app, "Fighter");
+        while app.world().resource::<Initiative>().active() != Some(fighter) {
+            app.update();
+        }
+        let haste = ActiveEffect::new("Haste", EffectDuration::Unlimited)
+            .with_condition(Condition::Quickened);
+        let mut entity = app.world_mut().entity_mut(fighter);
+        entity.insert(QuickenedActions(vec![
+            ActionKind::Strike,
+            ActionKind::Stride,
+        ]));
+        entity.get_mut::<Effects>().unwrap().0.add(haste);
+        entity.get_mut::<Sheet>().unwrap().0.stun(1);
+
+        // 다음 전사 턴까지 진행
+        app.world_mut().write_message(EndTurnCommand);
+        app.update();
+        while app.world().resource::<Initiative>().active() != Some(fighter) {
+            app.update();
+        }
+
+        // Stunned 1은 일반 액션 하나를 빼앗고, Quickened 추가 액션은 남는다
+        let state = app.world().get::<TurnState>(fighter).unwrap();
+        assert_eq!(state.remaining_actions(), 2);
+        assert_eq!(
+            state.turn.quickened_actions(),
+            Some(&[ActionKind::Strike, ActionKind::Stride][..])
+        );
+        assert_eq!(app.world().get::<Sheet>(fighter).unwrap().0.stunned(), 0);
+        let log = &app.world().resource::<CombatLog>().0;
+        assert!(log.contains(&"Fighter: 액션 1개를 잃음".to_string()));
+
+        // Raise a Shield에는 Quickened 추가 액션을 쓸 수 없다
+        let mut turn = state.turn.clone();
+        assert_eq!(
+            turn.spend_quickened(ActionKind::RaiseShield),
+            Err(RulesError::QuickenedNotAllowed(ActionKind::RaiseShield))
+        );
+    }
+
+    /// 어둠 속에서 전사가 고블린을 한 번 Strike했을 때 단순 판정에 실패했는지
+    fn misses_in_darkness(seed: u64, vision: Vision) -> bool {
+        let mut app = app(seed);
+        app.insert_resource(BattleMap(Lighting::new(LightLevel::Darkness)));
+        app.update();
+        app.update();
+
+        let fighter = find(&mut app, "Fighter");
+        let goblin = find(&mut app, "Goblin");
+        let pos = app.world().get::<GridPosition>(fighter).unwrap().0;
+        app.world_mut().entity_mut(fighter).insert(Senses(vision));
+        app.world_mut()
+            .entity_mut(goblin)
+            .insert(GridPosition(GridPos::new(pos.x + 1, pos.y)));
+        // 고블린이 먼저 전사를 쓰러뜨리면 판정할 수 없다
+        for _ in 0..10 {
+            if app.world().resource::<Initiative>().active() == Some(fighter) {
+                break;
+            }
+            app.update();
+        }
+
+        app.world_mut().write_message(StrikeCommand {
+            attacker: fighter,
+            target: goblin,
+        });
+        app.update();
+        let log = &app.world().resource::<CombatLog>().0;
+        log.iter()
+            .any(|line| line.starts_with("Fighter → Goblin: 보이지 않아 빗나감"))
+    }
+
+    #[test]
+    fn test_darkness_needs_flat_check() {
+        // 보통 시각은 어둠 속 대상을 Hidden으로 느껴 DC 11 단순 판정을 한다
+        assert!((0..20).any(|seed| misses_in_darkness(seed, Vision::Normal)));
+        assert!((0..20).all(|seed| !misses_in_darkness(seed, Vision::Darkvision)));
+    }
+
+    /// 전사 턴까지 진행하고 고블린을 전사 옆에 둔다
+    fn fighter_turn(seed: u64, light: LightLevel) -> (App, Entity, Entity) {
+        let mut app = app(seed);
+        app.insert_resource(BattleMap(Lighting::new(light)));
+        app.update();
+        app.update();
+
+        let fighter = find(&mut app, "Fighter");
+        let goblin = find(&mut app, "Goblin");
+        for _ in 0..10 {
+            if app.world().resource::<Initiative>().active() == Some(fighter) {
+                break;
+            }
+            app.update();
+        }
+        let pos = app.world().get::<GridPosition>(fighter).unwrap().0;
+        app.world_mut()
+            .entity_mut(goblin)
+            .insert(GridPosition(GridPos::new(pos.x + 1, pos.y)));
+        (app, fighter, goblin)
+    }
+
+    /// 20레벨, Stealth Legendary: 고블린의 Perception DC 19를 언제나 넘는다
+    fn make_sneaky(app: &mut App, fighter: Entity) {
+        let mut sheet = app.world_mut().get_mut::<Sheet>(fighter).unwrap();
+        sheet.0 = sheet
+            .0
+            .clone()
+            .with_level(20)
+            .unwrap()
+            .with_skill(Skill::Stealth, Proficiency::Legendary);
+    }
+
+    fn remaining_actions(app: &App, entity: Entity) -> u8 {
+        app.world()
+            .get::<TurnState>(entity)
+            .unwrap()
+            .remaining_actions()
+    }
+
+    #[test]
+    fn test_hide_needs_concealment() {
+        let (mut app, fighter, goblin) = fighter_turn(3, LightLevel::Bright);
+        make_sneaky(&mut app, fighter);
+
+        // 밝은 빛에서는 숨을 곳이 없고 액션도 쓰지 않는다
+        app.world_mut()
+            .write_message(HideCommand { hider: fighter });
+        app.update();
+        let log = &app.world().resource::<CombatLog>().0;
+        assert_eq!(
+            log.last().unwrap(),
+            "Fighter: 엄폐나 은폐가 있어야 숨을 수 있습니다"
+        );
+        assert_eq!(remaining_actions(&app, fighter), 3);
+
+        // 어스름은 고블린의 보통 시각에 흐릿하게 보인다
+        app.insert_resource(BattleMap(Lighting::new(LightLevel::Dim)));
+        app.world_mut()
+            .write_message(HideCommand { hider: fighter });
+        app.update();
+        let detection = &app.world().resource::<Detection>().0;
+        assert_eq!(detection.state(goblin, fighter), DetectionState::Hidden);
+        let log = &app.world().resource::<CombatLog>().0;
+        let line = log.last().unwrap();
+        assert!(line.starts_with("Fighter → Goblin: Hide ("), "{line}");
+        assert!(line.ends_with(", 숨음"), "{line}");
+        assert_eq!(remaining_actions(&app, fighter), 2);
+    }
+
+    #[test]
+    fn test_sneak_moves_while_hidden() {
+        let (mut app, fighter, goblin) = fighter_turn(3, LightLevel::Dim);
+        make_sneaky(&mut app, fighter);
+        let from = app.world().get::<GridPosition>(fighter).unwrap().0;
+        let to = GridPos::new(from.x, from.y + 2);
+
+        app.world_mut().write_message(SneakCommand {
+            sneaker: fighter,
+            to,
+        });
+        app.update();
+        let log = &app.world().resource::<CombatLog>().0;
+        assert_eq!(
+            log.last().unwrap(),
+            "Fighter: 숨어 있어야 Sneak할 수 있습니다"
+        );
+
+        // 이동력 25피트의 절반까지
+        app.world_mut()
+            .resource_mut::<Detection>()
+            .0
+            .set(goblin, fighter, DetectionState::Hidden);
+        app.world_mut().write_message(SneakCommand {
+            sneaker: fighter,
+            to: GridPos::new(from.x, from.y + 3),
+        });
+        app.update();
+        let log = &app.world().resource::<CombatLog>().0;
+        assert!(log.last().unwrap().starts_with("Fighter: "));
+        assert_eq!(remaining_actions(&app, fighter), 3);
+
+        app.world_mut().write_message(SneakCommand {
+            sneaker: fighter,
+            to,
+        });
+        app.update();
+        let detection = &app.world().resource::<Detection>().0;
+        assert_eq!(detection.state(goblin, fighter), DetectionState::Undetected);
+        assert_eq!(app.world().get::<GridPosition>(fighter).unwrap().0, to);
+        assert_eq!(remaining_actions(&app, fighter), 2);
+
+        // Stride는 드러난다
+        app.world_mut().write_message(StrideCommand {
+            mover: fighter,
+            to: from,
+        });
+        app.update();
+        let detection = &app.world().resource::<Detection>().0;
+        assert_eq!(detection.state(goblin, fighter), DetectionState::Observed);
+    }
+
+    #[test]
+    fn test_seek_finds_hidden_creature() {
+        let (mut app, fighter, goblin) = fighter_turn(3, LightLevel::Bright);
+        make_sneaky(&mut app, fighter);
+        app.world_mut()
+            .resource_mut::<Detection>()
+            .0
+            .set(fighter, goblin, DetectionState::Hidden);
+
+        // Perception +25 vs 고블린 Stealth DC 13
+        app.world_mut()
+            .write_message(SeekCommand { seeker: fighter });
+        app.update();
+        let detection = &app.world().resource::<Detection>().0;
+        assert_eq!(detection.state(fighter, goblin), DetectionState::Observed);
+        let log = &app.world().resource::<CombatLog>().0;
+        let line = log.last().unwrap();
+        assert!(line.starts_with("Fighter → Goblin: Seek ("), "{line}");
+        assert!(line.ends_with(", 드러남"), "{line}");
+        assert_eq!(remaining_actions(&app, fighter), 2);
+    }
+
+    /// 전사가 `seen` 상태로 감지한 고블린을 Strike했을 때 단순 판정에 실패한 기록
+    fn flat_check_failure(seed: u64, seen: DetectionState) -> Option<String> {
+        let (mut app, fighter, goblin) = fighter_turn(seed, LightLevel::Bright);
+        app.world_mut()
+            .resource_mut::<Detection>()
+            .0
+            .set(fighter, goblin, seen);
+        app.world_mut().write_message(StrikeCommand {
+            attacker: fighter,
+            target: goblin,
+        });
+        app.update();
+        let log = &app.world().resource::<CombatLog>().0;
+        log.iter()
+            .find(|line| line.starts_with("Fighter → Goblin: 보이지 않아 빗나감"))
+            .cloned()
+    }
+
+    #[test]
+    fn test_detection_sets_flat_check_dc() {
+        let failures = |seen| (0..20).filter_map(move |seed| flat_check_failure(seed, seen));
+        assert_eq!(failures(DetectionState::Observed).count(), 0);
+        let concealed: Vec<_> = failures(DetectionState::Concealed).collect();
+        assert!(!concealed.is_empty());
+        assert!(concealed.iter().all(|line| line.ends_with("DC 5)")));
+        let hidden: Vec<_> = failures(DetectionState::Hidden).collect();
+        assert!(hidden.len() > concealed.len());
+        assert!(hidden.iter().all(|line| line.ends_with("DC 11)")));
+    }
+
+    /// 고블린이 전사를 `seen` 상태로 감지할 때 전사의 Strike 한 번 뒤 고블린 HP
+    fn goblin_hp_after_strike(seed: u64, seen: DetectionState) -> i32 {
+        let (mut app, fighter, goblin) = fighter_turn(seed, LightLevel::Bright);
+        app.world_mut()
+            .resource_mut::<Detection>()
+            .0
+            .set(goblin, fighter, seen);
+        app.world_mut().write_message(StrikeCommand {
+            attacker: fighter,
+            target: goblin,
+        });
+        app.update();
+        // 공격하면 드러난다
+        let detection = &app.world().resource::<Detection>().0;
+        assert_eq!(detection.state(goblin, fighter), DetectionState::Observed);
+        app.world().get::<Sheet>(goblin).unwrap().current_hp()
+    }
+
+    #[test]
+    fn test_hidden_attacker_makes_target_off_guard() {
+        // 같은 굴림에서 AC만 2 낮다
+        let mut better = 0;
+        for seed in 0..20 {
+            let observed = goblin_hp_after_strike(seed, DetectionState::Observed);
+            let hidden = goblin_hp_after_strike(seed, DetectionState::Hidden);
+            assert!(hidden <= observed, "seed {seed}");
+            if hidden < observed {
+                better += 1;
+            }
+        }
+        assert!(better > 0);
+    }
+
+    #[test]
+    fn test_minion_acts_on_command() {
+        let mut app = app(3);
+        app.update();
+        let fighter = find(&mut app, "Fighter");
+        app.world_mut().spawn((
+            Name::new("Wolf"),
+            Sheet(wolf()),
+            Faction(Side::Party),
+            GridPosition(GridPos::new(1, 2)),
+            MinionSheet(Minion::new(fighter, MinionKind::AnimalCompanion)),
+        ));
+        app.update();
+
+        // 미니언은 우선권에 들어가지 않는다
+        let wolf = find(&mut app, "Wolf");
+        assert_eq!(app.world().resource::<Initiative>().order().len(), 2);
+        while app.world().resource::<Initiative>().active() != Some(fighter) {
+            app.world_mut().write_message(EndTurnCommand);
+            app.update();
+        }
+        assert!(app.world().resource::<Initiative>().is_acting(wolf));
+        assert_eq!(
+            app.world()
+                .get::<TurnState>(wolf)
+                .unwrap()
+                .remaining_actions(),
+            0
+        );
+
+        let command = MinionCommand {
+            master: fighter,
+            minion: wolf,
+        };
+        app.world_mut().write_message(command);
+        app.update();
+        // 액션 표시도 미니언의 두 칸
+        let wolf_turn = app.world().get::<TurnState>(wolf).unwrap();
+        assert_eq!(
+            (wolf_turn.remaining_actions(), wolf_turn.granted_actions()),
+            (2, 2)
+        );
+        let turn = app.world().get::<TurnState>(fighter).unwrap();
+        assert_eq!(turn.remaining_actions(), 2);
+
+        // 한 턴에 두 번 명령할 수 없다
+        app.world_mut().write_message(command);
+        app.update();
+        let log = &app.world().resource::<CombatLog>().0;
+        assert_eq!(
+            log.last().unwrap(),
+            "Fighter: 이번 턴에 이미 명령을 받았습니다"
+        );
+        let turn = app.world().get::<TurnState>(fighter).unwrap();
+        assert_eq!(turn.remaining_actions(), 2);
+    }
+
+    #[test]
+    fn test_champion_reaction() {
+        let mut app = app(5);
+        app.world_mut().spawn((
+            Name::new("Champion"),
+            Sheet(champion()),
+            Faction(Side::Party),
+            GridPosition(GridPos::new(1, 4)),
+        ));
+        app.update();
+        app.update();
+
+        // 고블린을 전사 옆에 두고 직접 조종한다, 언제나 명중
+        let fighter = find(&mut app, "Fighter");
+        let goblin = find(&mut app, "Goblin");
+        let mut entity = app.world_mut().entity_mut(goblin);
+        entity.remove::<AutoPlay>();
+        entity.insert(GridPosition(GridPos::new(2, 3)));
+        let mut sheet = entity.get_mut::<Sheet>().unwrap();
+        // Legendary 20레벨: +28
+        let sharp = sheet
+            .0
+            .clone()
+            .with_weapon_proficiency(Proficiency::Legendary);
+        sheet.0 = sharp.with_level(20).unwrap();
+
+        while app.world().resource::<Initiative>().active() != Some(goblin) {
+            app.world_mut().write_message(EndTurnCommand);
+            app.update();
+        }
+
+        let hp = app.world().get::<Sheet>(fighter).unwrap().current_hp();
+        app.world_mut().write_message(StrikeCommand {
+            attacker: goblin,
+            target: fighter,
+        });
+        app.update();
+
+        let log = &app.world().resource::<CombatLog>().0;
+        let reaction = log
+            .iter()
+            .find(|line| line.starts_with("Champion: Retributive Strike, 저항 3"))
+            .unwrap();
+        let taken: i32 = reaction
+            .trim_end_matches(')')
+            .rsplit(' ')
+            .next()
+            .unwrap()
+            .parse()
+            .unwrap();
+        let sheet = app.world().get::<Sheet>(fighter).unwrap();
+        assert_eq!(hp - sheet.current_hp(), taken);
+        // 고블린이 챔피언 간격 안이므로 반격
+        assert!(
+            log.last()
+                .unwrap()
+                .starts_with("Champion → Goblin: Longsword")
+        );
+
+        let champion = find(&mut app, "Champion");
+        assert!(
+            !app.world()
+                .get::<TurnState>(champion)
+                .unwrap()
+                .has_reaction()
+        );
+    }
+
+    #[test]
+    fn test_dying_makes_recovery_check() {
+        let mut app = app(3);
+        // 전사가 쓰러져도 전투가 끝나지 않도록 멀리 있는 아군
+        app.world_mut().spawn((
+            Name::new("Cleric"),
+            Sheet(cleric()),
+            Faction(Side::Party),
+            GridPosition(GridPos::new(20, 20)),
+        ));
+        app.update();
+        app.update();
+
+        let fighter = find(&mut app, "Fighter");
+        let goblin = find(&mut app, "Goblin");
+        app.world_mut().entity_mut(goblin).remove::<AutoPlay>();
+
+        let mut sheet = app.world_mut().get_mut::<Sheet>(fighter).unwrap();
+        let hp = sheet.current_hp();
+        sheet.0.take_damage(hp).unwrap();
+        assert_eq!(sheet.0.dying(), 1);
+
+        // 전사 차례는 회복 판정만 하고 넘어간다
+        for _ in 0..6 {
+            app.world_mut().write_message(EndTurnCommand);
+            app.update();
+            assert_ne!(app.world().resource::<Initiative>().active(), Some(fighter));
+        }
+
+        // 마지막 회복 판정 결과가 전사 상태와 맞는다
+        let log = &app.world().resource::<CombatLog>().0;
+        let line = log
+            .iter()
+            .rfind(|line| line.starts_with("Fighter: 회복 판정"))
+            .unwrap();
+        let sheet = &app.world().get::<Sheet>(fighter).unwrap().0;
+        let expected = match (sheet.is_dead(), sheet.dying()) {
+            (true, _) => "사망".to_string(),
+            (false, 0) => "안정".to_string(),
+            (false, dying) => Condition::Dying(dying).localize(Locale::Korean),
+        };
+        assert!(line.ends_with(&expected), "{line}");
+        assert!(!sheet.is_conscious());
+    }
+
+    #[test]
+    fn test_complex_hazard_takes_turns() {
+        let mut app = app(5);
+        let dart = HazardAttack::Strike {
+            attack_bonus: 10,
+            damage: Dice::new(1, 4),
+            damage_bonus: 0,
+        };
+        let mut gallery = Hazard::complex(
+            "Dart Gallery",
+            1,
+            20,
+            dart.clone(),
+            Routine {
+                actions: 2,
+                attack: dart,
+            },
+        );
+        gallery.trigger().unwrap();
+        app.world_mut()
+            .spawn((Name::new("Dart Gallery"), HazardSheet(gallery)));
+        app.update();
+        app.update();
+
+        let fighter = find(&mut app, "Fighter");
+        let hazard = find(&mut app, "Dart Gallery");
+        assert!(
+            app.world()
+                .resource::<Initiative>()
+                .order()
+                .contains(&hazard)
+        );
+
+        // 위험 요소는 스스로 행동하고 한 프레임 안에 턴을 넘긴다
+        for _ in 0..10 {
+            let active = app.world().resource::<Initiative>().active();
+            if active == Some(fighter) {
+                app.world_mut().write_message(EndTurnCommand);
+            }
+            app.update();
+            if active == Some(hazard) {
+                assert_ne!(app.world().resource::<Initiative>().active(), Some(hazard));
+            }
+        }
+        let log = &app.world().resource::<CombatLog>().0;
+        let attacks = log
+            .iter()
+            .filter(|line| line.starts_with("Dart Gallery → Fighter: "))
+            .count();
+        assert!(attacks >= 2, "{log:?}");
+    }
+
+    #[test]
+    fn test_disable_command() {
+        let mut app = app(3);
+        let pit = Hazard::simple(
+            "Hidden Pit",
+            0,
+            18,
+            HazardAttack::Save {
+                save: SaveType::Reflex,
+                dc: 20,
+                damage: Dice::new(2, 6),
+                damage_bonus: 0,
+            },
+        )
+        .with_disable(Skill::Athletics, 5, Proficiency::Untrained);
+        app.world_mut()
+            .spawn((Name::new("Hidden Pit"), HazardSheet(pit)));
+        app.update();
+        app.update();
+
+        let fighter = find(&mut app, "Fighter");
+        let pit = find(&mut app, "Hidden Pit");
+        // 작동 전의 위험 요소는 우선권에 들어가지 않는다
+        assert_eq!(app.world().resource::<Initiative>().order().len(), 2);
+        while app.world().resource::<Initiative>().active() != Some(fighter) {
+            app.update();
+        }
+
+        let disable = DisableCommand {
+            actor: fighter,
+            hazard: pit,
+            skill: Skill::Athletics,
+        };
+        app.world_mut().write_message(disable);
+        app.update();
+
+        let turn = app.world().get::<TurnState>(fighter).unwrap();
+        assert_eq!(turn.remaining_actions(), 3 - DISABLE_ACTIONS);
+        let log = &app.world().resource::<CombatLog>().0;
+        assert!(
+            log.last().unwrap().starts_with("Fighter: Hidden Pit"),
+            "{log:?}"
+        );
+
+        // 남은 1 액션으로는 다시 시도할 수 없다
+        let state = app.world().get::<HazardSheet>(pit).unwrap().0.state();
+        app.world_mut().write_message(disable);
+        app.update();
+        let log = &app.world().resource::<CombatLog>().0;
+        assert!(log.last().unwrap().starts_with("Fighter: "));
+        assert_eq!(
+            app.world().get::<HazardSheet>(pit).unwrap().0.state(),
+            state
+        );
+    }
+
+    #[test]
+    fn test_auto_play_finishes_encounter() {
+        let mut app = app(7);
+        app.update();
+        app.update();
+
+        // 양쪽 모두 자동으로 행동
+        let fighter = find(&mut app, "Fighter");
+        app.world_mut().entity_mut(fighter).insert(AutoPlay);
+
+        for _ in 0..1000 {
+            if state(&app) == EncounterState::Finished {
+                break;
+            }
+            app.update();
+        }
+
+        assert_eq!(state(&app), EncounterState::Finished);
+        assert!(!app.world().resource::<CombatLog>().0.is_empty());
+    }
+}
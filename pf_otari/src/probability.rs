@@ -0,0 +1,256 @@
+use crate::combat::{Weapon, calc_damage, multiple_attack_penalty};
+use crate::dice::{DegreeOfSuccess, Dice, check_degree};
+
+/// 판정 결과별 확률
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct DegreeProbabilities {
+    pub critical_success: f64,
+    pub success: f64,
+    pub failure: f64,
+    pub critical_failure: f64,
+}
+
+impl DegreeProbabilities {
+    pub fn of(&self, degree: DegreeOfSuccess) -> f64 {
+        match degree {
+            DegreeOfSuccess::CriticalSuccess => self.critical_success,
+            DegreeOfSuccess::Success => self.success,
+            DegreeOfSuccess::Failure => self.failure,
+            DegreeOfSuccess::CriticalFailure => self.critical_failure,
+        }
+    }
+
+    /// 명중 확률 (Success 이상)
+    pub fn hit(&self) -> f64 {
+        self.critical_success + self.success
+    }
+}
+
+/// `lo..=hi` 범위 중 `min..=max` 에 들어가는 정수 개수
+fn count_in(lo: i32, hi: i32, min: i32, max: i32) -> i32 {
+    i32::max(0, i32::min(hi, max) - i32::max(lo, min) + 1)
+}
+
+/// d20 + `modifier` vs `dc` 판정의 결과별 확률
+///
+/// 자연 2~19는 구간 계산으로, 자연 1과 20은 `check_degree`로 등급을 조정한다.
+pub fn degree_probabilities(modifier: i32, dc: i32) -> DegreeProbabilities {
+    // 자연 굴림 n 에 대해 n + modifier - dc 가 각 구간에 들어가는 n 의 경계
+    let success_from = dc - modifier;
+    let critical_from = success_from + 10;
+    let failure_from = success_from - 9;
+
+    let mut counts = [
+        count_in(2, 19, critical_from, i32::MAX),
+        count_in(2, 19, success_from, critical_from - 1),
+        count_in(2, 19, failure_from, success_from - 1),
+        count_in(2, 19, i32::MIN, failure_from - 1),
+    ];
+
+    for natural in [1, 20] {
+        let index = match check_degree(natural + modifier, dc, natural) {
+            DegreeOfSuccess::CriticalSuccess => 0,
+            DegreeOfSuccess::Success => 1,
+            DegreeOfSuccess::Failure => 2,
+            DegreeOfSuccess::CriticalFailure => 3,
+        };
+        counts[index] += 1;
+    }
+
+    DegreeProbabilities {
+        critical_success: counts[0] as f64 / 20.0,
+        success: counts[1] as f64 / 20.0,
+        failure: counts[2] as f64 / 20.0,
+        critical_failure: counts[3] as f64 / 20.0,
+    }
+}
+
+/// MAP를 적용한 Strike의 결과별 확률
+///
+/// # Arguments
+/// `attack_bonus` - 공격 보너스
+/// `target_ac` - 대상 AC
+/// `strikes_made` - 이번 턴에 이미 한 Strike 횟수
+/// `agile` - Agile 무기 여부
+pub fn strike_probabilities(
+    attack_bonus: i32,
+    target_ac: i32,
+    strikes_made: u8,
+    agile: bool,
+) -> DegreeProbabilities {
+    let map = multiple_attack_penalty(strikes_made, agile);
+    degree_probabilities(attack_bonus + map, target_ac)
+}
+
+/// 주사위 합계별 확률 분포
+fn dice_distribution(dice: Dice) -> Vec<(i32, f64)> {
+    let sides = dice.sides as usize;
+    let mut ways = vec![1u64];
+
+    for _ in 0..dice.count {
+        let mut next = vec![0u64; ways.len() + sides];
+        for (sum, w) in ways.iter().enumerate() {
+            for face in 1..=sides {
+                next[sum + face] += w;
+            }
+        }
+        ways = next;
+    }
+
+    let total = (sides as f64).powi(dice.count as i32);
+    ways.into_iter()
+        .enumerate()
+        .filter(|(_, w)| *w > 0)
+        .map(|(sum, w)| (sum as i32, w as f64 / total))
+        .collect()
+}
+
+/// 판정 확률이 주어졌을 때 Strike 1회의 기대 피해 (`calc_damage` 규칙 적용)
+pub fn expected_damage(probabilities: &DegreeProbabilities, dice: Dice, bonus: i32) -> f64 {
+    let distribution = dice_distribution(dice);
+
+    [DegreeOfSuccess::CriticalSuccess, DegreeOfSuccess::Success]
+        .into_iter()
+        .map(|degree| {
+            let p = probabilities.of(degree);
+            let damage: f64 = distribution
+                .iter()
+                .map(|(roll, q)| calc_damage(degree, *roll, bonus) as f64 * q)
+                .sum();
+            p * damage
+        })
+        .sum()
+}
+
+/// 한 턴에 `strikes`회 Strike할 때의 기대 피해 합계
+pub fn expected_damage_per_turn(
+    attack_bonus: i32,
+    target_ac: i32,
+    weapon: &Weapon,
+    damage_bonus: i32,
+    strikes: u8,
+) -> f64 {
+    (0..strikes)
+        .map(|made| {
+            let p = strike_probabilities(attack_bonus, target_ac, made, weapon.agile);
+            expected_damage(&p, weapon.damage, damage_bonus)
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::combat::strike;
+
+    /// d20 20가지 경우를 모두 굴려 본 결과 개수
+    fn enumerate(attack_bonus: i32, target_ac: i32) -> [u32; 4] {
+        let mut counts = [0; 4];
+        for roll in 1..=20 {
+            let index = match strike(roll, attack_bonus, target_ac).unwrap() {
+                DegreeOfSuccess::CriticalSuccess => 0,
+                DegreeOfSuccess::Success => 1,
+                DegreeOfSuccess::Failure => 2,
+                DegreeOfSuccess::CriticalFailure => 3,
+            };
+            counts[index] += 1;
+        }
+        counts
+    }
+
+    #[test]
+    fn test_degree_probabilities_match_enumeration() {
+        for attack_bonus in -10..=40 {
+            for target_ac in 5..=45 {
+                let p = degree_probabilities(attack_bonus, target_ac);
+                let counts = enumerate(attack_bonus, target_ac);
+
+                assert_eq!(p.critical_success * 20.0, counts[0] as f64);
+                assert_eq!(p.success * 20.0, counts[1] as f64);
+                assert_eq!(p.failure * 20.0, counts[2] as f64);
+                assert_eq!(p.critical_failure * 20.0, counts[3] as f64);
+            }
+        }
+    }
+
+    #[test]
+    fn test_degree_probabilities_examples() {
+        // +7 vs AC 18: 11 이상 명중, 21 이상(=자연 20만) 치명타
+        let p = degree_probabilities(7, 18);
+        assert_eq!(p.critical_success, 0.05);
+        assert_eq!(p.success, 0.45);
+        assert_eq!(p.hit(), 0.5);
+
+        // 아무리 높아도 자연 1은 한 단계 하강
+        let p = degree_probabilities(50, 10);
+        assert_eq!(p.critical_success, 0.95);
+        assert_eq!(p.success, 0.05);
+    }
+
+    #[test]
+    fn test_strike_probabilities_apply_map() {
+        assert_eq!(
+            strike_probabilities(7, 18, 0, false),
+            degree_probabilities(7, 18)
+        );
+        assert_eq!(
+            strike_probabilities(7, 18, 1, false),
+            degree_probabilities(2, 18)
+        );
+        assert_eq!(
+            strike_probabilities(7, 18, 2, false),
+            degree_probabilities(-3, 18)
+        );
+        assert_eq!(
+            strike_probabilities(7, 18, 1, true),
+            degree_probabilities(3, 18)
+        );
+        assert_eq!(
+            strike_probabilities(7, 18, 2, true),
+            degree_probabilities(-1, 18)
+        );
+    }
+
+    #[test]
+    fn test_expected_damage_matches_enumeration() {
+        let dice = Dice::new(2, 6);
+
+        for (attack_bonus, target_ac, bonus) in [(7, 18, 3), (12, 15, -4), (0, 25, 1), (20, 10, -7)]
+        {
+            let mut total = 0;
+            for roll in 1..=20 {
+                let degree = strike(roll, attack_bonus, target_ac).unwrap();
+                for a in 1..=6 {
+                    for b in 1..=6 {
+                        total += calc_damage(degree, a + b, bonus);
+                    }
+                }
+            }
+            let enumerated = total as f64 / (20.0 * 36.0);
+
+            let p = degree_probabilities(attack_bonus, target_ac);
+            let expected = expected_damage(&p, dice, bonus);
+            assert!((expected - enumerated).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_expected_damage_per_turn() {
+        let longsword = Weapon {
+            name: "Longsword".to_string(),
+            damage: Dice::new(1, 8),
+            agile: false,
+        };
+
+        // 1회 Strike: 명중 0.45 × 7.5 + 치명타 0.05 × 15 = 4.125
+        let one = expected_damage_per_turn(7, 18, &longsword, 3, 1);
+        assert!((one - 4.125).abs() < 1e-9);
+
+        // 2번째 Strike는 -5: 명중 0.2 × 7.5 + 치명타 0.05 × 15 = 2.25
+        let two = expected_damage_per_turn(7, 18, &longsword, 3, 2);
+        assert!((two - (4.125 + 2.25)).abs() < 1e-9);
+
+        assert_eq!(expected_damage_per_turn(7, 18, &longsword, 3, 0), 0.0);
+    }
+}
@@ -0,0 +1,232 @@
+use bevy::prelude::*;
+
+use crate::combat::ActionSlot;
+use crate::plugin::{
+    EncounterState, EndTurnCommand, Faction, GridPosition, Initiative, Language, Sheet,
+    StrideCommand, StrikeCommand, TurnState,
+};
+use crate::simulation::Side;
+
+const TILE_SIZE: f32 = 48.0;
+const GRID_COLS: i32 = 10;
+const GRID_ROWS: i32 = 7;
+const HP_BAR_HEIGHT: f32 = 6.0;
+const FONT_PATH: &str = "fonts/NanumGothic-Regular.ttf";
+
+/// 격자 전투 화면: 격자, 말, HP 바, 액션 바
+///
+/// `PfOtariPlugin`과 `DefaultPlugins`가 필요하다.
+pub struct PfOtariRenderPlugin;
+
+impl Plugin for PfOtariRenderPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, (spawn_camera, spawn_grid, spawn_action_bar))
+            .add_systems(
+                Update,
+                (
+                    spawn_tokens,
+                    project_grid_position,
+                    update_hp_bars,
+                    update_action_bar,
+                    handle_player_input.run_if(in_state(EncounterState::Combat)),
+                ),
+            );
+    }
+}
+
+#[derive(Component)]
+struct HpBar;
+
+#[derive(Component)]
+struct ActionBar;
+
+fn to_world(x: i32, y: i32) -> Vec3 {
+    let offset_x = -(GRID_COLS as f32 * TILE_SIZE) / 2.0 + TILE_SIZE / 2.0;
+    let offset_y = -(GRID_ROWS as f32 * TILE_SIZE) / 2.0 + TILE_SIZE / 2.0;
+    Vec3::new(
+        offset_x + x as f32 * TILE_SIZE,
+        offset_y + y as f32 * TILE_SIZE,
+        0.0,
+    )
+}
+
+fn spawn_camera(mut commands: Commands) {
+    commands.spawn(Camera2d);
+}
+
+fn spawn_grid(mut commands: Commands) {
+    for y in 0..GRID_ROWS {
+        for x in 0..GRID_COLS {
+            let color = if (x + y) % 2 == 0 {
+                Color::srgb(0.25, 0.25, 0.25)
+            } else {
+                Color::srgb(0.3, 0.3, 0.3)
+            };
+
+            commands.spawn((
+                Sprite {
+                    color,
+                    custom_size: Some(Vec2::splat(TILE_SIZE - 1.0)),
+                    ..default()
+                },
+                Transform::from_translation(to_world(x, y)),
+            ));
+        }
+    }
+}
+
+fn spawn_tokens(mut commands: Commands, tokens: Query<(Entity, &Faction), Added<Sheet>>) {
+    for (entity, faction) in &tokens {
+        let color = match faction.0 {
+            Side::Party => Color::srgb(0.2, 0.5, 1.0),
+            Side::Creatures => Color::srgb(0.9, 0.2, 0.2),
+        };
+
+        commands.entity(entity).insert((
+            Sprite {
+                color,
+                custom_size: Some(Vec2::splat(TILE_SIZE * 0.7)),
+                ..default()
+            },
+            Transform::default(),
+            children![(
+                HpBar,
+                Sprite {
+                    color: Color::srgb(0.1, 0.9, 0.1),
+                    custom_size: Some(Vec2::new(TILE_SIZE * 0.8, HP_BAR_HEIGHT)),
+                    ..default()
+                },
+                Transform::from_xyz(0.0, TILE_SIZE * 0.45, 1.0),
+            )],
+        ));
+    }
+}
+
+fn project_grid_position(
+    mut tokens: Query<(&GridPosition, &mut Transform), Changed<GridPosition>>,
+) {
+    for (position, mut transform) in &mut tokens {
+        transform.translation = to_world(position.0.x, position.0.y).with_z(1.0);
+    }
+}
+
+fn update_hp_bars(
+    tokens: Query<(&Sheet, &Children), Changed<Sheet>>,
+    mut bars: Query<(&mut Sprite, &mut Transform), With<HpBar>>,
+) {
+    for (sheet, children) in &tokens {
+        let ratio = sheet.current_hp() as f32 / sheet.max_hp().max(1) as f32;
+        let width = TILE_SIZE * 0.8;
+
+        for child in children.iter() {
+            if let Ok((mut sprite, mut transform)) = bars.get_mut(child) {
+                sprite.custom_size = Some(Vec2::new(width * ratio, HP_BAR_HEIGHT));
+                // 왼쪽 끝을 고정
+                transform.translation.x = -width * (1.0 - ratio) / 2.0;
+                sprite.color = if ratio > 0.5 {
+                    Color::srgb(0.1, 0.9, 0.1)
+                } else {
+                    Color::srgb(0.9, 0.6, 0.1)
+                };
+            }
+        }
+    }
+}
+
+fn spawn_action_bar(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.spawn((
+        ActionBar,
+        Text::new(""),
+        TextFont {
+            font: asset_server.load(FONT_PATH),
+            font_size: 24.,
+            ..default()
+        },
+        TextColor(Color::WHITE),
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(10.),
+            left: Val::Px(15.),
+            ..default()
+        },
+    ));
+}
+
+fn update_action_bar(
+    initiative: Res<Initiative>,
+    language: Res<Language>,
+    state: Res<State<EncounterState>>,
+    combatants: Query<(&TurnState, Option<&Name>)>,
+    mut bar: Query<&mut Text, With<ActionBar>>,
+) {
+    let Ok(mut text) = bar.single_mut() else {
+        return;
+    };
+
+    if *state.get() == EncounterState::Finished {
+        text.0 = language.0.text("hud.finished").to_string();
+        return;
+    }
+
+    let Some((turn, name)) = initiative.active().and_then(|e| combatants.get(e).ok()) else {
+        return;
+    };
+
+    let actions = turn
+        .action_slots()
+        .iter()
+        .map(ActionSlot::symbol)
+        .collect::<String>();
+    let reaction = if turn.has_reaction() { '●' } else { '○' };
+
+    text.0 = language.0.format(
+        "hud.status",
+        &[
+            ("round", &initiative.round()),
+            ("name", &name.map_or("?", |n| n.as_str())),
+            ("actions", &actions),
+            ("reaction", &reaction),
+        ],
+    );
+}
+
+/// 파티 턴 키 입력: A 인접한 적 Strike, S 가장 가까운 적에게 Stride, Space 턴 종료
+fn handle_player_input(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    initiative: Res<Initiative>,
+    combatants: Query<(Entity, &Faction, &GridPosition, &Sheet)>,
+    mut strikes: MessageWriter<StrikeCommand>,
+    mut strides: MessageWriter<StrideCommand>,
+    mut end_turn: MessageWriter<EndTurnCommand>,
+) {
+    let Some(active) = initiative.active() else {
+        return;
+    };
+    let Ok((_, faction, position, sheet)) = combatants.get(active) else {
+        return;
+    };
+    if faction.0 != Side::Party {
+        return;
+    }
+
+    let nearest = combatants
+        .iter()
+        .filter(|(_, f, _, sheet)| f.0 != faction.0 && sheet.is_standing())
+        .min_by_key(|(_, _, p, _)| position.0.distance_feet(p.0));
+
+    if keyboard_input.just_pressed(KeyCode::Space) {
+        end_turn.write(EndTurnCommand);
+    } else if let Some((target, _, target_pos, _)) = nearest {
+        if keyboard_input.just_pressed(KeyCode::KeyA) {
+            strikes.write(StrikeCommand {
+                attacker: active,
+                target,
+            });
+        } else if keyboard_input.just_pressed(KeyCode::KeyS) {
+            strides.write(StrideCommand {
+                mover: active,
+                to: position.0.step_toward(target_pos.0, sheet.0.speed()),
+            });
+        }
+    }
+}
@@ -0,0 +1,506 @@
+use std::collections::HashMap;
+
+use rand::Rng;
+
+use crate::character::{Character, Skill};
+use crate::check::{Check, CheckOutcome};
+use crate::dice::{DegreeOfSuccess, roll_d20};
+use crate::error::RulesError;
+use crate::grid::{GridPos, SQUARE_FEET};
+
+/// Concealed 대상을 노릴 때의 단순 판정 DC
+pub const CONCEALED_FLAT_DC: i32 = 5;
+/// Hidden, Undetected 대상을 노릴 때의 단순 판정 DC
+pub const HIDDEN_FLAT_DC: i32 = 11;
+/// Seek의 범위 (30피트 원뿔, 15피트 폭발 중 원뿔 기준)
+pub const SEEK_RANGE_FEET: i32 = 30;
+
+/// 관찰자가 대상을 감지한 정도, 뒤로 갈수록 덜 드러난다
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Copy, Clone)]
+pub enum DetectionState {
+    Observed,
+    /// 위치는 알지만 흐릿하게 보인다
+    Concealed,
+    /// 칸은 알지만 보이지 않는다
+    Hidden,
+    /// 어느 칸에 있는지 모른다
+    Undetected,
+    /// 있다는 것조차 모른다
+    Unnoticed,
+}
+
+impl DetectionState {
+    /// 대상으로 삼을 때의 단순 판정 DC, Undetected는 칸을 맞게 골랐을 때
+    pub fn flat_check_dc(&self) -> Option<i32> {
+        match self {
+            DetectionState::Observed => None,
+            DetectionState::Concealed => Some(CONCEALED_FLAT_DC),
+            DetectionState::Hidden | DetectionState::Undetected | DetectionState::Unnoticed => {
+                Some(HIDDEN_FLAT_DC)
+            }
+        }
+    }
+
+    /// 대상으로 삼을 수 있는지 단순 판정을 굴린다, 판정이 필요 없으면 항상 성공
+    pub fn target(&self, rng: &mut impl Rng) -> bool {
+        self.flat_check_dc().is_none_or(|dc| flat_check(dc, rng))
+    }
+
+    /// 보이지 않는 공격자에게는 Off-Guard가 된다
+    pub fn off_guard(&self) -> bool {
+        *self >= DetectionState::Hidden
+    }
+}
+
+/// 단순 판정: 수정치 없이 d20이 DC 이상이면 성공
+pub fn flat_check(dc: i32, rng: &mut impl Rng) -> bool {
+    roll_d20(rng) as i32 >= dc
+}
+
+/// 칸의 밝기
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum LightLevel {
+    Bright,
+    Dim,
+    Darkness,
+}
+
+/// 전투 지도의 조명, 따로 정하지 않은 칸은 기본 밝기
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Lighting {
+    ambient: LightLevel,
+    squares: HashMap<GridPos, LightLevel>,
+}
+
+impl Lighting {
+    pub fn new(ambient: LightLevel) -> Self {
+        Self {
+            ambient,
+            squares: HashMap::new(),
+        }
+    }
+
+    /// `center`에서 `radius_feet` 안의 칸을 밝힌다 (횃불 등)
+    pub fn with_light(mut self, center: GridPos, radius_feet: i32, level: LightLevel) -> Self {
+        let squares = radius_feet / SQUARE_FEET;
+        for x in center.x - squares..=center.x + squares {
+            for y in center.y - squares..=center.y + squares {
+                let pos = GridPos::new(x, y);
+                if center.distance_feet(pos) <= radius_feet {
+                    self.squares.insert(pos, level);
+                }
+            }
+        }
+        self
+    }
+
+    pub fn light_at(&self, pos: GridPos) -> LightLevel {
+        self.squares.get(&pos).copied().unwrap_or(self.ambient)
+    }
+}
+
+/// 시각의 종류
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum Vision {
+    Normal,
+    /// 어스름을 밝은 빛처럼 본다
+    LowLight,
+    /// 어둠 속에서도 본다
+    Darkvision,
+}
+
+impl Vision {
+    /// 대상이 있는 칸의 밝기로 정해지는 감지 상태
+    ///
+    /// 보이지 않아도 부정확한 감각인 청각으로 칸은 알 수 있어 Hidden이 된다.
+    ///
+    /// ```
+    /// use pf_otari::prelude::*;
+    ///
+    /// assert_eq!(Vision::Normal.sight(LightLevel::Dim), DetectionState::Concealed);
+    /// assert_eq!(Vision::LowLight.sight(LightLevel::Dim), DetectionState::Observed);
+    /// assert_eq!(Vision::LowLight.sight(LightLevel::Darkness), DetectionState::Hidden);
+    /// assert_eq!(Vision::Darkvision.sight(LightLevel::Darkness), DetectionState::Observed);
+    /// ```
+    pub fn sight(&self, light: LightLevel) -> DetectionState {
+        match (light, self) {
+            (LightLevel::Bright, _) => DetectionState::Observed,
+            (LightLevel::Dim, Vision::Normal) => DetectionState::Concealed,
+            (LightLevel::Dim, _) => DetectionState::Observed,
+            (LightLevel::Darkness, Vision::Darkvision) => DetectionState::Observed,
+            (LightLevel::Darkness, _) => DetectionState::Hidden,
+        }
+    }
+}
+
+/// Stealth 판정의 상대가 되는 관찰자
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct Observer<Id> {
+    pub id: Id,
+    /// `Character::perception_dc`
+    pub perception_dc: i32,
+    /// 숨는 쪽이 있는 칸의 밝기로 정해진 상태 (`Vision::sight`)
+    pub sight: DetectionState,
+    /// 관찰자로부터 엄폐를 받는지
+    pub cover: bool,
+}
+
+impl<Id> Observer<Id> {
+    pub fn new(id: Id, perception_dc: i32, sight: DetectionState) -> Self {
+        Self {
+            id,
+            perception_dc,
+            sight,
+            cover: false,
+        }
+    }
+
+    pub fn with_cover(mut self) -> Self {
+        self.cover = true;
+        self
+    }
+
+    /// 엄폐나 은폐가 있어야 숨을 수 있다
+    fn can_hide_from(&self) -> bool {
+        self.cover || self.sight >= DetectionState::Concealed
+    }
+}
+
+/// 관찰자와 대상 쌍마다의 감지 상태, 기록이 없으면 Observed
+///
+/// 조명으로 정해지는 상태는 기록하지 않고 `perceive`에서 합친다.
+///
+/// ```
+/// use pf_otari::prelude::*;
+///
+/// let mut awareness = Awareness::new();
+/// awareness.set("Goblin", "Rogue", DetectionState::Hidden);
+///
+/// let seen = awareness.perceive("Goblin", "Rogue", Vision::Darkvision, LightLevel::Darkness);
+/// assert_eq!(seen, DetectionState::Hidden);
+/// assert_eq!(seen.flat_check_dc(), Some(11));
+/// assert_eq!(awareness.state("Rogue", "Goblin"), DetectionState::Observed);
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Awareness<Id> {
+    /// (관찰자, 대상, 상태)
+    states: Vec<(Id, Id, DetectionState)>,
+}
+
+impl<Id> Default for Awareness<Id> {
+    fn default() -> Self {
+        Self { states: Vec::new() }
+    }
+}
+
+impl<Id: PartialEq + Copy> Awareness<Id> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 기록된 상태, 조명은 반영하지 않는다
+    pub fn state(&self, observer: Id, target: Id) -> DetectionState {
+        self.states
+            .iter()
+            .find(|(o, t, _)| *o == observer && *t == target)
+            .map_or(DetectionState::Observed, |(.., state)| *state)
+    }
+
+    pub fn set(&mut self, observer: Id, target: Id, state: DetectionState) {
+        self.states
+            .retain(|(o, t, _)| !(*o == observer && *t == target));
+        if state != DetectionState::Observed {
+            self.states.push((observer, target, state));
+        }
+    }
+
+    /// 기록된 상태와 시각 중 덜 드러난 쪽
+    pub fn perceive(
+        &self,
+        observer: Id,
+        target: Id,
+        vision: Vision,
+        light: LightLevel,
+    ) -> DetectionState {
+        self.state(observer, target).max(vision.sight(light))
+    }
+
+    /// 대상이 눈에 띄는 행동을 해 모두에게 드러난다 (Hide, Sneak, Step 외의 행동)
+    pub fn reveal(&mut self, target: Id) {
+        self.states.retain(|(_, t, _)| *t != target);
+    }
+
+    /// Hide: 엄폐나 은폐가 있는 관찰자마다 Stealth로 Perception DC에 판정, 성공하면 Hidden
+    pub fn hide(
+        &mut self,
+        hider: Id,
+        character: &Character,
+        observers: &[Observer<Id>],
+        rng: &mut impl Rng,
+    ) -> Result<Vec<(Id, CheckOutcome)>, RulesError> {
+        let eligible: Vec<_> = observers.iter().filter(|o| o.can_hide_from()).collect();
+        if eligible.is_empty() {
+            return Err(RulesError::NoCoverOrConcealment);
+        }
+
+        let stealth = character.skill_bonus(Skill::Stealth);
+        let mut results = Vec::new();
+        for observer in eligible {
+            let outcome = Check::new(stealth, observer.perception_dc).roll(rng);
+            if succeeded(outcome.degree) && self.state(observer.id, hider) < DetectionState::Hidden
+            {
+                self.set(observer.id, hider, DetectionState::Hidden);
+            }
+            results.push((observer.id, outcome));
+        }
+        Ok(results)
+    }
+
+    /// Sneak: 이동력 절반까지 움직이며 숨어 있던 관찰자마다 Stealth로 판정한다
+    ///
+    /// 성공하면 Undetected, 실패하면 Hidden, 대실패하면 드러난다.
+    /// 도착한 칸에 엄폐나 은폐가 없으면 성공해도 드러난다.
+    /// `observers`의 시각과 엄폐는 도착한 칸 기준이다.
+    pub fn sneak(
+        &mut self,
+        sneaker: Id,
+        character: &Character,
+        (from, to): (GridPos, GridPos),
+        observers: &[Observer<Id>],
+        rng: &mut impl Rng,
+    ) -> Result<Vec<(Id, CheckOutcome)>, RulesError> {
+        let speed = character.speed() / 2;
+        let distance = from.distance_feet(to);
+        if distance > speed {
+            return Err(RulesError::TooFar { distance, speed });
+        }
+        let hidden_from: Vec<_> = observers
+            .iter()
+            .filter(|o| self.state(o.id, sneaker) >= DetectionState::Hidden)
+            .collect();
+        if hidden_from.is_empty() {
+            return Err(RulesError::NotHidden);
+        }
+
+        let stealth = character.skill_bonus(Skill::Stealth);
+        let mut results = Vec::new();
+        for observer in hidden_from {
+            let outcome = Check::new(stealth, observer.perception_dc).roll(rng);
+            let state = match outcome.degree {
+                _ if !observer.can_hide_from() => DetectionState::Observed,
+                DegreeOfSuccess::CriticalSuccess | DegreeOfSuccess::Success => self
+                    .state(observer.id, sneaker)
+                    .max(DetectionState::Undetected),
+                DegreeOfSuccess::Failure => DetectionState::Hidden,
+                DegreeOfSuccess::CriticalFailure => DetectionState::Observed,
+            };
+            self.set(observer.id, sneaker, state);
+            results.push((observer.id, outcome));
+        }
+        Ok(results)
+    }
+
+    /// Seek: 범위 안의 대상마다 Perception으로 Stealth DC에 판정한다
+    ///
+    /// 대성공하면 드러나고, 성공하면 한 단계 드러난다 (Undetected는 Hidden, Hidden은 Observed).
+    pub fn seek(
+        &mut self,
+        seeker: Id,
+        character: &Character,
+        position: GridPos,
+        targets: &[(Id, &Character, GridPos)],
+        rng: &mut impl Rng,
+    ) -> Vec<(Id, CheckOutcome)> {
+        let perception = character.perception_bonus();
+        let mut results = Vec::new();
+        for (target, hider, at) in targets {
+            let state = self.state(seeker, *target);
+            if state == DetectionState::Observed || position.distance_feet(*at) > SEEK_RANGE_FEET {
+                continue;
+            }
+            let dc = 10 + hider.skill_bonus(Skill::Stealth);
+            let outcome = Check::new(perception, dc).roll(rng);
+            let found = match outcome.degree {
+                DegreeOfSuccess::CriticalSuccess => DetectionState::Observed,
+                DegreeOfSuccess::Success if state >= DetectionState::Undetected => {
+                    DetectionState::Hidden
+                }
+                DegreeOfSuccess::Success => DetectionState::Observed,
+                DegreeOfSuccess::Failure | DegreeOfSuccess::CriticalFailure => state,
+            };
+            self.set(seeker, *target, found);
+            results.push((*target, outcome));
+        }
+        results
+    }
+}
+
+fn succeeded(degree: DegreeOfSuccess) -> bool {
+    matches!(
+        degree,
+        DegreeOfSuccess::CriticalSuccess | DegreeOfSuccess::Success
+    )
+}
+
+#[cfg(test)]
+mod tests {
+
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    use super::DetectionState::*;
+    use super::*;
+    use crate::character::{Attribute, Defense, Proficiency};
+
+    const ROGUE: u8 = 0;
+    const GOBLIN: u8 = 1;
+    const ORC: u8 = 2;
+
+    fn rogue() -> Character {
+        Character::new(
+            8,
+            Attribute {
+                strength: 10,
+                dexterity: 18,
+                constitution: 12,
+                intelligence: 12,
+                wisdom: 12,
+                charisma: 10,
+            },
+            Defense {
+                armor_item: 1,
+                armor_proficiency: Proficiency::Trained,
+            },
+            Proficiency::Expert,
+        )
+        .with_skill(Skill::Stealth, Proficiency::Trained)
+    }
+
+    #[test]
+    fn test_flat_checks() {
+        assert_eq!(Observed.flat_check_dc(), None);
+        assert_eq!(Concealed.flat_check_dc(), Some(5));
+        assert_eq!(Hidden.flat_check_dc(), Some(11));
+        assert!(Hidden.off_guard() && !Concealed.off_guard());
+
+        let mut rng = StdRng::seed_from_u64(3);
+        assert!((0..100).all(|_| Observed.target(&mut rng)));
+        let hits = (0..1000).filter(|_| Hidden.target(&mut rng)).count();
+        assert!((400..600).contains(&hits)); // 10/20
+    }
+
+    #[test]
+    fn test_lighting() {
+        let cave = Lighting::new(LightLevel::Darkness).with_light(
+            GridPos::new(0, 0),
+            20,
+            LightLevel::Bright,
+        );
+        assert_eq!(cave.light_at(GridPos::new(4, 0)), LightLevel::Bright);
+        assert_eq!(cave.light_at(GridPos::new(5, 0)), LightLevel::Darkness);
+
+        let mut awareness = Awareness::new();
+        let dark = cave.light_at(GridPos::new(9, 9));
+        assert_eq!(awareness.perceive(ORC, ROGUE, Vision::Normal, dark), Hidden);
+        assert_eq!(
+            awareness.perceive(ORC, ROGUE, Vision::Darkvision, dark),
+            Observed
+        );
+
+        // 기록된 상태가 더 숨겨져 있으면 그쪽을 따른다
+        awareness.set(ORC, ROGUE, Undetected);
+        assert_eq!(
+            awareness.perceive(ORC, ROGUE, Vision::Normal, dark),
+            Undetected
+        );
+        awareness.reveal(ROGUE);
+        assert_eq!(awareness.state(ORC, ROGUE), Observed);
+    }
+
+    #[test]
+    fn test_hide_and_sneak() {
+        let mut rng = StdRng::seed_from_u64(5);
+        let rogue = rogue();
+        let mut awareness = Awareness::new();
+
+        // 밝은 곳에서 엄폐 없이는 숨을 수 없다
+        let goblin = Observer::new(GOBLIN, 14, Observed);
+        assert_eq!(
+            awareness.hide(ROGUE, &rogue, &[goblin], &mut rng),
+            Err(RulesError::NoCoverOrConcealment)
+        );
+        let path = (GridPos::new(0, 0), GridPos::new(1, 0));
+        assert_eq!(
+            awareness.sneak(ROGUE, &rogue, path, &[goblin], &mut rng),
+            Err(RulesError::NotHidden)
+        );
+
+        let observers = [goblin.with_cover(), Observer::new(ORC, 14, Concealed)];
+        for _ in 0..20 {
+            awareness.reveal(ROGUE);
+            for (id, outcome) in awareness.hide(ROGUE, &rogue, &observers, &mut rng).unwrap() {
+                let expected = if succeeded(outcome.degree) {
+                    Hidden
+                } else {
+                    Observed
+                };
+                assert_eq!(awareness.state(id, ROGUE), expected);
+            }
+        }
+
+        // 엄폐가 남은 고블린에게는 Undetected가 될 수 있지만, 밝은 곳으로 나오면 오크에게 드러난다
+        awareness.set(GOBLIN, ROGUE, Hidden);
+        awareness.set(ORC, ROGUE, Hidden);
+        let observers = [goblin.with_cover(), Observer::new(ORC, 14, Observed)];
+        let results = awareness
+            .sneak(ROGUE, &rogue, path, &observers, &mut rng)
+            .unwrap();
+        let (_, outcome) = &results[0];
+        let expected = match outcome.degree {
+            DegreeOfSuccess::CriticalSuccess | DegreeOfSuccess::Success => Undetected,
+            DegreeOfSuccess::Failure => Hidden,
+            DegreeOfSuccess::CriticalFailure => Observed,
+        };
+        assert_eq!(awareness.state(GOBLIN, ROGUE), expected);
+        assert_eq!(awareness.state(ORC, ROGUE), Observed);
+
+        // 이동력 25피트의 절반까지만
+        let far = (GridPos::new(0, 0), GridPos::new(3, 0));
+        assert_eq!(
+            awareness.sneak(ROGUE, &rogue, far, &observers, &mut rng),
+            Err(RulesError::TooFar {
+                distance: 15,
+                speed: 12
+            })
+        );
+    }
+
+    #[test]
+    fn test_seek() {
+        let mut rng = StdRng::seed_from_u64(9);
+        let rogue = rogue();
+        let orc = rogue.clone();
+        let here = GridPos::new(0, 0);
+
+        for _ in 0..20 {
+            let mut awareness = Awareness::new();
+            awareness.set(ORC, ROGUE, Undetected);
+            awareness.set(ORC, GOBLIN, Undetected);
+            let targets = [
+                (ROGUE, &rogue, GridPos::new(2, 0)),
+                // 30피트 밖은 찾지 못한다
+                (GOBLIN, &rogue, GridPos::new(7, 0)),
+            ];
+            let results = awareness.seek(ORC, &orc, here, &targets, &mut rng);
+            assert_eq!(results.len(), 1);
+
+            let expected = match results[0].1.degree {
+                DegreeOfSuccess::CriticalSuccess => Observed,
+                DegreeOfSuccess::Success => Hidden,
+                _ => Undetected,
+            };
+            assert_eq!(awareness.state(ORC, ROGUE), expected);
+            assert_eq!(awareness.state(ORC, GOBLIN), Undetected);
+        }
+    }
+}
@@ -0,0 +1,582 @@
+use std::fmt;
+
+use rand::rngs::StdRng;
+use rand::{Rng, RngExt, SeedableRng};
+
+use crate::character::Character;
+use crate::combat::{
+    ACTIONS_PER_TURN, CombatTurn, Weapon, calc_damage, multiple_attack_penalty, strike_character,
+};
+use crate::dice::roll_d20;
+use crate::error::RulesError;
+use crate::locale::{Locale, Localize};
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum Side {
+    Party,
+    Creatures,
+}
+
+impl Side {
+    fn opponent(&self) -> Self {
+        match self {
+            Side::Party => Side::Creatures,
+            Side::Creatures => Side::Party,
+        }
+    }
+}
+
+/// 전투 참가자
+#[derive(Debug, Clone)]
+pub struct Combatant {
+    pub name: String,
+    pub character: Character,
+    pub weapon: Weapon,
+    pub attack_bonus: i32,
+    pub damage_bonus: i32,
+    /// 턴마다 얻는 액션
+    pub actions: u8,
+}
+
+impl Combatant {
+    pub fn new(
+        name: &str,
+        character: Character,
+        weapon: Weapon,
+        attack_bonus: i32,
+        damage_bonus: i32,
+    ) -> Self {
+        Self {
+            name: name.to_string(),
+            character,
+            weapon,
+            attack_bonus,
+            damage_bonus,
+            actions: ACTIONS_PER_TURN,
+        }
+    }
+
+    pub fn with_actions(mut self, actions: u8) -> Self {
+        self.actions = actions;
+        self
+    }
+
+    /// 손에 든 무기와 캐릭터의 명중·피해 보너스로 만든다
+    pub fn armed(name: &str, character: Character) -> Result<Self, RulesError> {
+        let weapon = character.weapon().ok_or(RulesError::NoWeaponHeld)?.clone();
+        let attack_bonus = character.attack_bonus();
+        let damage_bonus = character.damage_bonus();
+        Ok(Self::new(
+            name,
+            character,
+            weapon,
+            attack_bonus,
+            damage_bonus,
+        ))
+    }
+}
+
+/// 공격 대상 선택 방식
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum Targeting {
+    /// 목록 순서상 처음으로 서 있는 적
+    First,
+    /// 현재 HP가 가장 낮은 적
+    LowestHp,
+    /// 무작위
+    Random,
+}
+
+/// 진영별 단순 전술
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct Tactics {
+    pub targeting: Targeting,
+    /// 턴마다 시도하는 Strike 횟수, 액션 수를 넘지 않음
+    pub strikes_per_turn: u8,
+}
+
+impl Default for Tactics {
+    fn default() -> Self {
+        Self {
+            targeting: Targeting::First,
+            strikes_per_turn: 2,
+        }
+    }
+}
+
+/// 파티와 크리처 사이의 전투를 반복 실행하는 몬테카를로 시뮬레이터
+pub struct Simulation {
+    party: Vec<Combatant>,
+    creatures: Vec<Combatant>,
+    party_tactics: Tactics,
+    creature_tactics: Tactics,
+    max_rounds: u32,
+}
+
+struct Slot {
+    side: Side,
+    combatant: Combatant,
+    initiative: i32,
+}
+
+enum BattleOutcome {
+    Victory(Side, u32),
+    Draw,
+}
+
+impl Simulation {
+    pub fn new(party: Vec<Combatant>, creatures: Vec<Combatant>) -> Self {
+        Self {
+            party,
+            creatures,
+            party_tactics: Tactics::default(),
+            creature_tactics: Tactics::default(),
+            max_rounds: 100,
+        }
+    }
+
+    pub fn with_tactics(mut self, side: Side, tactics: Tactics) -> Self {
+        match side {
+            Side::Party => self.party_tactics = tactics,
+            Side::Creatures => self.creature_tactics = tactics,
+        }
+        self
+    }
+
+    /// 이 라운드 수가 지나도 승부가 나지 않으면 무승부
+    pub fn with_max_rounds(mut self, max_rounds: u32) -> Self {
+        self.max_rounds = max_rounds;
+        self
+    }
+
+    fn tactics(&self, side: Side) -> Tactics {
+        match side {
+            Side::Party => self.party_tactics,
+            Side::Creatures => self.creature_tactics,
+        }
+    }
+
+    /// `iterations`회 전투를 실행하고 통계를 모은다. 같은 `seed`는 같은 결과를 낸다.
+    pub fn run(&self, iterations: u32, seed: u64) -> SimulationReport {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut report = SimulationReport::new(&self.party, &self.creatures);
+
+        for _ in 0..iterations {
+            self.run_once(&mut rng, &mut report);
+        }
+
+        report
+    }
+
+    fn run_once(&self, rng: &mut impl Rng, report: &mut SimulationReport) {
+        let mut slots: Vec<Slot> = self
+            .party
+            .iter()
+            .map(|c| (Side::Party, c))
+            .chain(self.creatures.iter().map(|c| (Side::Creatures, c)))
+            .map(|(side, combatant)| Slot {
+                side,
+                combatant: combatant.clone(),
+                initiative: roll_d20(rng) as i32 + combatant.character.perception_bonus(),
+            })
+            .collect();
+
+        // 보고서의 참가자 순서를 유지하기 위해 정렬 대신 순서 인덱스를 사용
+        let mut order: Vec<usize> = (0..slots.len()).collect();
+        order.sort_by(|a, b| slots[*b].initiative.cmp(&slots[*a].initiative));
+
+        let outcome = self.fight(rng, &mut slots, &order, report);
+        report.record_outcome(outcome);
+    }
+
+    fn fight(
+        &self,
+        rng: &mut impl Rng,
+        slots: &mut [Slot],
+        order: &[usize],
+        report: &mut SimulationReport,
+    ) -> BattleOutcome {
+        for round in 1..=self.max_rounds {
+            for &attacker in order {
+                if slots[attacker].combatant.character.current_hp() == 0 {
+                    continue;
+                }
+
+                self.take_turn(rng, slots, attacker, report);
+
+                let side = slots[attacker].side;
+                if !is_standing(slots, side.opponent()) {
+                    return BattleOutcome::Victory(side, round);
+                }
+            }
+        }
+
+        BattleOutcome::Draw
+    }
+
+    fn take_turn(
+        &self,
+        rng: &mut impl Rng,
+        slots: &mut [Slot],
+        attacker: usize,
+        report: &mut SimulationReport,
+    ) {
+        let side = slots[attacker].side;
+        let tactics = self.tactics(side);
+        let weapon = slots[attacker].combatant.weapon.clone();
+        let attack_bonus = slots[attacker].combatant.attack_bonus;
+        let damage_bonus = slots[attacker].combatant.damage_bonus;
+
+        let mut turn = CombatTurn::with_actions(slots[attacker].combatant.actions);
+        let mut strikes_made = 0;
+
+        while strikes_made < tactics.strikes_per_turn && turn.spend_action(1).is_ok() {
+            let Some(target) = select_target(rng, slots, side.opponent(), tactics.targeting) else {
+                break;
+            };
+
+            let map = multiple_attack_penalty(strikes_made, weapon.agile);
+            let degree = strike_character(
+                roll_d20(rng),
+                attack_bonus + map,
+                &slots[target].combatant.character,
+            )
+            .expect("대상은 쓰러지지 않은 참가자 중에서 고른다");
+            let damage = calc_damage(degree, weapon.damage.roll(rng), damage_bonus);
+            strikes_made += 1;
+
+            let character = &mut slots[target].combatant.character;
+            // 남은 HP를 넘는 피해는 세지 않는다
+            let lost = damage.min(character.current_hp() + character.temp_hp());
+            character
+                .take_damage(damage)
+                .expect("calc_damage는 음수를 반환하지 않는다");
+
+            report.combatants[attacker].damage_dealt += lost as u64;
+            report.combatants[target].damage_taken += lost as u64;
+            if damage > 0 && character.current_hp() == 0 {
+                report.combatants[target].knockouts += 1;
+            }
+        }
+    }
+}
+
+fn is_standing(slots: &[Slot], side: Side) -> bool {
+    slots
+        .iter()
+        .any(|s| s.side == side && s.combatant.character.current_hp() > 0)
+}
+
+fn select_target(
+    rng: &mut impl Rng,
+    slots: &[Slot],
+    side: Side,
+    targeting: Targeting,
+) -> Option<usize> {
+    let candidates: Vec<usize> = (0..slots.len())
+        .filter(|&i| slots[i].side == side && slots[i].combatant.character.current_hp() > 0)
+        .collect();
+
+    match targeting {
+        Targeting::First => candidates.first().copied(),
+        Targeting::LowestHp => candidates
+            .iter()
+            .copied()
+            .min_by_key(|&i| slots[i].combatant.character.current_hp()),
+        Targeting::Random if candidates.is_empty() => None,
+        Targeting::Random => Some(candidates[rng.random_range(0..candidates.len())]),
+    }
+}
+
+/// 참가자별 누적 통계
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct CombatantReport {
+    pub name: String,
+    pub side: Side,
+    /// 실제로 깎은 HP (임시 HP 포함), 넘치는 피해는 세지 않는다
+    pub damage_dealt: u64,
+    pub damage_taken: u64,
+    pub knockouts: u32,
+}
+
+/// 시뮬레이션 결과 통계
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct SimulationReport {
+    pub iterations: u32,
+    pub party_wins: u32,
+    pub creature_wins: u32,
+    pub draws: u32,
+    /// 승부가 난 전투의 라운드 합계
+    pub total_rounds: u64,
+    pub min_rounds: Option<u32>,
+    pub max_rounds: Option<u32>,
+    /// 파티, 크리처 순서로 입력한 참가자 통계
+    pub combatants: Vec<CombatantReport>,
+}
+
+impl SimulationReport {
+    fn new(party: &[Combatant], creatures: &[Combatant]) -> Self {
+        let combatants = party
+            .iter()
+            .map(|c| (Side::Party, c))
+            .chain(creatures.iter().map(|c| (Side::Creatures, c)))
+            .map(|(side, c)| CombatantReport {
+                name: c.name.clone(),
+                side,
+                damage_dealt: 0,
+                damage_taken: 0,
+                knockouts: 0,
+            })
+            .collect();
+
+        Self {
+            iterations: 0,
+            party_wins: 0,
+            creature_wins: 0,
+            draws: 0,
+            total_rounds: 0,
+            min_rounds: None,
+            max_rounds: None,
+            combatants,
+        }
+    }
+
+    fn record_outcome(&mut self, outcome: BattleOutcome) {
+        self.iterations += 1;
+
+        match outcome {
+            BattleOutcome::Victory(side, rounds) => {
+                match side {
+                    Side::Party => self.party_wins += 1,
+                    Side::Creatures => self.creature_wins += 1,
+                }
+                self.total_rounds += rounds as u64;
+                self.min_rounds = Some(self.min_rounds.map_or(rounds, |r| r.min(rounds)));
+                self.max_rounds = Some(self.max_rounds.map_or(rounds, |r| r.max(rounds)));
+            }
+            BattleOutcome::Draw => self.draws += 1,
+        }
+    }
+
+    /// 파티 승률 (0.0 ~ 1.0)
+    pub fn win_rate(&self) -> f64 {
+        ratio(self.party_wins as u64, self.iterations)
+    }
+
+    /// 승부가 난 전투의 평균 라운드 수
+    pub fn mean_rounds(&self) -> f64 {
+        ratio(self.total_rounds, self.party_wins + self.creature_wins)
+    }
+
+    /// 전투당 평균 가한 피해
+    pub fn mean_damage_dealt(&self, combatant: usize) -> f64 {
+        ratio(self.combatants[combatant].damage_dealt, self.iterations)
+    }
+
+    /// 전투당 평균 받은 피해
+    pub fn mean_damage_taken(&self, combatant: usize) -> f64 {
+        ratio(self.combatants[combatant].damage_taken, self.iterations)
+    }
+
+    /// 전투 중 쓰러진 비율
+    pub fn knockout_rate(&self, combatant: usize) -> f64 {
+        ratio(self.combatants[combatant].knockouts as u64, self.iterations)
+    }
+}
+
+fn ratio(value: u64, count: u32) -> f64 {
+    if count == 0 {
+        0.0
+    } else {
+        value as f64 / count as f64
+    }
+}
+
+impl fmt::Display for SimulationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.localize(Locale::Korean))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::character::{Attribute, Defense, Proficiency};
+    use crate::dice::Dice;
+
+    fn character(class_hp: i32, constitution: i32, armor_item: i32) -> Character {
+        Character::new(
+            class_hp,
+            Attribute {
+                strength: 16,
+                dexterity: 12,
+                constitution,
+                intelligence: 10,
+                wisdom: 12,
+                charisma: 10,
+            },
+            Defense {
+                armor_item,
+                armor_proficiency: Proficiency::Trained,
+            },
+            Proficiency::Trained,
+        )
+    }
+
+    fn longsword() -> Weapon {
+        Weapon {
+            name: "Longsword".to_string(),
+            damage: Dice::new(1, 8),
+            agile: false,
+        }
+    }
+
+    fn fighter(name: &str) -> Combatant {
+        Combatant::new(name, character(10, 14, 4), longsword(), 7, 3)
+    }
+
+    fn goblin() -> Combatant {
+        let dogslicer = Weapon {
+            name: "Dogslicer".to_string(),
+            damage: Dice::new(1, 6),
+            agile: true,
+        };
+        Combatant::new("Goblin", character(4, 10, 2), dogslicer, 5, 0)
+    }
+
+    #[test]
+    fn test_armed_combatant() {
+        use crate::inventory::{Bulk, Item, ItemState};
+
+        let mut character = character(10, 14, 4);
+        assert_eq!(
+            Combatant::armed("Fighter", character.clone()).unwrap_err(),
+            RulesError::NoWeaponHeld
+        );
+
+        character
+            .carry(Item::weapon(longsword(), Bulk::new(1), 1), ItemState::Held)
+            .unwrap();
+        let fighter = Combatant::armed("Fighter", character).unwrap();
+        assert_eq!(fighter.weapon, longsword());
+        assert_eq!(fighter.attack_bonus, 6); // STR 3 + Trained 3
+        assert_eq!(fighter.damage_bonus, 3);
+    }
+
+    #[test]
+    fn test_same_seed_same_report() {
+        let sim = Simulation::new(vec![fighter("A")], vec![goblin(), goblin()]);
+
+        assert_eq!(sim.run(200, 42), sim.run(200, 42));
+    }
+
+    #[test]
+    fn test_overwhelming_party_always_wins() {
+        let party = vec![fighter("A"), fighter("B"), fighter("C"), fighter("D")];
+        let sim = Simulation::new(party, vec![goblin()]);
+
+        let report = sim.run(500, 1);
+
+        assert_eq!(report.iterations, 500);
+        assert_eq!(report.party_wins, 500);
+        assert_eq!(report.win_rate(), 1.0);
+        // 파티가 매번 이기므로 고블린은 매번 쓰러짐
+        assert_eq!(report.knockout_rate(4), 1.0);
+        // 넘치는 피해는 세지 않으므로 매번 최대 HP만큼 받는다
+        let goblin_hp = goblin().character.max_hp() as u64;
+        assert_eq!(report.combatants[4].damage_taken, 500 * goblin_hp);
+        assert!(report.mean_rounds() >= 1.0);
+        assert!(report.min_rounds.unwrap() <= report.max_rounds.unwrap());
+    }
+
+    #[test]
+    fn test_report_language() {
+        let sim = Simulation::new(vec![fighter("A")], vec![goblin()]);
+        let report = sim.run(10, 1);
+
+        let english = report.localize(Locale::English);
+        let lines: Vec<&str> = english.lines().collect();
+        assert_eq!(lines[0], "Fights: 10");
+        assert!(lines[1].starts_with("Party win rate: "));
+        assert_eq!(
+            lines[3],
+            "Combatant | Side | Damage dealt | Damage taken | Knocked out"
+        );
+        assert!(lines[4].starts_with("A | Party | "));
+        assert!(lines[5].starts_with("Goblin | Creatures | "));
+
+        assert_eq!(report.to_string(), report.localize(Locale::Korean));
+        assert!(report.to_string().starts_with("전투 횟수: 10\n"));
+    }
+
+    #[test]
+    fn test_damage_dealt_matches_damage_taken() {
+        let sim = Simulation::new(vec![fighter("A"), fighter("B")], vec![goblin(), goblin()])
+            .with_tactics(
+                Side::Creatures,
+                Tactics {
+                    targeting: Targeting::LowestHp,
+                    strikes_per_turn: 3,
+                },
+            );
+
+        let report = sim.run(300, 9);
+
+        let total = |side: Side, f: fn(&CombatantReport) -> u64| -> u64 {
+            report
+                .combatants
+                .iter()
+                .filter(|c| c.side == side)
+                .map(f)
+                .sum()
+        };
+        assert_eq!(
+            total(Side::Party, |c| c.damage_dealt),
+            total(Side::Creatures, |c| c.damage_taken)
+        );
+        assert_eq!(
+            total(Side::Creatures, |c| c.damage_dealt),
+            total(Side::Party, |c| c.damage_taken)
+        );
+        assert_eq!(
+            report.party_wins + report.creature_wins + report.draws,
+            report.iterations
+        );
+    }
+
+    #[test]
+    fn test_action_budget() {
+        let aggressive = Tactics {
+            targeting: Targeting::Random,
+            strikes_per_turn: 3,
+        };
+        let sim = Simulation::new(vec![fighter("A").with_actions(0)], vec![goblin()])
+            .with_tactics(Side::Party, aggressive)
+            .with_max_rounds(5);
+
+        let report = sim.run(50, 4);
+
+        // 액션이 없는 전사는 공격하지 못한다
+        assert_eq!(report.combatants[0].damage_dealt, 0);
+        assert_eq!(report.party_wins, 0);
+    }
+
+    #[test]
+    fn test_no_strikes_is_draw() {
+        let passive = Tactics {
+            targeting: Targeting::Random,
+            strikes_per_turn: 0,
+        };
+        let sim = Simulation::new(vec![fighter("A")], vec![goblin()])
+            .with_tactics(Side::Party, passive)
+            .with_tactics(Side::Creatures, passive)
+            .with_max_rounds(3);
+
+        let report = sim.run(10, 0);
+
+        assert_eq!(report.draws, 10);
+        assert_eq!(report.mean_rounds(), 0.0);
+        assert_eq!(report.min_rounds, None);
+    }
+}
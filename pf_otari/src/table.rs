@@ -0,0 +1,650 @@
+use std::cmp::Reverse;
+use std::fmt;
+
+use rand::Rng;
+
+use crate::bestiary::{Creature, CreatureStrike, bestiary};
+use crate::combat::{CombatTurn, calc_damage, multiple_attack_penalty, strike};
+use crate::dice::{DegreeOfSuccess, Dice, roll_d20};
+use crate::error::RulesError;
+use crate::locale::{Locale, Localize};
+use crate::simulation::Side;
+
+/// 테이블 전투에 쓰는 참가자 능력치
+///
+/// 파일에서는 한 줄로 적는다.
+///
+/// ```
+/// use pf_otari::table::StatLine;
+///
+/// let line = "Valeros | AC 18 | HP 20 | Perception 4 | Longsword +7 1d8+4";
+/// let valeros: StatLine = line.parse().unwrap();
+///
+/// assert_eq!(valeros.ac, 18);
+/// assert_eq!(valeros.to_string(), line);
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct StatLine {
+    pub name: String,
+    pub ac: i32,
+    pub max_hp: i32,
+    pub perception: i32,
+    pub strike: CreatureStrike,
+}
+
+impl From<&Creature> for StatLine {
+    fn from(creature: &Creature) -> Self {
+        Self {
+            name: creature.name.clone(),
+            ac: creature.ac,
+            max_hp: creature.hp,
+            perception: creature.perception,
+            strike: creature.strike.clone(),
+        }
+    }
+}
+
+impl fmt::Display for StatLine {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let strike = &self.strike;
+        write!(
+            f,
+            "{} | AC {} | HP {} | Perception {} | {} {:+} {}",
+            self.name,
+            self.ac,
+            self.max_hp,
+            self.perception,
+            strike.name,
+            strike.attack_bonus,
+            strike.damage
+        )?;
+        if strike.damage_bonus != 0 {
+            write!(f, "{:+}", strike.damage_bonus)?;
+        }
+        if strike.agile {
+            write!(f, " agile")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::str::FromStr for StatLine {
+    type Err = RulesError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fields: Vec<&str> = s.split('|').map(str::trim).collect();
+        stat_line(&fields).ok_or_else(|| RulesError::InvalidRoster {
+            line: 1,
+            text: s.to_string(),
+        })
+    }
+}
+
+/// `이름 | AC n | HP n | Perception n | 무기 +명중 피해`
+fn stat_line(fields: &[&str]) -> Option<StatLine> {
+    let (name, fields) = fields.split_first()?;
+    let (mut ac, mut max_hp, mut perception, mut strike) = (None, None, None, None);
+    for field in fields {
+        match field.split_once(' ') {
+            Some(("AC", value)) => ac = value.parse().ok(),
+            Some(("HP", value)) => max_hp = value.parse().ok(),
+            Some(("Perception", value)) => perception = value.parse().ok(),
+            _ => strike = Some(parse_strike(field)?),
+        }
+    }
+    Some(StatLine {
+        name: name.to_string(),
+        ac: ac?,
+        max_hp: max_hp?,
+        perception: perception?,
+        strike: strike?,
+    })
+}
+
+/// `Longsword +7 1d8+4 agile`
+fn parse_strike(field: &str) -> Option<CreatureStrike> {
+    let tokens: Vec<&str> = field.split_whitespace().collect();
+    let bonus_at = tokens
+        .iter()
+        .position(|t| t.starts_with(['+', '-']) && t[1..].parse::<i32>().is_ok())?;
+    let attack_bonus = tokens[bonus_at].parse().ok()?;
+    let damage = tokens.get(bonus_at + 1)?;
+    let (dice, damage_bonus) = match damage.find(['+', '-']) {
+        Some(at) => (&damage[..at], damage[at..].parse().ok()?),
+        None => (*damage, 0),
+    };
+    let agile = match tokens.get(bonus_at + 2) {
+        Some(&"agile") => true,
+        Some(_) => return None,
+        None => false,
+    };
+    if bonus_at == 0 || tokens.len() > bonus_at + 3 {
+        return None;
+    }
+    Some(CreatureStrike {
+        name: tokens[..bonus_at].join(" "),
+        attack_bonus,
+        damage: dice.parse::<Dice>().ok()?,
+        damage_bonus,
+        agile,
+    })
+}
+
+/// 참가자 파일을 읽는다, 빈 줄과 `#` 주석은 건너뛰고 참가자가 없으면 거부한다
+///
+/// 능력치 줄 대신 수록 크리처 이름을 적을 수 있고, `x3`처럼 수를 붙이면 번호를 매겨 여럿 만든다.
+pub fn parse_roster(text: &str) -> Result<Vec<StatLine>, RulesError> {
+    let mut roster = Vec::new();
+    for (index, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let invalid = || RulesError::InvalidRoster {
+            line: index + 1,
+            text: line.to_string(),
+        };
+
+        if line.contains('|') {
+            let fields: Vec<&str> = line.split('|').map(str::trim).collect();
+            roster.push(stat_line(&fields).ok_or_else(invalid)?);
+            continue;
+        }
+
+        let (name, count) = match line.rsplit_once(" x") {
+            Some((name, count)) if count.parse::<u32>().is_ok() => {
+                (name.trim(), count.parse().map_err(|_| invalid())?)
+            }
+            _ => (line, 1),
+        };
+        if count == 0 {
+            return Err(invalid());
+        }
+        let creature = bestiary()
+            .into_iter()
+            .find(|c| c.name == name)
+            .ok_or_else(invalid)?;
+        for n in 1..=count {
+            let mut stats = StatLine::from(&creature);
+            if count > 1 {
+                stats.name = format!("{name} {n}");
+            }
+            roster.push(stats);
+        }
+    }
+    if roster.is_empty() {
+        return Err(RulesError::InvalidRoster {
+            line: 1,
+            text: text.lines().next().unwrap_or_default().to_string(),
+        });
+    }
+    Ok(roster)
+}
+
+/// 전투 중인 참가자
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Fighter {
+    pub stats: StatLine,
+    pub side: Side,
+    pub hp: i32,
+    pub initiative: i32,
+    pub turn: CombatTurn,
+    pub strikes_made: u8,
+}
+
+impl Fighter {
+    fn new(stats: StatLine, side: Side, initiative: i32) -> Self {
+        Self {
+            hp: stats.max_hp,
+            stats,
+            side,
+            initiative,
+            turn: CombatTurn::new(),
+            strikes_made: 0,
+        }
+    }
+
+    pub fn is_standing(&self) -> bool {
+        self.hp > 0
+    }
+}
+
+/// Strike 한 번의 결과
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct StrikeReport {
+    pub roll: u8,
+    pub attack_bonus: i32,
+    pub degree: DegreeOfSuccess,
+    pub damage: i32,
+}
+
+/// GM이 행동을 고르는 테이블 전투, 우선권 순서대로 턴이 돈다
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct TableFight {
+    /// 우선권 순서
+    fighters: Vec<Fighter>,
+    current: usize,
+    round: u32,
+}
+
+impl TableFight {
+    /// 우선권 `d20 + 지각`을 굴린다, 같으면 파티가 먼저
+    pub fn new(party: Vec<StatLine>, creatures: Vec<StatLine>, rng: &mut impl Rng) -> Self {
+        let sides = party
+            .into_iter()
+            .map(|s| (s, Side::Party))
+            .chain(creatures.into_iter().map(|s| (s, Side::Creatures)));
+        let mut fighters: Vec<Fighter> = sides
+            .map(|(stats, side)| {
+                let initiative = roll_d20(rng) as i32 + stats.perception;
+                Fighter::new(stats, side, initiative)
+            })
+            .collect();
+        fighters.sort_by_key(|f| (Reverse(f.initiative), f.side == Side::Creatures));
+        Self {
+            fighters,
+            current: 0,
+            round: 1,
+        }
+    }
+
+    pub fn fighters(&self) -> &[Fighter] {
+        &self.fighters
+    }
+
+    pub fn round(&self) -> u32 {
+        self.round
+    }
+
+    pub fn active_index(&self) -> usize {
+        self.current
+    }
+
+    pub fn active(&self) -> &Fighter {
+        &self.fighters[self.current]
+    }
+
+    /// 현재 참가자가 `target`을 Strike한다 (1 액션, MAP 적용)
+    pub fn strike(
+        &mut self,
+        target: usize,
+        rng: &mut impl Rng,
+    ) -> Result<StrikeReport, RulesError> {
+        let defender = self.fighters.get(target).ok_or(RulesError::OutOfReach)?;
+        if target == self.current {
+            return Err(RulesError::OutOfReach);
+        }
+        if !defender.is_standing() {
+            return Err(RulesError::TargetUnconscious);
+        }
+        let target_ac = defender.stats.ac;
+
+        let attacker = &mut self.fighters[self.current];
+        attacker.turn.spend_action(1)?;
+        let weapon = &attacker.stats.strike;
+        let attack_bonus =
+            weapon.attack_bonus + multiple_attack_penalty(attacker.strikes_made, weapon.agile);
+        attacker.strikes_made += 1;
+
+        let roll = roll_d20(rng);
+        let degree = strike(roll, attack_bonus, target_ac)?;
+        let damage = calc_damage(degree, weapon.damage.roll(rng), weapon.damage_bonus);
+        let defender = &mut self.fighters[target];
+        defender.hp = i32::max(defender.hp - damage, 0);
+
+        Ok(StrikeReport {
+            roll,
+            attack_bonus,
+            degree,
+            damage,
+        })
+    }
+
+    /// Strike 결과를 전투 기록 한 줄로 옮긴다
+    pub fn strike_line(
+        &self,
+        locale: Locale,
+        attacker: usize,
+        target: usize,
+        report: &StrikeReport,
+    ) -> String {
+        locale.format(
+            "log.strike",
+            &[
+                ("attacker", &self.fighters[attacker].stats.name),
+                ("target", &self.fighters[target].stats.name),
+                ("weapon", &self.fighters[attacker].stats.strike.name),
+                ("roll", &report.roll),
+                ("bonus", &format!("{:+}", report.attack_bonus)),
+                ("degree", &report.degree.localize(locale)),
+                ("damage", &report.damage),
+            ],
+        )
+    }
+
+    /// Strike가 아닌 행동 (Stride, Interact 등)에 액션을 쓴다
+    pub fn act(&mut self, cost: u8) -> Result<(), RulesError> {
+        self.fighters[self.current].turn.spend_action(cost)
+    }
+
+    /// 아무 참가자나 리액션을 쓴다
+    pub fn react(&mut self, fighter: usize) -> Result<(), RulesError> {
+        let fighter = self
+            .fighters
+            .get_mut(fighter)
+            .ok_or(RulesError::OutOfReach)?;
+        if !fighter.is_standing() {
+            return Err(RulesError::TargetUnconscious);
+        }
+        fighter.turn.spend_reaction()
+    }
+
+    /// 다음으로 서 있는 참가자에게 턴을 넘긴다, 턴 시작에 액션과 리액션을 되찾는다
+    pub fn end_turn(&mut self) {
+        for _ in 0..self.fighters.len() {
+            self.current += 1;
+            if self.current >= self.fighters.len() {
+                self.current = 0;
+                self.round += 1;
+            }
+            let next = &mut self.fighters[self.current];
+            if next.is_standing() {
+                next.turn = CombatTurn::new();
+                next.strikes_made = 0;
+                return;
+            }
+        }
+    }
+
+    /// 한쪽만 서 있으면 그쪽이 이긴다
+    pub fn winner(&self) -> Option<Side> {
+        let standing = |side| {
+            self.fighters
+                .iter()
+                .any(|f| f.side == side && f.is_standing())
+        };
+        match (standing(Side::Party), standing(Side::Creatures)) {
+            (true, false) => Some(Side::Party),
+            (false, true) => Some(Side::Creatures),
+            _ => None,
+        }
+    }
+
+    /// 진행 중인 전투를 저장 파일 형식으로 적는다
+    pub fn save(&self) -> String {
+        let mut text = format!("round {}\ncurrent {}\n", self.round, self.current);
+        for f in &self.fighters {
+            text += &format!(
+                "{:?} | {} | current {} | initiative {} | actions {} | reaction {} | strikes {}\n",
+                f.side,
+                f.stats,
+                f.hp,
+                f.initiative,
+                f.turn.remaining_actions(),
+                if f.turn.has_reaction() {
+                    "ready"
+                } else {
+                    "used"
+                },
+                f.strikes_made
+            );
+        }
+        text
+    }
+
+    /// [`TableFight::save`]로 저장한 전투를 이어서 한다
+    pub fn load(text: &str) -> Result<Self, RulesError> {
+        let mut lines = text
+            .lines()
+            .enumerate()
+            .filter(|(_, l)| !l.trim().is_empty());
+        let mut header = |label: &str| {
+            let (index, line) = lines.next().unwrap_or((0, ""));
+            line.strip_prefix(label)
+                .and_then(|v| v.trim().parse::<usize>().ok())
+                .ok_or_else(|| RulesError::InvalidRoster {
+                    line: index + 1,
+                    text: line.to_string(),
+                })
+        };
+        let round = header("round")? as u32;
+        let current = header("current")?;
+
+        let fighters = lines
+            .map(|(index, line)| {
+                load_fighter(line).ok_or_else(|| RulesError::InvalidRoster {
+                    line: index + 1,
+                    text: line.to_string(),
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if current >= fighters.len() {
+            return Err(RulesError::InvalidRoster {
+                line: 2,
+                text: format!("current {current}"),
+            });
+        }
+        Ok(Self {
+            fighters,
+            current,
+            round,
+        })
+    }
+}
+
+/// `진영 | 능력치 줄 | current n | initiative n | actions n | reaction ready | strikes n`
+fn load_fighter(line: &str) -> Option<Fighter> {
+    let fields: Vec<&str> = line.split('|').map(str::trim).collect();
+    let (side, fields) = fields.split_first()?;
+    let side = match *side {
+        "Party" => Side::Party,
+        "Creatures" => Side::Creatures,
+        _ => return None,
+    };
+    let (stats, state) = fields.split_at(fields.len().checked_sub(5)?);
+    let value = |label: &str| -> Option<&str> {
+        state
+            .iter()
+            .find_map(|f| f.strip_prefix(label))
+            .map(str::trim)
+    };
+
+    let mut fighter = Fighter::new(stat_line(stats)?, side, value("initiative")?.parse().ok()?);
+    fighter.hp = value("current")?.parse().ok()?;
+    fighter.strikes_made = value("strikes")?.parse().ok()?;
+    let actions: u8 = value("actions")?.parse().ok()?;
+    fighter
+        .turn
+        .spend_action(fighter.turn.remaining_actions().checked_sub(actions)?)
+        .ok()?;
+    match value("reaction")? {
+        "ready" => {}
+        "used" => fighter.turn.spend_reaction().ok()?,
+        _ => return None,
+    }
+    Some(fighter)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    use super::*;
+
+    const PARTY: &str = "\
+# 파티
+Valeros | AC 18 | HP 20 | Perception 4 | Longsword +7 1d8+4
+Merisiel | AC 19 | HP 16 | Perception 7 | Rapier +7 1d6+1 agile
+";
+
+    fn fight(seed: u64) -> TableFight {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let party = parse_roster(PARTY).unwrap();
+        let creatures = parse_roster("Goblin Warrior x2").unwrap();
+        TableFight::new(party, creatures, &mut rng)
+    }
+
+    #[test]
+    fn test_parse_roster() {
+        let party = parse_roster(PARTY).unwrap();
+        assert_eq!(party.len(), 2);
+        assert_eq!(
+            party[1].strike,
+            CreatureStrike {
+                name: "Rapier".to_string(),
+                attack_bonus: 7,
+                damage: Dice::new(1, 6),
+                damage_bonus: 1,
+                agile: true,
+            }
+        );
+
+        // 수록 크리처는 이름으로, 여럿이면 번호를 붙인다
+        let goblins = parse_roster("Goblin Warrior x2").unwrap();
+        assert_eq!(goblins[0].name, "Goblin Warrior 1");
+        assert_eq!(goblins[1].ac, 16);
+        assert_eq!(goblins[1].strike.name, "Dogslicer");
+
+        assert_eq!(
+            parse_roster("\nOwlbear King"),
+            Err(RulesError::InvalidRoster {
+                line: 2,
+                text: "Owlbear King".to_string()
+            })
+        );
+        assert!(parse_roster("Valeros | AC 18 | HP 20 | Longsword +7 1d8+4").is_err());
+        assert!(parse_roster("Valeros | AC 18 | HP 20 | Perception 4 | Longsword 1d8").is_err());
+
+        // 참가자가 없는 파일
+        assert_eq!(
+            parse_roster(""),
+            Err(RulesError::InvalidRoster {
+                line: 1,
+                text: String::new()
+            })
+        );
+        assert_eq!(
+            parse_roster("# 비어 있는 파티\n\n"),
+            Err(RulesError::InvalidRoster {
+                line: 1,
+                text: "# 비어 있는 파티".to_string()
+            })
+        );
+        assert!(parse_roster("Goblin Warrior x0").is_err());
+    }
+
+    #[test]
+    fn test_initiative_order() {
+        let fight = fight(1);
+        assert_eq!(fight.fighters().len(), 4);
+        assert_eq!(fight.round(), 1);
+        assert!(
+            fight
+                .fighters()
+                .windows(2)
+                .all(|w| w[0].initiative >= w[1].initiative)
+        );
+    }
+
+    #[test]
+    fn test_strike_and_actions() {
+        let mut rng = StdRng::seed_from_u64(2);
+        let mut fight = fight(2);
+        let me = fight.active_index();
+        let target = fight
+            .fighters()
+            .iter()
+            .position(|f| f.side != fight.active().side)
+            .unwrap();
+
+        assert_eq!(fight.strike(me, &mut rng), Err(RulesError::OutOfReach));
+        let first = fight.strike(target, &mut rng).unwrap();
+        let second = fight.strike(target, &mut rng).unwrap();
+        let penalty = if fight.active().stats.strike.agile {
+            -4
+        } else {
+            -5
+        };
+        assert_eq!(second.attack_bonus, first.attack_bonus + penalty);
+
+        let hp = fight.fighters()[target].stats.max_hp - first.damage - second.damage;
+        assert_eq!(fight.fighters()[target].hp, hp.max(0));
+        assert_eq!(fight.active().turn.remaining_actions(), 1);
+        assert_eq!(
+            fight.act(2),
+            Err(RulesError::InsufficientActions {
+                cost: 2,
+                remaining: 1
+            })
+        );
+
+        let line = fight.strike_line(Locale::Korean, me, target, &first);
+        assert!(line.ends_with(&format!("피해 {}", first.damage)), "{line}");
+    }
+
+    #[test]
+    fn test_end_turn_skips_fallen() {
+        let mut fight = fight(3);
+        let next = (fight.active_index() + 1) % 4;
+        fight.fighters[next].hp = 0;
+        fight.react(fight.active_index()).unwrap();
+        assert_eq!(
+            fight.react(fight.active_index()),
+            Err(RulesError::ReactionUsed)
+        );
+
+        let first = fight.active_index();
+        fight.end_turn();
+        assert_eq!(fight.active_index(), (next + 1) % 4);
+        assert_eq!(fight.active().turn, CombatTurn::new());
+
+        // 한 바퀴 돌면 다음 라운드, 리액션도 되찾는다
+        while fight.active_index() != first {
+            fight.end_turn();
+        }
+        assert_eq!(fight.round(), 2);
+        assert!(fight.active().turn.has_reaction());
+    }
+
+    #[test]
+    fn test_winner() {
+        let mut fight = fight(4);
+        assert_eq!(fight.winner(), None);
+        for f in &mut fight.fighters {
+            if f.side == Side::Creatures {
+                f.hp = 0;
+            }
+        }
+        assert_eq!(fight.winner(), Some(Side::Party));
+    }
+
+    #[test]
+    fn test_save_and_resume() {
+        let mut rng = StdRng::seed_from_u64(5);
+        let mut fight = fight(5);
+        let target = fight
+            .fighters()
+            .iter()
+            .position(|f| f.side != fight.active().side)
+            .unwrap();
+        fight.strike(target, &mut rng).unwrap();
+        fight.react(target).unwrap();
+
+        let saved = fight.save();
+        assert_eq!(TableFight::load(&saved), Ok(fight.clone()));
+
+        fight.end_turn();
+        assert_eq!(TableFight::load(&fight.save()), Ok(fight));
+
+        assert_eq!(
+            TableFight::load("round 1\ncurrent 0\nParty | Valeros"),
+            Err(RulesError::InvalidRoster {
+                line: 3,
+                text: "Party | Valeros".to_string()
+            })
+        );
+        assert!(TableFight::load("current 0").is_err());
+    }
+}
@@ -0,0 +1,413 @@
+use crate::combat::{ActionKind, CombatTurn, Weapon};
+use crate::grid::{GridPos, SQUARE_FEET};
+use crate::probability::{expected_damage, expected_damage_per_turn, strike_probabilities};
+
+/// Raise a Shield로 오르는 AC
+pub const SHIELD_AC_BONUS: i32 = 2;
+
+/// 전술 판단에 쓰는 참가자 정보
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct CombatantView<Id> {
+    pub id: Id,
+    pub position: GridPos,
+    pub speed: i32,
+    pub ac: i32,
+    pub hp: i32,
+    pub weapon: Weapon,
+    pub attack_bonus: i32,
+    pub damage_bonus: i32,
+    /// 다음 턴에 시작할 액션, 액션 예산과 Slowed, Stunned, Quickened를 반영한다
+    pub next_turn: CombatTurn,
+}
+
+impl<Id> CombatantView<Id> {
+    /// 이번 턴 `strikes_made`번 Strike한 뒤 다음 Strike의 기대 피해
+    pub fn expected_strike(&self, strikes_made: u8, target_ac: i32) -> f64 {
+        let p = strike_probabilities(
+            self.attack_bonus,
+            target_ac,
+            strikes_made,
+            self.weapon.agile,
+        );
+        expected_damage(&p, self.weapon.damage, self.damage_bonus)
+    }
+
+    /// 다음 턴에 `target` 칸을 Strike할 수 있는 횟수 (Stride 후 남은 액션, 최대 2회)
+    pub fn strikes_against(&self, target: GridPos) -> u8 {
+        let gap = self.position.distance_feet(target) - SQUARE_FEET;
+        let strides = if gap <= 0 {
+            0
+        } else if self.speed <= 0 {
+            return 0;
+        } else {
+            (gap + self.speed - 1) / self.speed
+        };
+        let mut turn = self.next_turn.clone();
+        for _ in 0..strides {
+            if turn.spend_action_for(ActionKind::Stride, 1).is_err() {
+                return 0;
+            }
+        }
+        turn.actions_for(ActionKind::Strike).min(2)
+    }
+}
+
+/// 방패 상태
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ShieldState {
+    NoShield,
+    Lowered,
+    Raised,
+}
+
+/// 전술이 보는 현재 턴 상황
+#[derive(Debug, Clone)]
+pub struct Situation<Id> {
+    pub me: CombatantView<Id>,
+    pub turn: CombatTurn,
+    pub strikes_made: u8,
+    pub shield: ShieldState,
+    /// 서 있는 적
+    pub enemies: Vec<CombatantView<Id>>,
+}
+
+impl<Id> Situation<Id> {
+    /// 방패를 들어 다음 라운드에 덜 받을 것으로 기대되는 피해
+    pub fn shield_value(&self) -> f64 {
+        self.enemies
+            .iter()
+            .map(|enemy| {
+                let strikes = enemy.strikes_against(self.me.position);
+                let incoming = |ac| {
+                    expected_damage_per_turn(
+                        enemy.attack_bonus,
+                        ac,
+                        &enemy.weapon,
+                        enemy.damage_bonus,
+                        strikes,
+                    )
+                };
+                incoming(self.me.ac) - incoming(self.me.ac + SHIELD_AC_BONUS)
+            })
+            .sum()
+    }
+}
+
+/// 전술이 고른 행동
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum TacticalAction<Id> {
+    Stride(GridPos),
+    Strike(Id),
+    RaiseShield,
+    EndTurn,
+}
+
+impl<Id> TacticalAction<Id> {
+    pub fn cost(&self) -> u8 {
+        match self {
+            TacticalAction::Stride(_) | TacticalAction::Strike(_) | TacticalAction::RaiseShield => {
+                1
+            }
+            TacticalAction::EndTurn => 0,
+        }
+    }
+
+    /// Quickened 추가 액션을 쓸 수 있는지 가릴 액션 종류
+    pub fn kind(&self) -> Option<ActionKind> {
+        match self {
+            TacticalAction::Stride(_) => Some(ActionKind::Stride),
+            TacticalAction::Strike(_) => Some(ActionKind::Strike),
+            TacticalAction::RaiseShield => Some(ActionKind::RaiseShield),
+            TacticalAction::EndTurn => None,
+        }
+    }
+}
+
+/// 자동 행동 방식, 크리처마다 다른 성격을 줄 수 있다
+pub trait Personality<Id>: Send + Sync {
+    /// 다음 행동을 고른다
+    fn choose(&self, situation: &Situation<Id>) -> TacticalAction<Id>;
+
+    /// 남은 액션으로 할 수 없는 행동을 고르면 턴을 끝낸다
+    fn next_action(&self, situation: &Situation<Id>) -> TacticalAction<Id> {
+        let action = self.choose(situation);
+        let available = action
+            .kind()
+            .map_or(situation.turn.remaining_actions(), |kind| {
+                situation.turn.actions_for(kind)
+            });
+        if action.cost() > available {
+            TacticalAction::EndTurn
+        } else {
+            action
+        }
+    }
+}
+
+/// 기본 전술: 가장 가까운 적에게 다가가 Strike하고, 남은 액션으로 방패를 든다
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct Aggressive {
+    /// 이보다 기대 피해가 낮은 Strike는 하지 않는다 (MAP 한계)
+    pub min_expected_damage: f64,
+}
+
+impl Default for Aggressive {
+    fn default() -> Self {
+        Self {
+            min_expected_damage: 1.0,
+        }
+    }
+}
+
+impl<Id: Copy> Personality<Id> for Aggressive {
+    fn choose(&self, situation: &Situation<Id>) -> TacticalAction<Id> {
+        let me = &situation.me;
+        let can_raise = situation.shield == ShieldState::Lowered;
+
+        // 닿는 적 중 기대 피해가 가장 큰 대상
+        let best = situation
+            .enemies
+            .iter()
+            .filter(|e| me.position.is_adjacent(e.position))
+            .map(|e| (e, me.expected_strike(situation.strikes_made, e.ac)))
+            .max_by(|(a, a_ev), (b, b_ev)| a_ev.total_cmp(b_ev).then(b.hp.cmp(&a.hp)));
+
+        if let Some((target, strike)) = best {
+            // 마지막 액션이면 방패와 비교한다
+            let shield = if can_raise {
+                situation.shield_value()
+            } else {
+                0.0
+            };
+            let last_action = situation.turn.remaining_actions() == 1;
+            if strike >= self.min_expected_damage && (!last_action || strike >= shield) {
+                return TacticalAction::Strike(target.id);
+            }
+        } else if let Some(nearest) = situation
+            .enemies
+            .iter()
+            .min_by_key(|e| me.position.distance_feet(e.position))
+        {
+            return TacticalAction::Stride(me.position.step_toward(nearest.position, me.speed));
+        }
+
+        if can_raise {
+            TacticalAction::RaiseShield
+        } else {
+            TacticalAction::EndTurn
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::condition::Condition;
+    use crate::dice::Dice;
+
+    /// 처음부터 방패를 들고 버티는 성격
+    struct Turtle;
+
+    impl<Id> Personality<Id> for Turtle {
+        fn choose(&self, situation: &Situation<Id>) -> TacticalAction<Id> {
+            match situation.shield {
+                ShieldState::Lowered => TacticalAction::RaiseShield,
+                _ => TacticalAction::EndTurn,
+            }
+        }
+    }
+
+    fn fighter() -> CombatantView<&'static str> {
+        CombatantView {
+            id: "Fighter",
+            position: GridPos::new(0, 0),
+            speed: 25,
+            ac: 18,
+            hp: 20,
+            weapon: Weapon {
+                name: "Longsword".to_string(),
+                damage: Dice::new(1, 8),
+                agile: false,
+            },
+            attack_bonus: 7,
+            damage_bonus: 3,
+            next_turn: CombatTurn::new(),
+        }
+    }
+
+    fn goblin(id: &'static str, x: i32) -> CombatantView<&'static str> {
+        CombatantView {
+            id,
+            position: GridPos::new(x, 0),
+            speed: 25,
+            ac: 16,
+            hp: 6,
+            weapon: Weapon {
+                name: "Dogslicer".to_string(),
+                damage: Dice::new(1, 6),
+                agile: true,
+            },
+            attack_bonus: 6,
+            damage_bonus: 0,
+            next_turn: CombatTurn::new(),
+        }
+    }
+
+    fn situation(
+        enemies: Vec<CombatantView<&'static str>>,
+        actions_spent: u8,
+        strikes_made: u8,
+        shield: ShieldState,
+    ) -> Situation<&'static str> {
+        let mut turn = CombatTurn::new();
+        turn.spend_action(actions_spent).unwrap();
+        Situation {
+            me: fighter(),
+            turn,
+            strikes_made,
+            shield,
+            enemies,
+        }
+    }
+
+    #[test]
+    fn test_expected_strike() {
+        let fighter = fighter();
+        // +7 대 AC 16: 9~18 성공, 19~20 치명타, 평균 피해 7.5
+        assert_eq!(fighter.expected_strike(0, 16), 0.5 * 7.5 + 0.1 * 15.0);
+        // -5: 14~19 성공, 20 치명타
+        assert_eq!(fighter.expected_strike(1, 16), 0.3 * 7.5 + 0.05 * 15.0);
+    }
+
+    #[test]
+    fn test_strikes_against() {
+        let goblin = goblin("Goblin", 1);
+        assert_eq!(goblin.strikes_against(GridPos::new(0, 0)), 2);
+        // 한 번 Stride하면 닿음
+        assert_eq!(goblin.strikes_against(GridPos::new(-5, 0)), 2);
+        // 두 번 Stride해야 닿음
+        assert_eq!(goblin.strikes_against(GridPos::new(-9, 0)), 1);
+        assert_eq!(goblin.strikes_against(GridPos::new(-20, 0)), 0);
+    }
+
+    #[test]
+    fn test_strikes_against_uses_next_turn() {
+        let mut goblin = goblin("Goblin", 1);
+        let far = GridPos::new(-9, 0);
+
+        // 액션 2개인 미니언은 두 번 Stride하면 남는 액션이 없다
+        goblin.next_turn = CombatTurn::with_actions(2);
+        assert_eq!(goblin.strikes_against(far), 0);
+        assert_eq!(goblin.strikes_against(GridPos::new(-5, 0)), 1);
+
+        // Slowed 1
+        goblin.next_turn = CombatTurn::start(3, &[Condition::Slowed(1)], &[]).turn;
+        assert_eq!(goblin.strikes_against(far), 0);
+        assert_eq!(goblin.strikes_against(GridPos::new(0, 0)), 2);
+
+        // Stride에 쓸 수 있는 Quickened 추가 액션
+        let quickened = [ActionKind::Stride];
+        goblin.next_turn = CombatTurn::start(3, &[Condition::Quickened], &quickened).turn;
+        assert_eq!(goblin.strikes_against(far), 2);
+    }
+
+    #[test]
+    fn test_stride_to_nearest_enemy() {
+        let s = situation(
+            vec![goblin("Far", 12), goblin("Near", 6)],
+            0,
+            0,
+            ShieldState::Lowered,
+        );
+        assert_eq!(
+            Aggressive::default().next_action(&s),
+            TacticalAction::Stride(GridPos::new(5, 0))
+        );
+    }
+
+    #[test]
+    fn test_strike_weakest_defense() {
+        let mut armored = goblin("Armored", 1);
+        armored.ac = 20;
+        let s = situation(
+            vec![armored, goblin("Goblin", -1)],
+            0,
+            0,
+            ShieldState::NoShield,
+        );
+        assert_eq!(
+            Aggressive::default().next_action(&s),
+            TacticalAction::Strike("Goblin")
+        );
+    }
+
+    #[test]
+    fn test_leftover_action_raises_shield() {
+        let mut ogre = goblin("Ogre", 1);
+        ogre.attack_bonus = 12;
+        ogre.weapon.damage = Dice::new(1, 10);
+        ogre.weapon.agile = false;
+        ogre.damage_bonus = 7;
+
+        // 두 번째 Strike는 방패보다 약해도 마지막 액션이 아니면 한다
+        let s = situation(vec![ogre.clone()], 1, 1, ShieldState::Lowered);
+        assert_eq!(
+            Aggressive::default().next_action(&s),
+            TacticalAction::Strike("Ogre")
+        );
+
+        // -10 Strike보다 방패로 막는 피해가 크다
+        let s = situation(vec![ogre.clone()], 2, 2, ShieldState::Lowered);
+        assert!(s.shield_value() > s.me.expected_strike(2, ogre.ac));
+        assert_eq!(
+            Aggressive::default().next_action(&s),
+            TacticalAction::RaiseShield
+        );
+
+        // 방패가 없으면 그래도 Strike
+        let s = situation(vec![ogre.clone()], 2, 2, ShieldState::NoShield);
+        assert_eq!(
+            Aggressive::default().next_action(&s),
+            TacticalAction::Strike("Ogre")
+        );
+
+        // 기대 피해가 너무 낮으면 하지 않는다
+        let cautious = Aggressive {
+            min_expected_damage: 3.0,
+        };
+        let s = situation(vec![ogre], 2, 2, ShieldState::Raised);
+        assert_eq!(cautious.next_action(&s), TacticalAction::EndTurn);
+    }
+
+    #[test]
+    fn test_no_actions_ends_turn() {
+        let s = situation(vec![goblin("Goblin", 1)], 3, 2, ShieldState::Lowered);
+        assert_eq!(
+            Aggressive::default().next_action(&s),
+            TacticalAction::EndTurn
+        );
+
+        let s = situation(Vec::new(), 0, 0, ShieldState::NoShield);
+        assert_eq!(
+            Aggressive::default().next_action(&s),
+            TacticalAction::EndTurn
+        );
+    }
+
+    #[test]
+    fn test_custom_personality() {
+        let personalities: Vec<Box<dyn Personality<&'static str>>> =
+            vec![Box::new(Aggressive::default()), Box::new(Turtle)];
+        let s = situation(vec![goblin("Goblin", 1)], 0, 0, ShieldState::Lowered);
+
+        let actions: Vec<_> = personalities.iter().map(|p| p.next_action(&s)).collect();
+        assert_eq!(
+            actions,
+            vec![
+                TacticalAction::Strike("Goblin"),
+                TacticalAction::RaiseShield
+            ]
+        );
+    }
+}
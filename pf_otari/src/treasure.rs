@@ -0,0 +1,436 @@
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, Mul};
+use std::str::FromStr;
+
+use crate::character::Character;
+use crate::error::RulesError;
+use crate::inventory::{Item, ItemState};
+
+/// 화폐, 동전(cp) 단위로 센다. 1 pp = 10 gp = 100 sp = 1000 cp
+///
+/// 만들기, 더하기, 곱하기가 `u64`를 넘으면 최댓값에서 멈춘다. 가격 표기를 읽을 때는 넘치면 거부한다.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Default)]
+pub struct Coins {
+    cp: u64,
+}
+
+impl Coins {
+    pub const ZERO: Coins = Coins { cp: 0 };
+
+    pub fn cp(cp: u64) -> Self {
+        Self { cp }
+    }
+
+    pub fn sp(sp: u64) -> Self {
+        Self {
+            cp: sp.saturating_mul(10),
+        }
+    }
+
+    pub fn gp(gp: u64) -> Self {
+        Self {
+            cp: gp.saturating_mul(100),
+        }
+    }
+
+    pub fn pp(pp: u64) -> Self {
+        Self {
+            cp: pp.saturating_mul(1000),
+        }
+    }
+
+    /// 모두 cp로 바꾼 값
+    pub fn copper(&self) -> u64 {
+        self.cp
+    }
+
+    /// 온전한 gp 값, 남는 sp와 cp는 버린다
+    pub fn whole_gp(&self) -> u64 {
+        self.cp / 100
+    }
+
+    /// 넘치면 `None`
+    pub fn checked_add(self, other: Coins) -> Option<Coins> {
+        self.cp.checked_add(other.cp).map(Coins::cp)
+    }
+
+    /// 모자라면 `None`
+    pub fn checked_sub(self, other: Coins) -> Option<Coins> {
+        self.cp.checked_sub(other.cp).map(Coins::cp)
+    }
+
+    /// 절반, 내림 (물건을 팔 때)
+    pub fn half(&self) -> Coins {
+        Coins::cp(self.cp / 2)
+    }
+}
+
+impl Add for Coins {
+    type Output = Coins;
+
+    fn add(self, other: Coins) -> Coins {
+        Coins::cp(self.cp.saturating_add(other.cp))
+    }
+}
+
+impl Mul<u64> for Coins {
+    type Output = Coins;
+
+    fn mul(self, count: u64) -> Coins {
+        Coins::cp(self.cp.saturating_mul(count))
+    }
+}
+
+impl Sum for Coins {
+    fn sum<I: Iterator<Item = Coins>>(iter: I) -> Coins {
+        iter.fold(Coins::ZERO, Add::add)
+    }
+}
+
+/// `12 gp 5 sp`처럼 gp, sp, cp로 쓴다. pp는 값이 크더라도 gp로 쓴다
+impl fmt::Display for Coins {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.cp == 0 {
+            return write!(f, "0 gp");
+        }
+        let parts = [
+            (self.cp / 100, "gp"),
+            (self.cp / 10 % 10, "sp"),
+            (self.cp % 10, "cp"),
+        ];
+        let text: Vec<String> = parts
+            .iter()
+            .filter(|(value, _)| *value > 0)
+            .map(|(value, unit)| format!("{value} {unit}"))
+            .collect();
+        write!(f, "{}", text.join(" "))
+    }
+}
+
+/// `3 pp 12 gp 5 sp` 같은 가격 표기를 읽는다
+impl FromStr for Coins {
+    type Err = RulesError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || RulesError::InvalidPrice(s.to_string());
+        let words: Vec<&str> = s.split_whitespace().collect();
+        if words.is_empty() || !words.len().is_multiple_of(2) {
+            return Err(invalid());
+        }
+        words.chunks(2).try_fold(Coins::ZERO, |total, pair| {
+            let value: u64 = pair[0].replace(',', "").parse().map_err(|_| invalid())?;
+            let copper = match pair[1] {
+                "cp" => 1,
+                "sp" => 10,
+                "gp" => 100,
+                "pp" => 1000,
+                _ => return Err(invalid()),
+            };
+            value
+                .checked_mul(copper)
+                .and_then(|cp| total.checked_add(Coins::cp(cp)))
+                .ok_or_else(invalid)
+        })
+    }
+}
+
+/// 4인 파티의 레벨별 보물 (gp): 총 가치, 파티 화폐, 추가 PC 1명당 화폐
+const TREASURE_BY_LEVEL: [(u64, u64, u64); 20] = [
+    (175, 40, 10),
+    (300, 70, 18),
+    (500, 120, 30),
+    (850, 200, 50),
+    (1_350, 320, 80),
+    (2_000, 500, 125),
+    (2_900, 720, 180),
+    (4_000, 1_000, 250),
+    (5_700, 1_400, 350),
+    (8_000, 2_000, 500),
+    (11_500, 2_800, 700),
+    (16_500, 4_000, 1_000),
+    (25_000, 6_000, 1_500),
+    (36_500, 9_000, 2_250),
+    (54_500, 13_000, 3_250),
+    (82_500, 20_000, 5_000),
+    (128_000, 30_000, 7_500),
+    (208_000, 48_000, 12_000),
+    (355_000, 80_000, 20_000),
+    (490_000, 140_000, 35_000),
+];
+
+/// 표의 기준 파티 크기
+pub const STANDARD_PARTY: u32 = 4;
+
+/// 한 레벨 동안 파티가 얻는 보물
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct TreasureBudget {
+    pub level: i32,
+    pub total: Coins,
+    /// (아이템 레벨, 개수)
+    pub permanent_items: Vec<(i32, u32)>,
+    pub currency: Coins,
+}
+
+/// 레벨별 보물 표, 4인보다 많거나 적으면 PC 1명당 화폐를 더하거나 뺀다
+pub fn treasure_by_level(level: i32, party_size: u32) -> Result<TreasureBudget, RulesError> {
+    if !(1..=20).contains(&level) {
+        return Err(RulesError::InvalidLevel(level));
+    }
+    let (total, currency, per_pc) = TREASURE_BY_LEVEL[level as usize - 1];
+    let adjust = |gp: u64| {
+        let gp = gp + per_pc * party_size as u64;
+        Coins::gp(gp.saturating_sub(per_pc * STANDARD_PARTY as u64))
+    };
+    // 20레벨은 20레벨 아이템 4개, 그 밖에는 한 레벨 높은 것 2개와 같은 레벨 2개
+    let permanent_items = if level == 20 {
+        vec![(20, 4)]
+    } else {
+        vec![(level + 1, 2), (level, 2)]
+    };
+    Ok(TreasureBudget {
+        level,
+        total: adjust(total),
+        permanent_items,
+        currency: adjust(currency),
+    })
+}
+
+/// 파티가 아직 나눠 갖지 않은 보물과 레벨
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct PartyTreasure {
+    pub level: i32,
+    pub coins: Coins,
+    pub items: Vec<Item>,
+}
+
+/// 레벨 보물 표와 비교한 파티의 재산
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct TreasureReport {
+    pub budget: TreasureBudget,
+    /// 이전 레벨까지와 이번 레벨의 보물 총 가치
+    pub expected_wealth: Coins,
+    /// 화폐와 모든 아이템 가격
+    pub wealth: Coins,
+    pub currency: Coins,
+    /// 이번 레벨 보물 표에 있는 레벨의 영구 아이템 (아이템 레벨, 개수)
+    pub permanent_items: Vec<(i32, u32)>,
+}
+
+impl TreasureReport {
+    /// 재산과 기대 재산의 차이 (cp), 양수면 넘침
+    pub fn difference(&self) -> i64 {
+        self.wealth.copper() as i64 - self.expected_wealth.copper() as i64
+    }
+
+    /// 보물 표보다 모자란 영구 아이템 (아이템 레벨, 개수)
+    pub fn missing_permanent_items(&self) -> Vec<(i32, u32)> {
+        self.budget
+            .permanent_items
+            .iter()
+            .map(|(level, expected)| {
+                let held = self
+                    .permanent_items
+                    .iter()
+                    .find(|(l, _)| l == level)
+                    .map_or(0, |(_, count)| *count);
+                (*level, expected.saturating_sub(held))
+            })
+            .filter(|(_, missing)| *missing > 0)
+            .collect()
+    }
+}
+
+impl PartyTreasure {
+    pub fn new(level: i32) -> Self {
+        Self {
+            level,
+            coins: Coins::ZERO,
+            items: Vec::new(),
+        }
+    }
+
+    pub fn award_coins(&mut self, coins: Coins) {
+        self.coins = self.coins + coins;
+    }
+
+    pub fn award_item(&mut self, item: Item) {
+        self.items.push(item);
+    }
+
+    /// 나눠 갖지 않은 보물의 총 가치
+    pub fn value(&self) -> Coins {
+        self.coins + self.items.iter().map(|item| item.price).sum()
+    }
+
+    /// 보관한 아이템을 캐릭터에게 넘긴다
+    pub fn give_item(
+        &mut self,
+        index: usize,
+        character: &mut Character,
+        state: ItemState,
+    ) -> Result<usize, RulesError> {
+        let item = self
+            .items
+            .get(index)
+            .cloned()
+            .ok_or(RulesError::NoSuchItem(index))?;
+        let slot = character.carry(item, state)?;
+        self.items.remove(index);
+        Ok(slot)
+    }
+
+    /// 캐릭터 소지품과 보관한 보물을 합쳐 레벨 보물 표와 비교한다
+    pub fn report(&self, party: &[Character]) -> Result<TreasureReport, RulesError> {
+        let size = party.len() as u32;
+        let budget = treasure_by_level(self.level, size)?;
+        let expected_wealth = (1..=self.level)
+            .map(|level| treasure_by_level(level, size).map(|b| b.total))
+            .sum::<Result<Coins, RulesError>>()?;
+
+        let held: Vec<&Item> = party
+            .iter()
+            .flat_map(|c| c.inventory().items().map(|(item, _)| item))
+            .chain(&self.items)
+            .collect();
+        let permanent_items = budget
+            .permanent_items
+            .iter()
+            .map(|(level, _)| {
+                let count = held
+                    .iter()
+                    .filter(|item| !item.consumable && item.level == *level)
+                    .count();
+                (*level, count as u32)
+            })
+            .collect();
+
+        Ok(TreasureReport {
+            budget,
+            expected_wealth,
+            wealth: party.iter().map(Character::wealth).sum::<Coins>() + self.value(),
+            currency: party.iter().map(Character::coins).sum::<Coins>() + self.coins,
+            permanent_items,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::character::{Attribute, Defense, Proficiency};
+    use crate::inventory::Bulk;
+
+    fn adventurer() -> Character {
+        Character::new(
+            10,
+            Attribute {
+                strength: 14,
+                dexterity: 14,
+                constitution: 12,
+                intelligence: 10,
+                wisdom: 12,
+                charisma: 10,
+            },
+            Defense {
+                armor_item: 0,
+                armor_proficiency: Proficiency::Trained,
+            },
+            Proficiency::Trained,
+        )
+    }
+
+    #[test]
+    fn test_coins() {
+        assert_eq!(Coins::pp(1), Coins::gp(10));
+        assert_eq!(Coins::gp(1), Coins::sp(10));
+        assert_eq!(Coins::sp(1), Coins::cp(10));
+        assert_eq!(Coins::gp(3) + Coins::sp(5), Coins::cp(350));
+        assert_eq!(Coins::cp(1234).to_string(), "12 gp 3 sp 4 cp");
+        assert_eq!(Coins::pp(2).to_string(), "20 gp");
+        assert_eq!(Coins::ZERO.to_string(), "0 gp");
+        assert_eq!("1 pp 2 gp 5 sp".parse(), Ok(Coins::cp(1250)));
+        assert_eq!("1,400 gp".parse(), Ok(Coins::gp(1400)));
+        assert_eq!(
+            "12 gold".parse::<Coins>(),
+            Err(RulesError::InvalidPrice("12 gold".to_string()))
+        );
+        assert_eq!(Coins::sp(5).checked_sub(Coins::gp(1)), None);
+
+        // 넘치는 가격은 읽지 않고, 계산은 최댓값에서 멈춘다
+        let huge = format!("{} pp", u64::MAX / 100);
+        assert_eq!(
+            huge.parse::<Coins>(),
+            Err(RulesError::InvalidPrice(huge.clone()))
+        );
+        let sum = format!("{} cp 1 cp", u64::MAX);
+        assert_eq!(
+            sum.parse::<Coins>(),
+            Err(RulesError::InvalidPrice(sum.clone()))
+        );
+        assert_eq!(Coins::cp(u64::MAX).checked_add(Coins::cp(1)), None);
+        assert_eq!(Coins::gp(u64::MAX), Coins::cp(u64::MAX));
+        assert_eq!(Coins::cp(u64::MAX) + Coins::cp(1), Coins::cp(u64::MAX));
+        assert_eq!(Coins::gp(u64::MAX / 2) * 3, Coins::cp(u64::MAX));
+        assert_eq!(Coins::cp(15).half(), Coins::cp(7));
+    }
+
+    #[test]
+    fn test_treasure_by_level() {
+        let first = treasure_by_level(1, 4).unwrap();
+        assert_eq!(first.total, Coins::gp(175));
+        assert_eq!(first.currency, Coins::gp(40));
+        assert_eq!(first.permanent_items, vec![(2, 2), (1, 2)]);
+
+        // 5인 파티는 PC 1명당 화폐를 더 받는다
+        let fifth = treasure_by_level(5, 5).unwrap();
+        assert_eq!(fifth.currency, Coins::gp(400));
+        assert_eq!(fifth.total, Coins::gp(1_430));
+        assert_eq!(treasure_by_level(10, 3).unwrap().currency, Coins::gp(1_500));
+
+        assert_eq!(
+            treasure_by_level(20, 4).unwrap().permanent_items,
+            vec![(20, 4)]
+        );
+        assert_eq!(treasure_by_level(21, 4), Err(RulesError::InvalidLevel(21)));
+    }
+
+    #[test]
+    fn test_report() {
+        let mut party = vec![adventurer(), adventurer()];
+        party[0].gain_coins(Coins::gp(100));
+        party[1]
+            .carry(
+                Item::gear("+1 Weapon Potency", Bulk::NEGLIGIBLE, 0)
+                    .with_price(Coins::gp(35))
+                    .with_level(2),
+                ItemState::Worn,
+            )
+            .unwrap();
+
+        let mut treasure = PartyTreasure::new(1);
+        treasure.award_coins(Coins::gp(20));
+        treasure.award_item(
+            Item::gear("Healing Potion", Bulk::LIGHT, 1)
+                .with_price(Coins::gp(4))
+                .with_level(1)
+                .consumable(),
+        );
+
+        let report = treasure.report(&party).unwrap();
+        assert_eq!(report.currency, Coins::gp(120));
+        assert_eq!(report.wealth, Coins::gp(159));
+        assert_eq!(report.permanent_items, vec![(2, 1), (1, 0)]);
+        assert_eq!(report.missing_permanent_items(), vec![(2, 1), (1, 2)]);
+        // 2인 파티의 1레벨 보물은 175 - 20 = 155 gp
+        assert_eq!(report.expected_wealth, Coins::gp(155));
+        assert_eq!(report.difference(), 400);
+
+        treasure
+            .give_item(0, &mut party[0], ItemState::Stowed)
+            .unwrap();
+        assert!(treasure.items.is_empty());
+        assert_eq!(treasure.report(&party).unwrap().wealth, Coins::gp(159));
+    }
+}